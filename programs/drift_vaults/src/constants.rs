@@ -1,4 +1,31 @@
+/// The default liquidation window, and the shortest a vault can set
 pub const TIME_FOR_LIQUIDATION: i64 = ONE_HOUR;
+/// The longest liquidation window a vault can set
+pub const MAX_TIME_FOR_LIQUIDATION: i64 = ONE_DAY * 7;
+/// The max number of liquidators a vault can allow
+pub const MAX_LIQUIDATORS: usize = 4;
+
+/// The max number of drift sub-accounts a vault can own, including the primary sub-account
+pub const MAX_SUB_ACCOUNTS: u16 = 8;
+
+/// The max number of withdraw simulations, after the one for the full amount, when searching for the largest
+/// partial withdraw amount. The amount found is within 1/256th of the request of the largest one.
+pub const MAX_WITHDRAW_SEARCH_ITERATIONS: u32 = 8;
+
+/// The number of additional withdraw requests a vault depositor can have pending at once
+pub const MAX_WITHDRAW_REQUEST_SLOTS: usize = 8;
+
+/// The shortest notice for fee and parameter increases, on top of which the redeem period applies
+pub const MIN_PARAMS_TIMELOCK: i64 = ONE_DAY * 7;
+
+/// The delay before a new withdraw destination takes effect
+pub const WITHDRAW_DESTINATION_TIMELOCK: i64 = ONE_DAY * 2;
+/// The max duration of an emergency pause, after which it expires on its own
+pub const MAX_PAUSE_DURATION: i64 = ONE_DAY * 7;
+/// The min time between the end of one emergency pause and the start of the next
+pub const PAUSE_COOLDOWN: i64 = ONE_DAY * 7;
+/// The time after a max drawdown breach before the manager can lift reduce-only
+pub const DRAWDOWN_COOLDOWN: i64 = ONE_DAY;
 
 // TIME
 pub const ONE_HOUR: i64 = 60 * 60;
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use drift::state::user::User;
 
 pub trait InitializeUserCPI {
     fn drift_initialize_user(&self, name: [u8; 32], bump: u8) -> Result<()>;
@@ -48,3 +49,79 @@ pub trait CancelRequestRemoveInsuranceFundStakeCPI {
 pub trait RemoveInsuranceFundStakeCPI {
     fn drift_remove_insurance_fund_stake(&self, market_index: u16) -> Result<()>;
 }
+
+pub trait InitializeSubAccountCPI {
+    fn drift_initialize_sub_account(&self, sub_account_id: u16) -> Result<()>;
+}
+
+pub trait UpdateSubAccountDelegateCPI<'info> {
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()>;
+
+    /// Sets `delegate` on each of the vault's non-primary sub-accounts.
+    fn drift_update_sub_accounts_delegate(
+        &self,
+        sub_accounts: &[AccountLoader<'info, User>],
+        delegate: Pubkey,
+    ) -> Result<()> {
+        for sub_account in sub_accounts {
+            let sub_account_id = sub_account.load()?.sub_account_id;
+            self.drift_update_sub_account_delegate(
+                sub_account.to_account_info(),
+                sub_account_id,
+                delegate,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub trait UpdateSubAccountReduceOnlyCPI<'info> {
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()>;
+
+    /// Sets `reduce_only` on each of the vault's non-primary sub-accounts.
+    fn drift_update_sub_accounts_reduce_only(
+        &self,
+        sub_accounts: &[AccountLoader<'info, User>],
+        reduce_only: bool,
+    ) -> Result<()> {
+        for sub_account in sub_accounts {
+            let sub_account_id = sub_account.load()?.sub_account_id;
+            self.drift_update_sub_account_reduce_only(
+                sub_account.to_account_info(),
+                sub_account_id,
+                reduce_only,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Sets the vault's drift user and its non-primary sub-accounts reduce-only when [`Vault::enforce_drawdown`]
+/// returns true. They stay reduce-only until the manager resets the drawdown breach.
+///
+/// [`Vault::enforce_drawdown`]: crate::state::Vault::enforce_drawdown
+pub trait DrawdownReduceOnlyCPI<'info> {
+    fn drift_enforce_drawdown(
+        &self,
+        reduce_only: bool,
+        sub_accounts: &[AccountLoader<'info, User>],
+    ) -> Result<()>;
+}
+
+pub trait TransferDepositCPI {
+    fn drift_transfer_deposit(&self, market_index: u16, amount: u64) -> Result<()>;
+}
+
+pub trait DeleteUserCPI<'info> {
+    fn drift_delete_user(&self, user: AccountInfo<'info>) -> Result<()>;
+}
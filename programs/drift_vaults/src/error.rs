@@ -55,6 +55,26 @@ pub enum ErrorCode {
     VaultProtocolMissing,
     #[msg("InvalidTokenization")]
     InvalidTokenization,
+    #[msg("VaultProtocolNotMigrated")]
+    VaultProtocolNotMigrated,
+    #[msg("SlippageExceeded")]
+    SlippageExceeded,
+    #[msg("VaultDepositorNotMigrated")]
+    VaultDepositorNotMigrated,
+    #[msg("VaultDepositorFrozen")]
+    VaultDepositorFrozen,
+    #[msg("InvalidVaultDepositorClose")]
+    InvalidVaultDepositorClose,
+    #[msg("VaultWindingDown")]
+    VaultWindingDown,
+    #[msg("InvalidVaultClose")]
+    InvalidVaultClose,
+    #[msg("VaultPaused")]
+    VaultPaused,
+    #[msg("InvalidVaultSubAccounts")]
+    InvalidVaultSubAccounts,
+    #[msg("VaultNotMigrated")]
+    VaultNotMigrated,
 }
 
 impl From<DriftErrorCode> for ErrorCode {
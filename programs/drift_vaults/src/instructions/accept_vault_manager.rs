@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_vault_migrated};
+use crate::drift_cpi::UpdateUserDelegateCPI;
+use crate::state::events::VaultManagerTransferRecord;
+use crate::state::{Vault, VaultProtocolProvider};
+use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
+
+pub fn accept_vault_manager<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AcceptVaultManager<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let delegate_before = vault.delegate;
+    let old_manager = vault.accept_manager(*ctx.accounts.new_manager.key)?;
+
+    emit!(VaultManagerTransferRecord {
+        ts: now,
+        vault: vault.pubkey,
+        old_manager,
+        new_manager: vault.manager,
+        delegate: vault.delegate,
+        manager_shares: vault.get_manager_shares(&mut vp)?,
+        manager_withdraw_request_shares: vault.last_manager_withdraw_request.shares,
+        manager_withdraw_request_value: vault.last_manager_withdraw_request.value,
+    });
+
+    // while in liquidation or paused the drift user delegate is restored from vault.delegate once it ends
+    let update_drift_delegate =
+        vault.delegate != delegate_before && !vault.in_liquidation() && !vault.is_paused(now);
+    let delegate = vault.delegate;
+
+    drop(vault);
+    drop(vp);
+
+    if update_drift_delegate {
+        ctx.drift_update_user_delegate(delegate)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptVaultManager<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub new_manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, AcceptVaultManager<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_vault_migrated, is_vault_protocol_for_vault, is_vault_protocol_migrated,
+};
+use crate::state::events::VaultProtocolTransferRecord;
+use crate::state::{Vault, VaultProtocol};
+
+pub fn accept_vault_protocol<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptVaultProtocol<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = ctx.accounts.vault.load()?;
+    let mut vp = ctx.accounts.vault_protocol.load_mut()?;
+
+    let old_protocol = vp.accept_protocol(*ctx.accounts.new_protocol.key)?;
+
+    emit!(VaultProtocolTransferRecord {
+        ts: now,
+        vault: vault.pubkey,
+        old_protocol,
+        new_protocol: vp.protocol,
+        protocol_shares: vp.protocol_profit_and_fee_shares,
+        protocol_withdraw_request_shares: vp.last_protocol_withdraw_request.shares,
+        protocol_withdraw_request_value: vp.last_protocol_withdraw_request.value,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptVaultProtocol<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    pub new_protocol: Signer<'info>,
+}
@@ -5,16 +5,28 @@ use drift::program::Drift;
 use drift::state::insurance_fund_stake::InsuranceFundStake;
 use drift::state::spot_market::SpotMarket;
 
-use crate::constraints::{is_if_stake_for_vault, is_manager_for_vault, is_user_stats_for_vault};
+use crate::constraints::{
+    is_if_stake_for_vault, is_manager_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
 use crate::drift_cpi::AddInsuranceFundStakeCPI;
+use crate::error::ErrorCode;
 use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, Vault};
+use crate::{declare_vault_seeds, validate, Vault};
 
 pub fn add_insurance_fund_stake<'info>(
     ctx: Context<'_, '_, '_, 'info, AddInsuranceFundStake<'info>>,
     market_index: u16,
     amount: u64,
 ) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    // depositor-funded stake is moved with vault_add/vault_remove_insurance_fund_stake
+    validate!(
+        !(vault.if_stake_in_equity && market_index == vault.spot_market_index),
+        ErrorCode::InvalidVaultUpdate,
+        "insurance fund stake for vault spot market is counted in vault equity"
+    )?;
+    drop(vault);
+
     ctx.token_transfer(amount)?;
     ctx.drift_add_insurance_fund_stake(market_index, amount)?;
     Ok(())
@@ -25,6 +37,7 @@ pub fn add_insurance_fund_stake<'info>(
 pub struct AddInsuranceFundStake<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?,
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
 use crate::constraints::{
     is_delegate_for_vault, is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault,
-    is_vault_for_vault_depositor,
+    is_vault_depositor_migrated, is_vault_for_vault_depositor, is_vault_migrated,
 };
+use crate::drift_cpi::DrawdownReduceOnlyCPI;
 use crate::state::{Vault, VaultProtocolProvider};
-use crate::AccountMapProvider;
 use crate::VaultDepositor;
+use crate::{declare_vault_seeds, implement_drawdown_reduce_only_cpi, AccountMapProvider};
 
 pub fn apply_profit_share<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ApplyProfitShare<'info>>,
@@ -32,11 +34,25 @@ pub fn apply_profit_share<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
-    vault_depositor.apply_profit_share(vault_equity, &mut vault, &mut vp)?;
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
+
+    vault_depositor.apply_profit_share(vault_equity, &mut vault, &mut vp, clock.unix_timestamp)?;
+
+    drop(vault);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
 
     Ok(())
 }
@@ -45,11 +61,13 @@ pub fn apply_profit_share<'c: 'info, 'info>(
 pub struct ApplyProfitShare<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)? || is_delegate_for_vault(&vault, &manager)?
     )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
@@ -72,3 +90,5 @@ pub struct ApplyProfitShare<'info> {
     pub drift_signer: AccountInfo<'info>,
     pub drift_program: Program<'info, Drift>,
 }
+
+implement_drawdown_reduce_only_cpi!(ApplyProfitShare);
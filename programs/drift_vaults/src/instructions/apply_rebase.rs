@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
-use crate::constraints::{is_user_for_vault, is_vault_for_vault_depositor};
+use crate::constraints::{
+    is_user_for_vault, is_vault_depositor_migrated, is_vault_for_vault_depositor, is_vault_migrated,
+};
 use crate::{AccountMapProvider, Vault, VaultDepositor, VaultProtocolProvider};
 
 pub fn apply_rebase<'c: 'info, 'info>(
@@ -26,9 +28,15 @@ pub fn apply_rebase<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity)?;
 
@@ -37,10 +45,14 @@ pub fn apply_rebase<'c: 'info, 'info>(
 
 #[derive(Accounts)]
 pub struct ApplyRebase<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
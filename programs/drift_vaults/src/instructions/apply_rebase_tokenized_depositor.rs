@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
-use crate::constraints::{is_tokenized_depositor_for_vault, is_user_for_vault};
+use crate::constraints::{is_tokenized_depositor_for_vault, is_user_for_vault, is_vault_migrated};
 use crate::state::traits::VaultDepositorBase;
 use crate::{AccountMapProvider, TokenizedVaultDepositor, Vault, VaultProtocolProvider};
 
@@ -26,9 +26,15 @@ pub fn apply_rebase_tokenized_depositor<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     ctx.accounts
         .tokenized_vault_depositor
@@ -40,7 +46,10 @@ pub fn apply_rebase_tokenized_depositor<'c: 'info, 'info>(
 
 #[derive(Accounts)]
 pub struct ApplyRebaseTokenizedDepositor<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
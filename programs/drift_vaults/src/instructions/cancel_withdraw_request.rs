@@ -5,6 +5,7 @@ use drift::state::user::User;
 
 use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated,
 };
 use crate::state::{Vault, VaultProtocolProvider};
 use crate::AccountMapProvider;
@@ -29,9 +30,15 @@ pub fn cancel_withdraw_request<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, None, vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     vault_depositor.cancel_withdraw_request(
         vault_equity.cast()?,
@@ -45,10 +52,14 @@ pub fn cancel_withdraw_request<'c: 'info, 'info>(
 
 #[derive(Accounts)]
 pub struct CancelWithdrawRequest<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
         constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
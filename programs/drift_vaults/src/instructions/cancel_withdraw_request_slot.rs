@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated,
+};
+use crate::state::{Vault, VaultProtocolProvider, WithdrawRequestSlots};
+use crate::AccountMapProvider;
+use crate::VaultDepositor;
+
+pub fn cancel_withdraw_request_slot<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CancelWithdrawRequestSlot<'info>>,
+    slot: u8,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut withdraw_request_slots = ctx.accounts.withdraw_request_slots.load_mut()?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    vault_depositor.cancel_withdraw_request_slot(
+        &mut withdraw_request_slots,
+        slot,
+        vault_equity.cast()?,
+        &mut vault,
+        &mut vp,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelWithdrawRequestSlot<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_request_slots", vault_depositor.key().as_ref()],
+        bump,
+    )]
+    pub withdraw_request_slots: AccountLoader<'info, WithdrawRequestSlots>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: unused, for future proofing
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
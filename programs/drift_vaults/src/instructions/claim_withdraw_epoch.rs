@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::{Deposit as DriftDeposit, UpdateUser};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated, is_withdraw_destination_for_vault_depositor,
+};
+use crate::drift_cpi::{DepositCPI, DrawdownReduceOnlyCPI};
+use crate::state::{Vault, VaultDepositor, VaultProtocolProvider, WithdrawEpoch};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{
+    declare_vault_seeds, implement_deposit, implement_drawdown_reduce_only_cpi, AccountMapProvider,
+};
+
+pub fn claim_withdraw_epoch<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimWithdrawEpoch<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut withdraw_epoch = ctx.accounts.withdraw_epoch.load_mut()?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
+
+    let (user_withdraw_amount, vault_deposit_amount) = vault_depositor.claim_withdraw_epoch(
+        &mut withdraw_epoch,
+        vault_equity,
+        &mut vault,
+        &mut vp,
+        clock.unix_timestamp,
+    )?;
+
+    msg!(
+        "user_withdraw_amount: {}, vault_deposit_amount: {}",
+        user_withdraw_amount,
+        vault_deposit_amount
+    );
+
+    drop(vault);
+    drop(withdraw_epoch);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
+    if user_withdraw_amount > 0 {
+        ctx.token_transfer(user_withdraw_amount)?;
+    }
+
+    if vault_deposit_amount > 0 {
+        ctx.drift_deposit(vault_deposit_amount)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimWithdrawEpoch<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_epoch", vault.key().as_ref(), vault_depositor.load()?.withdraw_request_epoch.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub withdraw_epoch: AccountLoader<'info, WithdrawEpoch>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_withdraw_destination_for_vault_depositor(&vault_depositor, &user_token_account)?,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ClaimWithdrawEpoch<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = Transfer {
+            from: self.accounts.vault_token_account.to_account_info().clone(),
+            to: self.accounts.user_token_account.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> DepositCPI for Context<'_, '_, '_, 'info, ClaimWithdrawEpoch<'info>> {
+    fn drift_deposit(&self, amount: u64) -> Result<()> {
+        implement_deposit!(self, amount);
+        Ok(())
+    }
+}
+
+implement_drawdown_reduce_only_cpi!(ClaimWithdrawEpoch);
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount};
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::DeleteUser;
+use drift::program::Drift;
+
+use crate::constraints::{
+    is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
+use crate::drift_cpi::DeleteUserCPI;
+use crate::state::events::VaultCloseRecord;
+use crate::token_cpi::CloseTokenAccountCPI;
+use crate::{declare_vault_seeds, AccountMapProvider};
+use crate::{error::ErrorCode, validate, Vault};
+
+/// Closes a wound down vault with no shares left: deletes the vault's drift sub-accounts, passed in the
+/// remaining_accounts, and primary `user`, then closes the vault token account and the vault, returning
+/// the rent to the manager. Drift has no instruction to delete `UserStats`, so it is left behind.
+pub fn close_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CloseVault<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = ctx.accounts.vault.load()?;
+
+    vault.validate_can_close()?;
+
+    validate!(
+        ctx.accounts.vault_token_account.amount == 0,
+        ErrorCode::InvalidVaultClose,
+        "vault token account still holds {}",
+        ctx.accounts.vault_token_account.amount
+    )?;
+
+    let sub_accounts = ctx.load_sub_accounts(false)?;
+    vault.validate_sub_accounts(&sub_accounts)?;
+    let sub_account_count = vault.get_sub_account_count();
+
+    let vault_key = vault.pubkey;
+    let manager = vault.manager;
+
+    drop(vault);
+
+    // drift checks each sub-account belongs to the vault and has no open positions or balances
+    for sub_account in sub_accounts {
+        ctx.drift_delete_user(sub_account.to_account_info())?;
+    }
+    ctx.drift_delete_user(ctx.accounts.drift_user.clone())?;
+
+    ctx.close_token_account()?;
+
+    emit!(VaultCloseRecord {
+        ts: now,
+        vault: vault_key,
+        manager,
+        sub_accounts_deleted: sub_account_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+        close = manager,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DeleteUserCPI<'info> for Context<'_, '_, '_, 'info, CloseVault<'info>> {
+    fn drift_delete_user(&self, user: AccountInfo<'info>) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = DeleteUser {
+            user,
+            user_stats: self.accounts.drift_user_stats.clone(),
+            state: self.accounts.drift_state.clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds);
+        drift::cpi::delete_user(cpi_context)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> CloseTokenAccountCPI for Context<'_, '_, '_, 'info, CloseVault<'info>> {
+    fn close_token_account(&self) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = CloseAccount {
+            account: self.accounts.vault_token_account.to_account_info().clone(),
+            destination: self.accounts.manager.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::close_account(cpi_context)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_vault_depositor_migrated, is_vault_for_vault_depositor,
+    is_vault_migrated,
+};
+use crate::state::events::VaultDepositorCloseRecord;
+use crate::state::{Vault, VaultDepositor, WithdrawRequestSlots};
+
+/// Closes an unfrozen vault depositor with no shares, pending withdraws or limit overrides, returning the
+/// rent to the authority. The depositor's [`WithdrawRequestSlots`] is closed too if passed.
+pub fn close_vault_depositor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CloseVaultDepositor<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault_depositor = ctx.accounts.vault_depositor.load()?;
+
+    vault_depositor.validate_can_close(false)?;
+
+    emit!(VaultDepositorCloseRecord {
+        ts: now,
+        vault: vault_depositor.vault,
+        vault_depositor: vault_depositor.pubkey,
+        depositor_authority: vault_depositor.authority,
+        closed_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVaultDepositor<'info> {
+    #[account(
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+        close = authority,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_request_slots", vault_depositor.key().as_ref()],
+        bump,
+        close = authority,
+    )]
+    pub withdraw_request_slots: Option<AccountLoader<'info, WithdrawRequestSlots>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_manager_for_vault, is_vault_migrated, is_vault_protocol_for_vault,
+    is_vault_protocol_migrated,
+};
+use crate::state::{Vault, VaultProtocol};
+use crate::{error::ErrorCode, validate};
+
+/// Closes the [`VaultProtocol`] of a winding down vault once the protocol has withdrawn its shares,
+/// returning the rent to the protocol.
+pub fn close_vault_protocol<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseVaultProtocol<'info>>,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let vp = ctx.accounts.vault_protocol.load()?;
+
+    validate!(
+        vault.wind_down,
+        ErrorCode::InvalidVaultClose,
+        "vault must be winding down before the vault protocol is closed"
+    )?;
+    vp.validate_can_close()?;
+
+    vault.vault_protocol = false;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVaultProtocol<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?,
+        constraint = vault_protocol.load()?.protocol == protocol.key(),
+        close = protocol,
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(mut)]
+    /// CHECK: checked in vault_protocol constraint, receives the rent
+    pub protocol: AccountInfo<'info>,
+}
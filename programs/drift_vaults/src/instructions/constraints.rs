@@ -1,9 +1,52 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::TokenAccount;
 use drift::state::insurance_fund_stake::InsuranceFundStake;
+use drift::state::user::User;
 
+use crate::error::ErrorCode;
 use crate::state::VaultProtocol;
-use crate::{TokenizedVaultDepositor, Vault, VaultDepositor};
+use crate::{Size, TokenizedVaultDepositor, Vault, VaultDepositor};
+
+/// A [`Vault`] created before the account grew must be migrated with migrate_vault before it can be loaded.
+pub fn is_vault_migrated(vault: &AccountLoader<Vault>) -> Result<bool> {
+    if vault.to_account_info().data_len() < Vault::SIZE {
+        let ec = ErrorCode::VaultNotMigrated;
+        msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+        msg!("Vault must be migrated with migrate_vault");
+        return Err(anchor_lang::error::Error::from(ec));
+    }
+
+    Ok(true)
+}
+
+/// A [`VaultDepositor`] created before the account grew must be migrated with migrate_vault_depositor
+/// before it can be loaded.
+pub fn is_vault_depositor_migrated(
+    vault_depositor: &AccountLoader<VaultDepositor>,
+) -> Result<bool> {
+    if vault_depositor.to_account_info().data_len() < VaultDepositor::SIZE {
+        let ec = ErrorCode::VaultDepositorNotMigrated;
+        msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+        msg!("VaultDepositor must be migrated with migrate_vault_depositor");
+        return Err(anchor_lang::error::Error::from(ec));
+    }
+
+    Ok(true)
+}
+
+/// A [`VaultProtocol`] created before the account grew must be migrated with migrate_vault_protocol
+/// before it can be loaded.
+pub fn is_vault_protocol_migrated(vault_protocol: &AccountLoader<VaultProtocol>) -> Result<bool> {
+    if vault_protocol.to_account_info().data_len() < VaultProtocol::SIZE {
+        let ec = ErrorCode::VaultProtocolNotMigrated;
+        msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+        msg!("VaultProtocol must be migrated with migrate_vault_protocol");
+        return Err(anchor_lang::error::Error::from(ec));
+    }
+
+    Ok(true)
+}
 
 pub fn is_vault_for_vault_depositor(
     vault_depositor: &AccountLoader<VaultDepositor>,
@@ -47,10 +90,38 @@ pub fn is_delegate_for_vault(vault: &AccountLoader<Vault>, signer: &Signer) -> R
     Ok(vault.load()?.delegate.eq(signer.key))
 }
 
+/// The delegate only manages the insurance fund stake that is counted in vault equity, in the vault's
+/// spot market. Any other stake is the manager's.
+pub fn is_delegate_for_vault_if_stake(
+    vault: &AccountLoader<Vault>,
+    signer: &Signer,
+    market_index: u16,
+) -> Result<bool> {
+    let vault = vault.load()?;
+    Ok(vault.delegate.eq(signer.key)
+        && vault.if_stake_in_equity
+        && vault.spot_market_index == market_index)
+}
+
+pub fn is_liquidation_delegate_for_vault(
+    vault: &AccountLoader<Vault>,
+    signer: &Signer,
+) -> Result<bool> {
+    let vault = vault.load()?;
+    Ok(vault.in_liquidation() && vault.liquidation_delegate.eq(signer.key))
+}
+
 pub fn is_user_for_vault(vault: &AccountLoader<Vault>, user_key: &Pubkey) -> Result<bool> {
     Ok(vault.load()?.user.eq(user_key))
 }
 
+pub fn is_sub_account_for_vault(
+    vault: &AccountLoader<Vault>,
+    sub_account: &AccountLoader<User>,
+) -> Result<bool> {
+    Ok(sub_account.load()?.authority.eq(&vault.key()))
+}
+
 pub fn is_user_stats_for_vault(
     vault: &AccountLoader<Vault>,
     user_stats: &AccountInfo,
@@ -111,3 +182,29 @@ pub fn is_if_stake_for_vault(
 ) -> Result<bool> {
     Ok(if_stake.load()?.authority.eq(&vault.key()))
 }
+
+/// Withdrawals go to the vault depositor's registered withdraw destination, or else any token account
+/// of its authority.
+pub fn is_withdraw_destination_for_vault_depositor(
+    vault_depositor: &AccountLoader<VaultDepositor>,
+    token_account: &Account<TokenAccount>,
+) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault_depositor = vault_depositor.load()?;
+    Ok(match vault_depositor.withdraw_destination.get(now) {
+        Some(destination) => token_account.key().eq(&destination),
+        None => token_account.owner.eq(&vault_depositor.authority),
+    })
+}
+
+pub fn is_withdraw_destination_for_manager(
+    vault: &AccountLoader<Vault>,
+    token_account: &Account<TokenAccount>,
+) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = vault.load()?;
+    Ok(match vault.manager_withdraw_destination.get(now) {
+        Some(destination) => token_account.key().eq(&destination),
+        None => token_account.owner.eq(&vault.manager),
+    })
+}
@@ -1,22 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use drift::cpi::accounts::Deposit as DriftDeposit;
+use drift::cpi::accounts::{Deposit as DriftDeposit, UpdateUser};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
 use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated,
 };
-use crate::drift_cpi::DepositCPI;
+use crate::drift_cpi::{DepositCPI, DrawdownReduceOnlyCPI};
 use crate::error::ErrorCode;
 use crate::state::{Vault, VaultDepositor, VaultProtocolProvider};
 use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, implement_deposit, validate, AccountMapProvider};
+use crate::{
+    declare_vault_seeds, implement_deposit, implement_drawdown_reduce_only_cpi, validate,
+    AccountMapProvider,
+};
 
 pub fn deposit<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
     amount: u64,
+    min_shares_out: Option<u128>,
 ) -> Result<()> {
     let clock = &Clock::get()?;
 
@@ -24,6 +29,7 @@ pub fn deposit<'c: 'info, 'info>(
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
 
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    vault.validate_access(&vault_depositor.authority, &ctx.load_access_accounts())?;
 
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
@@ -38,11 +44,19 @@ pub fn deposit<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
 
-    vault_depositor.deposit(
+    let shares_out = vault_depositor.deposit(
         amount,
         vault_equity,
         &mut vault,
@@ -50,10 +64,22 @@ pub fn deposit<'c: 'info, 'info>(
         clock.unix_timestamp,
     )?;
 
+    if let Some(min_shares_out) = min_shares_out {
+        validate!(
+            shares_out >= min_shares_out,
+            ErrorCode::SlippageExceeded,
+            "deposit minted {} shares < min_shares_out {}",
+            shares_out,
+            min_shares_out
+        )?;
+    }
+
     drop(vault);
     drop(user);
     drop(vp);
 
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
     ctx.token_transfer(amount)?;
 
     ctx.drift_deposit(amount)?;
@@ -63,10 +89,14 @@ pub fn deposit<'c: 'info, 'info>(
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
         constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
@@ -130,3 +160,5 @@ impl<'info> DepositCPI for Context<'_, '_, '_, 'info, Deposit<'info>> {
         Ok(())
     }
 }
+
+implement_drawdown_reduce_only_cpi!(Deposit);
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use drift::cpi::accounts::{Deposit as DriftDeposit, UpdateUser};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_user_for_vault, is_user_stats_for_vault, is_vault_depositor_migrated, is_vault_migrated,
+};
+use crate::drift_cpi::{DepositCPI, DrawdownReduceOnlyCPI};
+use crate::error::ErrorCode;
+use crate::state::events::DepositForRecord;
+use crate::state::{Vault, VaultDepositor, VaultProtocolProvider};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{
+    declare_vault_seeds, implement_deposit, implement_drawdown_reduce_only_cpi, validate,
+    AccountMapProvider,
+};
+
+/// Deposits from the funder's token account into a vault depositor owned by another authority.
+/// The vault depositor must already exist, so permissioned vaults still control who holds shares.
+pub fn deposit_for<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DepositFor<'info>>,
+    amount: u64,
+    min_shares_out: Option<u128>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    vault.validate_access(&vault_depositor.authority, &ctx.load_access_accounts())?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
+
+    let shares_out = vault_depositor.deposit(
+        amount,
+        vault_equity,
+        &mut vault,
+        &mut vp,
+        clock.unix_timestamp,
+    )?;
+
+    if let Some(min_shares_out) = min_shares_out {
+        validate!(
+            shares_out >= min_shares_out,
+            ErrorCode::SlippageExceeded,
+            "deposit minted {} shares < min_shares_out {}",
+            shares_out,
+            min_shares_out
+        )?;
+    }
+
+    emit!(DepositForRecord {
+        ts: clock.unix_timestamp,
+        vault: vault.pubkey,
+        vault_depositor: vault_depositor.pubkey,
+        depositor_authority: vault_depositor.authority,
+        funder: ctx.accounts.funder.key(),
+        amount,
+        shares: shares_out,
+    });
+
+    drop(vault);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
+    ctx.token_transfer(amount)?;
+
+    ctx.drift_deposit(amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositFor<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// CHECK: the owner of the vault depositor, does not need to sign
+    pub authority: AccountInfo<'info>,
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::authority = funder,
+        token::mint = vault_token_account.mint
+    )]
+    pub funder_token_account: Box<Account<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, DepositFor<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.accounts.funder_token_account.to_account_info().clone(),
+            to: self.accounts.vault_token_account.to_account_info().clone(),
+            authority: self.accounts.funder.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new(token_program, cpi_accounts);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> DepositCPI for Context<'_, '_, '_, 'info, DepositFor<'info>> {
+    fn drift_deposit(&self, amount: u64) -> Result<()> {
+        implement_deposit!(self, amount);
+        Ok(())
+    }
+}
+
+implement_drawdown_reduce_only_cpi!(DepositFor);
@@ -1,16 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 use anchor_spl::token::{Token, TokenAccount};
-use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
 use crate::constraints::*;
-use crate::drift_cpi::WithdrawCPI;
+use crate::drift_cpi::{DrawdownReduceOnlyCPI, WithdrawCPI};
 use crate::state::{Vault, VaultDepositor, VaultProtocolProvider};
 use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, AccountMapProvider};
+use crate::{declare_vault_seeds, implement_drawdown_reduce_only_cpi, AccountMapProvider};
 
 pub fn force_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ForceWithdraw<'info>>,
@@ -32,12 +32,20 @@ pub fn force_withdraw<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
 
     let (withdraw_amount, _) =
-        vault_depositor.withdraw(vault_equity, &mut vault, &mut vp, clock.unix_timestamp)?;
+        vault_depositor.force_withdraw(vault_equity, &mut vault, &mut vp, clock.unix_timestamp)?;
 
     msg!("force_withdraw_amount: {}", withdraw_amount);
 
@@ -45,6 +53,8 @@ pub fn force_withdraw<'c: 'info, 'info>(
     drop(user);
     drop(vp);
 
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
     ctx.drift_withdraw(withdraw_amount)?;
 
     ctx.token_transfer(withdraw_amount)?;
@@ -56,12 +66,14 @@ pub fn force_withdraw<'c: 'info, 'info>(
 pub struct ForceWithdraw<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)? || is_delegate_for_vault(&vault, &manager)?
     )]
     pub vault: AccountLoader<'info, Vault>,
     pub manager: Signer<'info>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
@@ -94,7 +106,7 @@ pub struct ForceWithdraw<'info> {
     pub drift_signer: AccountInfo<'info>,
     #[account(
         mut,
-        token::authority = vault_depositor.load()?.authority,
+        constraint = is_withdraw_destination_for_vault_depositor(&vault_depositor, &user_token_account)?,
         token::mint = vault_token_account.mint
     )]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
@@ -148,3 +160,5 @@ impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ForceWithdraw<'info>
         Ok(())
     }
 }
+
+implement_drawdown_reduce_only_cpi!(ForceWithdraw);
@@ -4,7 +4,7 @@ use drift::cpi::accounts::InitializeInsuranceFundStake as DriftInitializeInsuran
 use drift::program::Drift;
 use drift::state::spot_market::SpotMarket;
 
-use crate::constraints::{is_manager_for_vault, is_user_stats_for_vault};
+use crate::constraints::{is_manager_for_vault, is_user_stats_for_vault, is_vault_migrated};
 use crate::drift_cpi::InitializeInsuranceFundStakeCPI;
 use crate::{declare_vault_seeds, Vault};
 
@@ -21,6 +21,7 @@ pub fn initialize_insurance_fund_stake<'info>(
 pub struct InitializeInsuranceFundStake<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?,
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -1,4 +1,4 @@
-use crate::constraints::is_manager_for_vault;
+use crate::constraints::{is_manager_for_vault, is_vault_migrated};
 use crate::{Size, TokenizedVaultDepositor, Vault};
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -61,6 +61,9 @@ pub fn initialize_tokenized_vault_depositor(
 #[derive(Accounts)]
 #[instruction(params: InitializeTokenizedVaultDepositorParams)]
 pub struct InitializeTokenizedVaultDepositor<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         init,
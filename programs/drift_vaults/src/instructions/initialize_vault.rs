@@ -24,6 +24,7 @@ pub fn initialize_vault<'info>(
     vault.token_account = *ctx.accounts.token_account.to_account_info().key;
     vault.spot_market_index = params.spot_market_index;
     vault.init_ts = Clock::get()?.unix_timestamp;
+    vault.sub_account_count = 1;
 
     validate!(
         params.redeem_period < ONE_DAY * 90,
@@ -50,11 +51,41 @@ pub fn initialize_vault<'info>(
     vault.profit_share = params.profit_share;
 
     validate!(
-        params.hurdle_rate == 0,
+        params.hurdle_rate < PERCENTAGE_PRECISION_U64.cast()?,
         ErrorCode::InvalidVaultInitialization,
-        "hurdle rate not implemented"
+        "hurdle rate must be < 100%"
     )?;
     vault.hurdle_rate = params.hurdle_rate;
+
+    validate!(
+        params.profit_share_crystallization_period >= 0
+            && params.profit_share_crystallization_period <= ONE_DAY * 365,
+        ErrorCode::InvalidVaultInitialization,
+        "profit share crystallization period must be <= 365 days"
+    )?;
+    vault.profit_share_crystallization_period = params.profit_share_crystallization_period;
+
+    validate!(
+        params.entry_fee < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultInitialization,
+        "entry fee must be < 100%"
+    )?;
+    vault.entry_fee = params.entry_fee;
+
+    validate!(
+        params.exit_fee < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultInitialization,
+        "exit fee must be < 100%"
+    )?;
+    vault.exit_fee = params.exit_fee;
+
+    validate!(
+        params.early_withdraw_penalty < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultInitialization,
+        "early withdraw penalty must be < 100%"
+    )?;
+    vault.early_withdraw_penalty = params.early_withdraw_penalty;
+    vault.early_withdraw_penalty_to_manager = params.early_withdraw_penalty_to_manager;
     vault.bump = bump;
     vault.permissioned = params.permissioned;
 
@@ -75,6 +106,11 @@ pub struct VaultParams {
     pub min_deposit_amount: u64,
     pub profit_share: u32,
     pub hurdle_rate: u32,
+    pub profit_share_crystallization_period: i64,
+    pub entry_fee: u32,
+    pub exit_fee: u32,
+    pub early_withdraw_penalty: u32,
+    pub early_withdraw_penalty_to_manager: bool,
     pub spot_market_index: u16,
     pub permissioned: bool,
 }
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 
+use crate::constraints::is_vault_migrated;
 use crate::error::ErrorCode;
 use crate::state::Vault;
-use crate::{validate, Size, VaultDepositor};
+use crate::{validate, AccountMapProvider, Size, VaultDepositor};
 
-pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Result<()> {
+pub fn initialize_vault_depositor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositor<'info>>,
+) -> Result<()> {
     let mut vault_depositor = ctx.accounts.vault_depositor.load_init()?;
     vault_depositor.vault = ctx.accounts.vault.key();
     vault_depositor.pubkey = ctx.accounts.vault_depositor.key();
@@ -23,6 +26,8 @@ pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Res
             ErrorCode::Default,
             "Vault depositor authority must pay to create account"
         )?;
+
+        vault.validate_access(&vault_depositor.authority, &ctx.load_access_accounts())?;
     }
 
     Ok(())
@@ -30,6 +35,9 @@ pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Res
 
 #[derive(Accounts)]
 pub struct InitializeVaultDepositor<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
       init,
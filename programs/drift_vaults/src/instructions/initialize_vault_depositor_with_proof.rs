@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_vault_migrated;
+use crate::error::ErrorCode;
+use crate::state::Vault;
+use crate::{validate, Size, VaultDepositor};
+
+/// Lets an allowlisted authority create its own vault depositor in a permissioned vault by proving its
+/// leaf is in the vault's allowlist Merkle root. A non-zero `max_equity` in the leaf caps the depositor.
+pub fn initialize_vault_depositor_with_proof(
+    ctx: Context<InitializeVaultDepositorWithProof>,
+    max_equity: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    validate!(
+        vault.permissioned,
+        ErrorCode::InvalidVaultInitialization,
+        "vault is not permissioned, use initialize_vault_depositor"
+    )?;
+    validate!(
+        vault.verify_allowlist_proof(ctx.accounts.authority.key, max_equity, &proof),
+        ErrorCode::PermissionedVault,
+        "authority is not on the vault allowlist"
+    )?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_init()?;
+    vault_depositor.vault = ctx.accounts.vault.key();
+    vault_depositor.pubkey = ctx.accounts.vault_depositor.key();
+    vault_depositor.authority = *ctx.accounts.authority.key;
+    vault_depositor.allowlist_max_equity = max_equity;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorWithProof<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+      init,
+      seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+      space = VaultDepositor::SIZE,
+      bump,
+      payer = payer
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
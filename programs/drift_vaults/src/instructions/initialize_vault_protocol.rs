@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
+use crate::state::{Vault, VaultProtocol};
+use crate::{error::ErrorCode, validate, AccountMapProvider, Size, VaultProtocolParams};
+
+pub fn initialize_vault_protocol<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultProtocol<'info>>,
+    params: VaultProtocolParams,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let now = clock.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(
+        !vault.vault_protocol,
+        ErrorCode::InvalidVaultUpdate,
+        "vault already has a vault protocol"
+    )?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), false)?;
+    let equity_accounts = ctx.load_equity_accounts(false)?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    // settle management fees accrued at the legacy rate before the protocol fee applies
+    vault.apply_fee(&mut None, vault_equity, now)?;
+    vault.last_fee_update_ts = now;
+
+    let mut vp = ctx.accounts.vault_protocol.load_init()?;
+
+    // checked against the manager's pending fees as well, so the combined fees stay below 100% once
+    // both sides' proposals take effect
+    validate!(
+        vault
+            .management_fee
+            .max(vault.pending_management_fee)
+            .saturating_add(params.protocol_fee.cast::<i64>()?)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "management fee plus protocol fee must be < 100%"
+    )?;
+
+    validate!(
+        vault
+            .profit_share
+            .max(vault.pending_profit_share)
+            .saturating_add(params.protocol_profit_share)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager profit share protocol profit share must be < 100%"
+    )?;
+
+    validate!(
+        vault
+            .entry_fee
+            .max(vault.pending_entry_fee)
+            .saturating_add(params.protocol_entry_fee)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager entry fee plus protocol entry fee must be < 100%"
+    )?;
+
+    validate!(
+        vault
+            .exit_fee
+            .max(vault.pending_exit_fee)
+            .saturating_add(params.protocol_exit_fee)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager exit fee plus protocol exit fee must be < 100%"
+    )?;
+
+    // existing depositors joined without protocol fees, so they only apply after the usual notice
+    vp.propose_initial_params(
+        &vault,
+        params.protocol_fee,
+        params.protocol_profit_share,
+        params.protocol_entry_fee,
+        params.protocol_exit_fee,
+        now,
+    )?;
+
+    vp.protocol = params.protocol;
+    vp.bump = ctx.bumps.vault_protocol;
+
+    vault.vault_protocol = true;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: VaultProtocolParams)]
+pub struct InitializeVaultProtocol<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_protocol", vault.key().as_ref()],
+        space = VaultProtocol::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    pub manager: Signer<'info>,
+    #[account(
+        constraint = protocol.key() == params.protocol,
+    )]
+    pub protocol: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: unused, for future proofing
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::{InitializeUser, UpdateUser};
+use drift::program::Drift;
+
+use crate::constants::MAX_SUB_ACCOUNTS;
+use crate::constraints::{is_manager_for_vault, is_user_stats_for_vault, is_vault_migrated};
+use crate::drift_cpi::{InitializeSubAccountCPI, UpdateSubAccountDelegateCPI};
+use crate::{declare_vault_seeds, implement_update_sub_account_delegate_cpi};
+use crate::{error::ErrorCode, validate, Vault};
+
+pub fn initialize_vault_sub_account<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeVaultSubAccount<'info>>,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    // drift sub-account ids are sequential, the primary sub-account is 0
+    let sub_account_id = vault.get_sub_account_count();
+    validate!(
+        sub_account_id < MAX_SUB_ACCOUNTS,
+        ErrorCode::InvalidVaultUpdate,
+        "vault can own at most {} sub-accounts",
+        MAX_SUB_ACCOUNTS
+    )?;
+    vault.sub_account_count = sub_account_id + 1;
+
+    let delegate = vault.get_drift_delegate(Clock::get()?.unix_timestamp);
+
+    drop(vault);
+
+    ctx.drift_initialize_sub_account(sub_account_id)?;
+
+    if delegate != Pubkey::default() {
+        ctx.drift_update_sub_account_delegate(
+            ctx.accounts.drift_user.clone(),
+            sub_account_id,
+            delegate,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultSubAccount<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    #[account(mut)]
+    pub drift_user: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    #[account(mut)]
+    pub drift_state: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> InitializeSubAccountCPI
+    for Context<'_, '_, '_, 'info, InitializeVaultSubAccount<'info>>
+{
+    fn drift_initialize_sub_account(&self, sub_account_id: u16) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+        let name = self.accounts.vault.load()?.name;
+
+        let cpi_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_accounts = InitializeUser {
+            user_stats: self.accounts.drift_user_stats.clone(),
+            user: self.accounts.drift_user.clone(),
+            state: self.accounts.drift_state.clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            payer: self.accounts.payer.to_account_info().clone(),
+            rent: self.accounts.rent.to_account_info().clone(),
+            system_program: self.accounts.system_program.to_account_info().clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds);
+        drift::cpi::initialize_user(cpi_ctx, sub_account_id, name)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountDelegateCPI<'info>
+    for Context<'_, '_, '_, 'info, InitializeVaultSubAccount<'info>>
+{
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
@@ -26,6 +26,7 @@ pub fn initialize_vault_with_protocol<'c: 'info, 'info>(
     vault.token_account = *ctx.accounts.token_account.to_account_info().key;
     vault.spot_market_index = params.spot_market_index;
     vault.init_ts = Clock::get()?.unix_timestamp;
+    vault.sub_account_count = 1;
 
     let mut vp = ctx.accounts.vault_protocol.load_init()?;
 
@@ -72,11 +73,49 @@ pub fn initialize_vault_with_protocol<'c: 'info, 'info>(
     vault.vault_protocol = true;
 
     validate!(
-        params.hurdle_rate == 0,
+        params.hurdle_rate < PERCENTAGE_PRECISION_U64.cast()?,
         ErrorCode::InvalidVaultInitialization,
-        "hurdle rate not implemented"
+        "hurdle rate must be < 100%"
     )?;
     vault.hurdle_rate = params.hurdle_rate;
+
+    validate!(
+        params.profit_share_crystallization_period >= 0
+            && params.profit_share_crystallization_period <= ONE_DAY * 365,
+        ErrorCode::InvalidVaultInitialization,
+        "profit share crystallization period must be <= 365 days"
+    )?;
+    vault.profit_share_crystallization_period = params.profit_share_crystallization_period;
+
+    validate!(
+        params
+            .entry_fee
+            .saturating_add(params.vault_protocol.protocol_entry_fee)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultInitialization,
+        "manager entry fee plus protocol entry fee must be < 100%"
+    )?;
+    vault.entry_fee = params.entry_fee;
+    vp.protocol_entry_fee = params.vault_protocol.protocol_entry_fee;
+
+    validate!(
+        params
+            .exit_fee
+            .saturating_add(params.vault_protocol.protocol_exit_fee)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultInitialization,
+        "manager exit fee plus protocol exit fee must be < 100%"
+    )?;
+    vault.exit_fee = params.exit_fee;
+    vp.protocol_exit_fee = params.vault_protocol.protocol_exit_fee;
+
+    validate!(
+        params.early_withdraw_penalty < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultInitialization,
+        "early withdraw penalty must be < 100%"
+    )?;
+    vault.early_withdraw_penalty = params.early_withdraw_penalty;
+    vault.early_withdraw_penalty_to_manager = params.early_withdraw_penalty_to_manager;
     vault.bump = bump;
     vault.permissioned = params.permissioned;
 
@@ -98,6 +137,11 @@ pub struct VaultWithProtocolParams {
     pub min_deposit_amount: u64,
     pub profit_share: u32,
     pub hurdle_rate: u32,
+    pub profit_share_crystallization_period: i64,
+    pub entry_fee: u32,
+    pub exit_fee: u32,
+    pub early_withdraw_penalty: u32,
+    pub early_withdraw_penalty_to_manager: bool,
     pub spot_market_index: u16,
     pub permissioned: bool,
     pub vault_protocol: VaultProtocolParams,
@@ -108,6 +152,8 @@ pub struct VaultProtocolParams {
     pub protocol: Pubkey,
     pub protocol_fee: u64,
     pub protocol_profit_share: u32,
+    pub protocol_entry_fee: u32,
+    pub protocol_exit_fee: u32,
 }
 
 #[derive(Accounts)]
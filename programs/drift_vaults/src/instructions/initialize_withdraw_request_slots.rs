@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_vault_depositor_migrated, is_vault_for_vault_depositor,
+    is_vault_migrated,
+};
+use crate::state::{Vault, VaultDepositor, WithdrawRequestSlots};
+use crate::Size;
+
+pub fn initialize_withdraw_request_slots(
+    ctx: Context<InitializeWithdrawRequestSlots>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut withdraw_request_slots = ctx.accounts.withdraw_request_slots.load_init()?;
+
+    withdraw_request_slots.vault_depositor = ctx.accounts.vault_depositor.key();
+    withdraw_request_slots.vault = ctx.accounts.vault.key();
+    withdraw_request_slots.shares_base = vault.shares_base;
+    withdraw_request_slots.bump = ctx.bumps.withdraw_request_slots;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeWithdrawRequestSlots<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"withdraw_request_slots", vault_depositor.key().as_ref()],
+        space = WithdrawRequestSlots::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub withdraw_request_slots: AccountLoader<'info, WithdrawRequestSlots>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::{process_withdraw, Withdraw, WithdrawMode};
+
+pub fn instant_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    min_tokens_out: Option<u64>,
+) -> Result<()> {
+    process_withdraw(ctx, min_tokens_out, WithdrawMode::Instant)
+}
@@ -4,15 +4,27 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
-use crate::constants::permissioned_liquidator;
-use crate::constraints::{is_user_for_vault, is_user_stats_for_vault};
-use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI};
+use crate::constraints::{
+    is_user_for_vault, is_user_stats_for_vault, is_vault_depositor_migrated, is_vault_migrated,
+};
+use crate::drift_cpi::{
+    UpdateSubAccountDelegateCPI, UpdateSubAccountReduceOnlyCPI, UpdateUserDelegateCPI,
+    UpdateUserReduceOnlyCPI,
+};
 use crate::state::{Vault, VaultDepositor, VaultProtocolProvider};
 use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
+use crate::{
+    implement_update_sub_account_delegate_cpi, implement_update_sub_account_reduce_only_cpi,
+};
 use crate::{implement_update_user_reduce_only_cpi, AccountMapProvider};
 
+/// Hands the vault to a liquidator when a depositor can't withdraw after the redeem period. The liquidator
+/// becomes the drift delegate of the primary user and every sub-account, all set reduce-only, for the
+/// vault's liquidation window. `liquidator` must be allowed by the vault and defaults to
+/// [`Vault::get_default_liquidator`].
 pub fn liquidate<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, Liquidate<'info>>,
+    liquidator: Option<Pubkey>,
 ) -> Result<()> {
     let clock = &Clock::get()?;
     let now = Clock::get()?.unix_timestamp;
@@ -31,14 +43,20 @@ pub fn liquidate<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(vault.spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
     // 1. Check the vault depositor has waited the redeem period
     vault_depositor
         .last_withdraw_request
         .check_redeem_period_finished(&vault, now)?;
     // 2. Check that the depositor is unable to withdraw
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
     vault_depositor.check_cant_withdraw(
         &vault,
         vault_equity,
@@ -49,25 +67,37 @@ pub fn liquidate<'c: 'info, 'info>(
     )?;
     // 3. Check that the vault is not already in liquidation
     vault.check_available_for_liquidation(now)?;
+    // settings proposed through propose_vault_params take effect once no liquidation is ongoing
+    vault.apply_pending_liquidation_settings(now);
 
-    vault.set_liquidation_delegate(permissioned_liquidator::id(), now);
+    // 4. Check the liquidator is allowed by the vault
+    let liquidator = liquidator.unwrap_or_else(|| vault.get_default_liquidator());
+    vault.validate_liquidator(&liquidator)?;
+
+    vault.set_liquidation_delegate(liquidator, now);
 
     drop(user);
     drop(vault);
     drop(vp);
 
-    ctx.drift_update_user_delegate(permissioned_liquidator::id())?;
+    ctx.drift_update_user_delegate(liquidator)?;
     ctx.drift_update_user_reduce_only(true)?;
+    ctx.drift_update_sub_accounts_delegate(&equity_accounts.sub_accounts, liquidator)?;
+    ctx.drift_update_sub_accounts_reduce_only(&equity_accounts.sub_accounts, true)?;
 
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct Liquidate<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
     )]
@@ -101,3 +131,32 @@ impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, Liquidate<'in
         Ok(())
     }
 }
+
+impl<'info> UpdateSubAccountDelegateCPI<'info> for Context<'_, '_, '_, 'info, Liquidate<'info>> {
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountReduceOnlyCPI<'info> for Context<'_, '_, '_, 'info, Liquidate<'info>> {
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()> {
+        implement_update_sub_account_reduce_only_cpi!(
+            self,
+            sub_account,
+            sub_account_id,
+            reduce_only
+        );
+        Ok(())
+    }
+}
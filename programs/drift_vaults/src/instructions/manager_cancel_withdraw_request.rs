@@ -3,7 +3,9 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::casting::Cast;
 use drift::state::user::User;
 
-use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
+use crate::constraints::{
+    is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
 use crate::state::{Vault, VaultProtocolProvider};
 use crate::AccountMapProvider;
 
@@ -25,9 +27,15 @@ pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, None, vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     vault.manager_cancel_withdraw_request(&mut vp, vault_equity.cast()?, clock.unix_timestamp)?;
 
@@ -38,6 +46,7 @@ pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
 pub struct ManagerCancelWithdrawRequest<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?
     )]
     pub vault: AccountLoader<'info, Vault>,
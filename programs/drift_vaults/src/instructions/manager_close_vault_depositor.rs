@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_manager_for_vault, is_vault_depositor_migrated, is_vault_for_vault_depositor,
+    is_vault_migrated,
+};
+use crate::state::events::VaultDepositorCloseRecord;
+use crate::state::{Vault, VaultDepositor, WithdrawRequestSlots};
+
+/// Sweeps an abandoned, unfrozen vault depositor with no shares and no pending withdraws, along with its
+/// [`WithdrawRequestSlots`] if passed. The rent is returned to the depositor authority, not the manager.
+pub fn manager_close_vault_depositor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ManagerCloseVaultDepositor<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault_depositor = ctx.accounts.vault_depositor.load()?;
+
+    vault_depositor.validate_can_close(true)?;
+
+    emit!(VaultDepositorCloseRecord {
+        ts: now,
+        vault: vault_depositor.vault,
+        vault_depositor: vault_depositor.pubkey,
+        depositor_authority: vault_depositor.authority,
+        closed_by: ctx.accounts.manager.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManagerCloseVaultDepositor<'info> {
+    #[account(
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = vault_depositor.load()?.authority == depositor_authority.key(),
+        close = depositor_authority,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(mut)]
+    /// CHECK: checked in vault_depositor constraint, receives the rent
+    pub depositor_authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_request_slots", vault_depositor.key().as_ref()],
+        bump,
+        close = depositor_authority,
+    )]
+    pub withdraw_request_slots: Option<AccountLoader<'info, WithdrawRequestSlots>>,
+    pub manager: Signer<'info>,
+}
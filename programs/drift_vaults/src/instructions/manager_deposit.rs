@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use drift::cpi::accounts::Deposit as DriftDeposit;
+use drift::cpi::accounts::{Deposit as DriftDeposit, UpdateUser};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
-use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::drift_cpi::DepositCPI;
+use crate::constraints::{
+    is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
+use crate::drift_cpi::{DepositCPI, DrawdownReduceOnlyCPI};
 use crate::state::{Vault, VaultProtocolProvider};
 use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, AccountMapProvider};
+use crate::{declare_vault_seeds, implement_drawdown_reduce_only_cpi, AccountMapProvider};
 
 pub fn manager_deposit<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ManagerDeposit<'info>>,
@@ -32,9 +34,17 @@ pub fn manager_deposit<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
 
     vault.manager_deposit(&mut vp, amount, vault_equity, clock.unix_timestamp)?;
 
@@ -42,6 +52,8 @@ pub fn manager_deposit<'c: 'info, 'info>(
     drop(user);
     drop(vp);
 
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
     ctx.token_transfer(amount)?;
 
     ctx.drift_deposit(amount)?;
@@ -53,6 +65,7 @@ pub fn manager_deposit<'c: 'info, 'info>(
 pub struct ManagerDeposit<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -134,3 +147,5 @@ impl<'info> DepositCPI for Context<'_, '_, '_, 'info, ManagerDeposit<'info>> {
         Ok(())
     }
 }
+
+implement_drawdown_reduce_only_cpi!(ManagerDeposit);
@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
-use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
+use crate::constraints::{
+    is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
 use crate::state::VaultProtocolProvider;
 use crate::AccountMapProvider;
 use crate::{Vault, WithdrawUnit};
@@ -29,12 +31,25 @@ pub fn manager_request_withdraw<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    // without the drift program, a breach sets the drift users reduce-only in the next instruction that has it
+    vault.check_drawdown(vault_equity, now)?;
 
     vault.manager_request_withdraw(&mut vp, withdraw_amount, withdraw_unit, vault_equity, now)?;
 
+    drop(vault);
+    drop(user);
+    drop(vp);
+
     Ok(())
 }
 
@@ -42,6 +57,7 @@ pub fn manager_request_withdraw<'c: 'info, 'info>(
 pub struct ManagerRequestWithdraw<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -1,16 +1,19 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 use anchor_spl::token::{Token, TokenAccount};
-use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
-use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::drift_cpi::WithdrawCPI;
+use crate::constraints::{
+    is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+    is_withdraw_destination_for_manager,
+};
+use crate::drift_cpi::{DrawdownReduceOnlyCPI, WithdrawCPI};
 use crate::state::{Vault, VaultProtocolProvider};
 use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, AccountMapProvider};
+use crate::{declare_vault_seeds, implement_drawdown_reduce_only_cpi, AccountMapProvider};
 
 pub fn manager_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ManagerWithdraw<'info>>,
@@ -32,9 +35,17 @@ pub fn manager_withdraw<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
 
     let manager_withdraw_amount = vault.manager_withdraw(&mut vp, vault_equity, now)?;
 
@@ -42,6 +53,8 @@ pub fn manager_withdraw<'c: 'info, 'info>(
     drop(user);
     drop(vp);
 
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
     ctx.drift_withdraw(manager_withdraw_amount)?;
 
     ctx.token_transfer(manager_withdraw_amount)?;
@@ -53,6 +66,7 @@ pub fn manager_withdraw<'c: 'info, 'info>(
 pub struct ManagerWithdraw<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -86,7 +100,7 @@ pub struct ManagerWithdraw<'info> {
     pub drift_signer: AccountInfo<'info>,
     #[account(
         mut,
-        token::authority = manager,
+        constraint = is_withdraw_destination_for_manager(&vault, &user_token_account)?,
         token::mint = vault_token_account.mint
     )]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
@@ -140,3 +154,5 @@ impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ManagerWithdraw<'inf
         Ok(())
     }
 }
+
+implement_drawdown_reduce_only_cpi!(ManagerWithdraw);
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Vault;
+use crate::Size;
+
+/// Grows a [`Vault`] created before new fields were added to the account.
+pub fn migrate_vault<'c: 'info, 'info>(
+    _ctx: Context<'_, '_, 'c, 'info, MigrateVault<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    #[account(
+        mut,
+        realloc = Vault::SIZE,
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
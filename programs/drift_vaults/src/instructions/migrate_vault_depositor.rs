@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VaultDepositor;
+use crate::Size;
+
+/// Grows a [`VaultDepositor`] created before new fields were added to the account.
+pub fn migrate_vault_depositor<'c: 'info, 'info>(
+    _ctx: Context<'_, '_, 'c, 'info, MigrateVaultDepositor<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateVaultDepositor<'info> {
+    #[account(
+        mut,
+        realloc = VaultDepositor::SIZE,
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_vault_migrated, is_vault_protocol_for_vault};
+use crate::state::{Vault, VaultProtocol};
+use crate::Size;
+
+/// Grows a [`VaultProtocol`] created before new fields were added to the account.
+pub fn migrate_vault_protocol<'c: 'info, 'info>(
+    _ctx: Context<'_, '_, 'c, 'info, MigrateVaultProtocol<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateVaultProtocol<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        realloc = VaultProtocol::SIZE,
+        realloc::payer = payer,
+        realloc::zero = true,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
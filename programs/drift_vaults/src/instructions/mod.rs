@@ -1,66 +1,146 @@
+pub use accept_vault_manager::*;
+pub use accept_vault_protocol::*;
 pub use add_insurance_fund_stake::*;
 pub use apply_profit_share::*;
 pub use apply_rebase::*;
 pub use apply_rebase_tokenized_depositor::*;
 pub use cancel_request_remove_insurance_fund_stake::*;
 pub use cancel_withdraw_request::*;
+pub use cancel_withdraw_request_slot::*;
+pub use claim_withdraw_epoch::*;
+pub use close_vault::*;
+pub use close_vault_depositor::*;
+pub use close_vault_protocol::*;
 pub use deposit::*;
+pub use deposit_for::*;
 pub use force_withdraw::*;
 pub use initialize_insurance_fund_stake::*;
 pub use initialize_tokenized_vault_depositor::*;
 pub use initialize_vault::*;
 pub use initialize_vault_depositor::*;
+pub use initialize_vault_depositor_with_proof::*;
+pub use initialize_vault_protocol::*;
+pub use initialize_vault_sub_account::*;
 pub use initialize_vault_with_protocol::*;
+pub use initialize_withdraw_request_slots::*;
+pub use instant_withdraw::*;
 pub use liquidate::*;
 pub use manager_cancel_withdraw_request::*;
+pub use manager_close_vault_depositor::*;
 pub use manager_deposit::*;
 pub use manager_request_withdraw::*;
 pub use manager_withdraw::*;
+pub use migrate_vault::*;
+pub use migrate_vault_depositor::*;
+pub use migrate_vault_protocol::*;
+pub use partial_withdraw::*;
+pub use pause_vault::*;
+pub use propose_vault_manager::*;
+pub use propose_vault_params::*;
+pub use propose_vault_protocol::*;
+pub use propose_vault_protocol_params::*;
 pub use protocol_cancel_withdraw_request::*;
 pub use protocol_request_withdraw::*;
 pub use protocol_withdraw::*;
 pub use redeem_tokens::*;
 pub use remove_insurance_fund_stake::*;
+pub use remove_vault_protocol::*;
+pub use renounce_vault_protocol::*;
 pub use request_remove_insurance_fund_stake::*;
 pub use request_withdraw::*;
+pub use request_withdraw_slot::*;
 pub use reset_delegate::*;
+pub use reset_drawdown_breach::*;
+pub use settle_withdraw_epoch::*;
 pub use tokenize_shares::*;
+pub use transfer_sub_account_deposit::*;
+pub use unpause_vault::*;
 pub use update_delegate::*;
+pub use update_insurance_fund_stake_in_equity::*;
+pub use update_manager_withdraw_destination::*;
 pub use update_margin_trading_enabled::*;
 pub use update_vault::*;
+pub use update_vault_depositor_frozen::*;
+pub use update_vault_depositor_limits::*;
 pub use update_vault_protocol::*;
+pub use update_withdraw_destination::*;
+pub use vault_add_insurance_fund_stake::*;
+pub use vault_remove_insurance_fund_stake::*;
+pub use wind_down_vault::*;
+pub use wind_down_withdraw::*;
 pub use withdraw::*;
+pub use withdraw_slot::*;
 
+mod accept_vault_manager;
+mod accept_vault_protocol;
 mod add_insurance_fund_stake;
 mod apply_profit_share;
 mod apply_rebase;
 mod apply_rebase_tokenized_depositor;
 mod cancel_request_remove_insurance_fund_stake;
 mod cancel_withdraw_request;
+mod cancel_withdraw_request_slot;
+mod claim_withdraw_epoch;
+mod close_vault;
+mod close_vault_depositor;
+mod close_vault_protocol;
 pub mod constraints;
 mod deposit;
+mod deposit_for;
 mod force_withdraw;
 mod initialize_insurance_fund_stake;
 mod initialize_tokenized_vault_depositor;
 mod initialize_vault;
 mod initialize_vault_depositor;
+mod initialize_vault_depositor_with_proof;
+mod initialize_vault_protocol;
+mod initialize_vault_sub_account;
 mod initialize_vault_with_protocol;
+mod initialize_withdraw_request_slots;
+mod instant_withdraw;
 mod liquidate;
 mod manager_cancel_withdraw_request;
+mod manager_close_vault_depositor;
 mod manager_deposit;
 mod manager_request_withdraw;
 mod manager_withdraw;
+mod migrate_vault;
+mod migrate_vault_depositor;
+mod migrate_vault_protocol;
+mod partial_withdraw;
+mod pause_vault;
+mod propose_vault_manager;
+mod propose_vault_params;
+mod propose_vault_protocol;
+mod propose_vault_protocol_params;
 mod protocol_cancel_withdraw_request;
 mod protocol_request_withdraw;
 mod protocol_withdraw;
 mod redeem_tokens;
 mod remove_insurance_fund_stake;
+mod remove_vault_protocol;
+mod renounce_vault_protocol;
 mod request_remove_insurance_fund_stake;
 mod request_withdraw;
+mod request_withdraw_slot;
 mod reset_delegate;
+mod reset_drawdown_breach;
+mod settle_withdraw_epoch;
 mod tokenize_shares;
+mod transfer_sub_account_deposit;
+mod unpause_vault;
 mod update_delegate;
+mod update_insurance_fund_stake_in_equity;
+mod update_manager_withdraw_destination;
 mod update_margin_trading_enabled;
 mod update_vault;
+mod update_vault_depositor_frozen;
+mod update_vault_depositor_limits;
 pub mod update_vault_protocol;
+mod update_withdraw_destination;
+mod vault_add_insurance_fund_stake;
+mod vault_remove_insurance_fund_stake;
+mod wind_down_vault;
+mod wind_down_withdraw;
 mod withdraw;
+mod withdraw_slot;
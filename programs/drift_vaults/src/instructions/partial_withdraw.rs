@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::{process_withdraw, Withdraw, WithdrawMode};
+
+/// Withdraws as much of the pending withdraw request as the drift user's free collateral allows,
+/// leaving the remainder pending.
+pub fn partial_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    min_tokens_out: Option<u64>,
+) -> Result<()> {
+    process_withdraw(ctx, min_tokens_out, WithdrawMode::Partial)
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_vault_migrated};
+use crate::drift_cpi::{UpdateSubAccountDelegateCPI, UpdateUserDelegateCPI};
+use crate::error::ErrorCode;
+use crate::implement_update_sub_account_delegate_cpi;
+use crate::state::events::VaultPauseRecord;
+use crate::state::VaultProtocolProvider;
+use crate::{declare_vault_seeds, implement_update_user_delegate_cpi, validate};
+use crate::{AccountMapProvider, Vault};
+
+/// Emergency pause by the manager or, for protocol vaults, the protocol. Deposits and tokenizing are halted
+/// and the drift delegate is set to the null key so the delegate can't trade, while withdrawals continue.
+/// The pause expires after `duration` seconds, at most [`crate::constants::MAX_PAUSE_DURATION`].
+pub fn pause_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PauseVault<'info>>,
+    duration: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let has_vault_protocol = vp.is_some();
+    let vp = vp.as_mut().map(|vp| vp.load()).transpose()?;
+
+    let authority = ctx.accounts.authority.key();
+    validate!(
+        authority == vault.manager || vp.as_ref().is_some_and(|vp| vp.protocol == authority),
+        ErrorCode::InvalidVaultUpdate,
+        "only the manager or protocol can pause the vault"
+    )?;
+
+    let paused_until_ts = vault.pause(authority, duration, now)?;
+
+    emit!(VaultPauseRecord {
+        ts: now,
+        vault: vault.pubkey,
+        authority,
+        paused_until_ts,
+    });
+
+    // the vault's other drift sub-accounts are passed in remaining accounts and also lose their delegate
+    let sub_accounts = ctx.load_sub_accounts(has_vault_protocol)?;
+    vault.validate_sub_accounts(&sub_accounts)?;
+
+    drop(vault);
+    drop(vp);
+
+    ctx.drift_update_user_delegate(Pubkey::default())?;
+    ctx.drift_update_sub_accounts_delegate(&sub_accounts, Pubkey::default())?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PauseVault<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, PauseVault<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountDelegateCPI<'info> for Context<'_, '_, '_, 'info, PauseVault<'info>> {
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
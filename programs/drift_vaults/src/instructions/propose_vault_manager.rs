@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_manager_for_vault, is_vault_migrated};
+use crate::Vault;
+
+pub fn propose_vault_manager<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProposeVaultManager<'info>>,
+    new_manager: Pubkey,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    vault.propose_manager(new_manager)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeVaultManager<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+}
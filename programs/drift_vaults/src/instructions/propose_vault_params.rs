@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
+
+use crate::constants::ONE_DAY;
+use crate::constraints::{is_manager_for_vault, is_vault_migrated};
+use crate::state::{LiquidationSettings, Vault, VaultProtocolProvider};
+use crate::{error::ErrorCode, validate};
+
+pub fn propose_vault_params<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ProposeVaultParams<'info>>,
+    params: ProposeVaultParamsParams,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+    validate!(
+        !vault.wind_down,
+        ErrorCode::VaultWindingDown,
+        "vault is winding down"
+    )?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let vp = vp.as_mut().map(|vp| vp.load()).transpose()?;
+
+    // checked against the protocol's pending fees as well, so the combined fees stay below 100% once
+    // both sides' proposals take effect
+    let management_fee = params.management_fee.unwrap_or(0);
+    let protocol_fee = vp
+        .as_ref()
+        .map_or(0, |vp| vp.protocol_fee.max(vp.pending_protocol_fee));
+    validate!(
+        management_fee.saturating_add(protocol_fee.cast::<i64>()?)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "management fee plus protocol fee must be < 100%"
+    )?;
+
+    let redeem_period = params.redeem_period.unwrap_or(0);
+    validate!(
+        redeem_period < ONE_DAY * 90,
+        ErrorCode::InvalidVaultUpdate,
+        "redeem period must be < 90 days"
+    )?;
+
+    let profit_share = params.profit_share.unwrap_or(0);
+    let protocol_profit_share = vp.as_ref().map_or(0, |vp| {
+        vp.protocol_profit_share
+            .max(vp.pending_protocol_profit_share)
+    });
+    validate!(
+        profit_share.saturating_add(protocol_profit_share) < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager profit share protocol profit share must be < 100%"
+    )?;
+
+    let hurdle_rate = params.hurdle_rate.unwrap_or(0);
+    validate!(
+        hurdle_rate < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "hurdle rate must be < 100%"
+    )?;
+
+    let entry_fee = params.entry_fee.unwrap_or(0);
+    let protocol_entry_fee = vp.as_ref().map_or(0, |vp| {
+        vp.protocol_entry_fee.max(vp.pending_protocol_entry_fee)
+    });
+    validate!(
+        entry_fee.saturating_add(protocol_entry_fee) < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager entry fee plus protocol entry fee must be < 100%"
+    )?;
+
+    let exit_fee = params.exit_fee.unwrap_or(0);
+    let protocol_exit_fee = vp.as_ref().map_or(0, |vp| {
+        vp.protocol_exit_fee.max(vp.pending_protocol_exit_fee)
+    });
+    validate!(
+        exit_fee.saturating_add(protocol_exit_fee) < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager exit fee plus protocol exit fee must be < 100%"
+    )?;
+
+    let early_withdraw_penalty = params.early_withdraw_penalty.unwrap_or(0);
+    validate!(
+        early_withdraw_penalty < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "early withdraw penalty must be < 100%"
+    )?;
+
+    vault.propose_params(
+        management_fee,
+        redeem_period,
+        profit_share,
+        hurdle_rate,
+        entry_fee,
+        exit_fee,
+        early_withdraw_penalty,
+        params.profit_share_crystallization_period.unwrap_or(0),
+        LiquidationSettings {
+            liquidators: params.liquidators,
+            liquidation_window: params.liquidation_window,
+            permissionless_liquidation: params.permissionless_liquidation,
+        },
+        params.effective_ts,
+        now,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ProposeVaultParamsParams {
+    pub management_fee: Option<i64>,
+    pub redeem_period: Option<i64>,
+    pub profit_share: Option<u32>,
+    pub hurdle_rate: Option<u32>,
+    pub entry_fee: Option<u32>,
+    pub exit_fee: Option<u32>,
+    pub early_withdraw_penalty: Option<u32>,
+    pub profit_share_crystallization_period: Option<i64>,
+    pub liquidators: Option<Vec<Pubkey>>,
+    pub liquidation_window: Option<i64>,
+    pub permissionless_liquidation: Option<bool>,
+    pub effective_ts: i64,
+}
+
+#[derive(Accounts)]
+pub struct ProposeVaultParams<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+}
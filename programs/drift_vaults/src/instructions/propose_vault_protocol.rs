@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_protocol_for_vault, is_vault_migrated, is_vault_protocol_for_vault,
+    is_vault_protocol_migrated,
+};
+use crate::state::{Vault, VaultProtocol};
+
+pub fn propose_vault_protocol<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProposeVaultProtocol<'info>>,
+    new_protocol: Pubkey,
+) -> Result<()> {
+    let mut vp = ctx.accounts.vault_protocol.load_mut()?;
+
+    vp.propose_protocol(new_protocol)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeVaultProtocol<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    pub protocol: Signer<'info>,
+}
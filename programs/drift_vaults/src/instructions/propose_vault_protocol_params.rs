@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
+
+use crate::constraints::{
+    is_protocol_for_vault, is_vault_migrated, is_vault_protocol_for_vault,
+    is_vault_protocol_migrated,
+};
+use crate::state::{Vault, VaultProtocol};
+use crate::{error::ErrorCode, validate};
+
+pub fn propose_vault_protocol_params<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ProposeVaultProtocolParams<'info>>,
+    params: ProposeVaultProtocolParamsParams,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = ctx.accounts.vault.load()?;
+    let mut vp = ctx.accounts.vault_protocol.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    // checked against the manager's pending fees as well, so the combined fees stay below 100% once
+    // both sides' proposals take effect
+    let protocol_fee = params.protocol_fee.unwrap_or(0);
+    validate!(
+        vault
+            .management_fee
+            .max(vault.pending_management_fee)
+            .saturating_add(protocol_fee.cast::<i64>()?)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "management fee plus protocol fee must be < 100%"
+    )?;
+
+    let protocol_profit_share = params.protocol_profit_share.unwrap_or(0);
+    validate!(
+        vault
+            .profit_share
+            .max(vault.pending_profit_share)
+            .saturating_add(protocol_profit_share)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager profit share protocol profit share must be < 100%"
+    )?;
+
+    let protocol_entry_fee = params.protocol_entry_fee.unwrap_or(0);
+    validate!(
+        vault
+            .entry_fee
+            .max(vault.pending_entry_fee)
+            .saturating_add(protocol_entry_fee)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager entry fee plus protocol entry fee must be < 100%"
+    )?;
+
+    let protocol_exit_fee = params.protocol_exit_fee.unwrap_or(0);
+    validate!(
+        vault
+            .exit_fee
+            .max(vault.pending_exit_fee)
+            .saturating_add(protocol_exit_fee)
+            < PERCENTAGE_PRECISION_U64.cast()?,
+        ErrorCode::InvalidVaultUpdate,
+        "manager exit fee plus protocol exit fee must be < 100%"
+    )?;
+
+    vp.propose_params(
+        &vault,
+        protocol_fee,
+        protocol_profit_share,
+        protocol_entry_fee,
+        protocol_exit_fee,
+        params.effective_ts,
+        now,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ProposeVaultProtocolParamsParams {
+    pub protocol_fee: Option<u64>,
+    pub protocol_profit_share: Option<u32>,
+    pub protocol_entry_fee: Option<u32>,
+    pub protocol_exit_fee: Option<u32>,
+    pub effective_ts: i64,
+}
+
+#[derive(Accounts)]
+pub struct ProposeVaultProtocolParams<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    pub protocol: Signer<'info>,
+}
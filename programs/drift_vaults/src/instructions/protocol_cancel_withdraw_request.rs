@@ -4,7 +4,8 @@ use drift::math::casting::Cast;
 use drift::state::user::User;
 
 use crate::constraints::{
-    is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
+    is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+    is_vault_protocol_for_vault, is_vault_protocol_migrated,
 };
 use crate::{AccountMapProvider, Vault, VaultProtocol};
 
@@ -23,9 +24,15 @@ pub fn protocol_cancel_withdraw_request<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, None, vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     vault.protocol_cancel_withdraw_request(&mut vp, vault_equity.cast()?, clock.unix_timestamp)?;
 
@@ -36,6 +43,8 @@ pub fn protocol_cancel_withdraw_request<'c: 'info, 'info>(
 pub struct ProtocolCancelWithdrawRequest<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
         constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -3,7 +3,8 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
 use crate::constraints::{
-    is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
+    is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+    is_vault_protocol_for_vault, is_vault_protocol_migrated,
 };
 use crate::{AccountMapProvider, Vault, VaultProtocol, WithdrawUnit};
 
@@ -27,12 +28,25 @@ pub fn protocol_request_withdraw<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    // without the drift program, a breach sets the drift users reduce-only in the next instruction that has it
+    vault.check_drawdown(vault_equity, now)?;
 
     vault.protocol_request_withdraw(&mut vp, withdraw_amount, withdraw_unit, vault_equity, now)?;
 
+    drop(vault);
+    drop(user);
+    drop(vp);
+
     Ok(())
 }
 
@@ -40,6 +54,8 @@ pub fn protocol_request_withdraw<'c: 'info, 'info>(
 pub struct ProtocolRequestWithdraw<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
         constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
     )]
     pub vault: AccountLoader<'info, Vault>,
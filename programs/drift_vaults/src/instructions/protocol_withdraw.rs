@@ -1,18 +1,19 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 use anchor_spl::token::{Token, TokenAccount};
-use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
 use drift::state::user::User;
 
 use crate::constraints::{
-    is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
+    is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_migrated,
+    is_vault_protocol_for_vault, is_vault_protocol_migrated,
 };
-use crate::drift_cpi::WithdrawCPI;
+use crate::drift_cpi::{DrawdownReduceOnlyCPI, WithdrawCPI};
 use crate::state::{Vault, VaultProtocol};
 use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, AccountMapProvider};
+use crate::{declare_vault_seeds, implement_drawdown_reduce_only_cpi, AccountMapProvider};
 
 pub fn protocol_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ProtocolWithdraw<'info>>,
@@ -31,9 +32,17 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
 
     let protocol_withdraw_amount = vault.protocol_withdraw(&mut vp, vault_equity, now)?;
 
@@ -41,6 +50,8 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
     drop(user);
     drop(vp);
 
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
     ctx.drift_withdraw(protocol_withdraw_amount)?;
 
     ctx.token_transfer(protocol_withdraw_amount)?;
@@ -52,6 +63,8 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
 pub struct ProtocolWithdraw<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
         constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -144,3 +157,5 @@ impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ProtocolWithdraw<'in
         Ok(())
     }
 }
+
+implement_drawdown_reduce_only_cpi!(ProtocolWithdraw);
@@ -1,6 +1,7 @@
 use crate::constraints::{
     is_ata, is_authority_for_vault_depositor, is_mint_for_tokenized_depositor,
-    is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_vault_depositor_migrated,
+    is_vault_migrated,
 };
 use crate::error::ErrorCode;
 use crate::state::traits::VaultDepositorBase;
@@ -44,9 +45,15 @@ pub fn redeem_tokens<'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
-
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     validate!(
         !vault_depositor.last_withdraw_request.pending(),
@@ -129,10 +136,14 @@ pub fn redeem_tokens<'info>(
 
 #[derive(Accounts)]
 pub struct RedeemTokens<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
         constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
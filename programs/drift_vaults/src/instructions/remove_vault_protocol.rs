@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_protocol_for_vault, is_vault_migrated, is_vault_protocol_for_vault,
+    is_vault_protocol_migrated,
+};
+use crate::state::{Vault, VaultProtocol};
+
+pub fn remove_vault_protocol<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveVaultProtocol<'info>>,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let vp = ctx.accounts.vault_protocol.load()?;
+
+    vp.validate_can_remove()?;
+
+    vault.vault_protocol = false;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveVaultProtocol<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?,
+        close = protocol,
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(mut)]
+    pub protocol: Signer<'info>,
+}
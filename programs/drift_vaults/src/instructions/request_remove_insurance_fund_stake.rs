@@ -5,7 +5,10 @@ use drift::program::Drift;
 use drift::state::insurance_fund_stake::InsuranceFundStake;
 use drift::state::spot_market::SpotMarket;
 
-use crate::constraints::{is_if_stake_for_vault, is_manager_for_vault, is_user_stats_for_vault};
+use crate::constraints::{
+    is_delegate_for_vault_if_stake, is_if_stake_for_vault, is_manager_for_vault,
+    is_user_stats_for_vault, is_vault_migrated,
+};
 use crate::drift_cpi::RequestRemoveInsuranceFundStakeCPI;
 use crate::{declare_vault_seeds, Vault};
 
@@ -23,10 +26,11 @@ pub fn request_remove_insurance_fund_stake<'info>(
 pub struct RequestRemoveInsuranceFundStake<'info> {
     #[account(
         mut,
-        constraint = is_manager_for_vault(&vault, &manager)?,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &authority)? || is_delegate_for_vault_if_stake(&vault, &authority, market_index)?,
     )]
     pub vault: AccountLoader<'info, Vault>,
-    pub manager: Signer<'info>,
+    pub authority: Signer<'info>,
     #[account(
         mut,
         seeds = [b"spot_market", market_index.to_le_bytes().as_ref()],
@@ -5,18 +5,21 @@ use drift::state::user::User;
 
 use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated,
 };
+use crate::error::ErrorCode;
 use crate::state::account_maps::AccountMapProvider;
 use crate::state::{Vault, VaultProtocolProvider};
-use crate::{VaultDepositor, WithdrawUnit};
+use crate::{validate, VaultDepositor, WithdrawUnit};
 
 pub fn request_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, RequestWithdraw<'info>>,
     withdraw_amount: u64,
     withdraw_unit: WithdrawUnit,
+    max_shares_burned: Option<u128>,
 ) -> Result<()> {
     let clock = &Clock::get()?;
-    let vault = &mut ctx.accounts.vault.load_mut()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
     let user = ctx.accounts.drift_user.load()?;
@@ -30,28 +33,56 @@ pub fn request_withdraw<'c: 'info, 'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, None, vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    // without the drift program, a breach sets the drift users reduce-only in the next instruction that has it
+    vault.check_drawdown(vault_equity, clock.unix_timestamp)?;
 
     vault_depositor.request_withdraw(
         withdraw_amount.cast()?,
         withdraw_unit,
         vault_equity,
-        vault,
+        &mut vault,
         &mut vp,
         clock.unix_timestamp,
     )?;
 
+    if let Some(max_shares_burned) = max_shares_burned {
+        let request_shares = vault_depositor.last_withdraw_request.shares;
+        validate!(
+            request_shares <= max_shares_burned,
+            ErrorCode::SlippageExceeded,
+            "withdraw request for {} shares > max_shares_burned {}",
+            request_shares,
+            max_shares_burned
+        )?;
+    }
+
+    drop(vault);
+    drop(user);
+    drop(vp);
+
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct RequestWithdraw<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
         constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
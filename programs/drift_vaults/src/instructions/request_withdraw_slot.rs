@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated,
+};
+use crate::drift_cpi::DrawdownReduceOnlyCPI;
+use crate::state::account_maps::AccountMapProvider;
+use crate::state::{Vault, VaultProtocolProvider, WithdrawRequestSlots};
+use crate::{
+    declare_vault_seeds, implement_drawdown_reduce_only_cpi, VaultDepositor, WithdrawUnit,
+};
+
+pub fn request_withdraw_slot<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RequestWithdrawSlot<'info>>,
+    withdraw_amount: u64,
+    withdraw_unit: WithdrawUnit,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut withdraw_request_slots = ctx.accounts.withdraw_request_slots.load_mut()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
+
+    let slot = vault_depositor.request_withdraw_slot(
+        &mut withdraw_request_slots,
+        withdraw_amount.cast()?,
+        withdraw_unit,
+        vault_equity,
+        &mut vault,
+        &mut vp,
+        clock.unix_timestamp,
+    )?;
+
+    msg!("withdraw request slot: {}", slot);
+
+    drop(vault);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestWithdrawSlot<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_request_slots", vault_depositor.key().as_ref()],
+        bump,
+    )]
+    pub withdraw_request_slots: AccountLoader<'info, WithdrawRequestSlots>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: unused, for future proofing
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+implement_drawdown_reduce_only_cpi!(RequestWithdrawSlot);
@@ -3,16 +3,26 @@ use drift::cpi::accounts::UpdateUser;
 use drift::program::Drift;
 use drift::state::user::User;
 
-use crate::constraints::is_user_for_vault;
-use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI};
+use crate::constraints::{is_user_for_vault, is_vault_migrated};
+use crate::drift_cpi::{
+    UpdateSubAccountDelegateCPI, UpdateSubAccountReduceOnlyCPI, UpdateUserDelegateCPI,
+    UpdateUserReduceOnlyCPI,
+};
 use crate::error::ErrorCode;
 use crate::state::Vault;
-use crate::validate;
 use crate::{
     declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
 };
+use crate::{
+    implement_update_sub_account_delegate_cpi, implement_update_sub_account_reduce_only_cpi,
+};
+use crate::{validate, AccountMapProvider};
 
-pub fn reset_delegate<'info>(ctx: Context<'_, '_, '_, 'info, ResetDelegate<'info>>) -> Result<()> {
+/// Ends an expired liquidation, restoring the vault delegate on the primary user and the sub-accounts,
+/// which are passed in the remaining_accounts.
+pub fn reset_delegate<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ResetDelegate<'info>>,
+) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
 
     validate!(
@@ -25,19 +35,28 @@ pub fn reset_delegate<'info>(ctx: Context<'_, '_, '_, 'info, ResetDelegate<'info
     vault.check_can_exit_liquidation(now)?;
     vault.reset_liquidation_delegate();
 
-    let delegate = vault.delegate;
+    let delegate = vault.get_drift_delegate(now);
+    let reduce_only = vault.in_drawdown_breach();
+
+    let sub_accounts = ctx.load_sub_accounts(false)?;
+    vault.validate_sub_accounts(&sub_accounts)?;
 
     drop(vault);
 
     ctx.drift_update_user_delegate(delegate)?;
-    ctx.drift_update_user_reduce_only(false)?;
+    ctx.drift_update_user_reduce_only(reduce_only)?;
+    ctx.drift_update_sub_accounts_delegate(&sub_accounts, delegate)?;
+    ctx.drift_update_sub_accounts_reduce_only(&sub_accounts, reduce_only)?;
 
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct ResetDelegate<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
@@ -61,3 +80,36 @@ impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, ResetDelegate
         Ok(())
     }
 }
+
+impl<'info> UpdateSubAccountDelegateCPI<'info>
+    for Context<'_, '_, '_, 'info, ResetDelegate<'info>>
+{
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountReduceOnlyCPI<'info>
+    for Context<'_, '_, '_, 'info, ResetDelegate<'info>>
+{
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()> {
+        implement_update_sub_account_reduce_only_cpi!(
+            self,
+            sub_account,
+            sub_account_id,
+            reduce_only
+        );
+        Ok(())
+    }
+}
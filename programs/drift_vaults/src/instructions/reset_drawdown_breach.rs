@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_vault_migrated};
+use crate::drift_cpi::{UpdateSubAccountReduceOnlyCPI, UpdateUserReduceOnlyCPI};
+use crate::implement_update_sub_account_reduce_only_cpi;
+use crate::state::{Vault, VaultProtocolProvider};
+use crate::{declare_vault_seeds, implement_update_user_reduce_only_cpi, AccountMapProvider};
+
+/// Lifts reduce-only after a max drawdown breach once the cooldown has passed. The share price high water
+/// mark restarts at the current share price.
+pub fn reset_drawdown_breach<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ResetDrawdownBreach<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let vp = vp.as_mut().map(|vp| vp.load()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    vault.reset_drawdown_breach(vault_equity, clock.unix_timestamp)?;
+
+    // liquidation keeps the vault reduce-only until it ends
+    let reduce_only = vault.in_liquidation();
+
+    drop(vault);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_update_user_reduce_only(reduce_only)?;
+    ctx.drift_update_sub_accounts_reduce_only(&equity_accounts.sub_accounts, reduce_only)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResetDrawdownBreach<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, ResetDrawdownBreach<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountReduceOnlyCPI<'info>
+    for Context<'_, '_, '_, 'info, ResetDrawdownBreach<'info>>
+{
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()> {
+        implement_update_sub_account_reduce_only_cpi!(
+            self,
+            sub_account,
+            sub_account_id,
+            reduce_only
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_user_stats_for_vault, is_vault_migrated};
+use crate::drift_cpi::{
+    DrawdownReduceOnlyCPI, UpdateSubAccountDelegateCPI, UpdateSubAccountReduceOnlyCPI,
+    UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI,
+};
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultProtocolProvider, WithdrawEpoch};
+use crate::{
+    declare_vault_seeds, implement_drawdown_reduce_only_cpi, implement_update_user_delegate_cpi,
+    implement_update_user_reduce_only_cpi, implement_withdraw, validate, AccountMapProvider, Size,
+};
+use crate::{
+    implement_update_sub_account_delegate_cpi, implement_update_sub_account_reduce_only_cpi,
+};
+
+/// Settles the vault's current withdraw epoch with up to `amount` of collateral freed by the manager.
+/// The filled amount is withdrawn from drift into the vault token account for depositors to claim.
+/// The manager, delegate or liquidator can settle at any time, anyone else once the queue is overdue.
+pub fn settle_withdraw_epoch<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SettleWithdrawEpoch<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut withdraw_epoch = ctx.accounts.withdraw_epoch.load_init()?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let authority = ctx.accounts.authority.key();
+    validate!(
+        authority == vault.manager
+            || authority == vault.delegate
+            || (vault.in_liquidation() && authority == vault.liquidation_delegate)
+            || vault.is_withdraw_queue_overdue(clock.unix_timestamp)?,
+        ErrorCode::InvalidVaultWithdraw,
+        "only the manager or delegate can settle before the oldest request waited the redeem period"
+    )?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
+
+    withdraw_epoch.pubkey = ctx.accounts.withdraw_epoch.key();
+    withdraw_epoch.bump = ctx.bumps.withdraw_epoch;
+
+    let settle_amount = vault.settle_withdraw_epoch(
+        &mut withdraw_epoch,
+        &mut vp,
+        amount,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    msg!("settle_amount: {}", settle_amount);
+
+    // a liquidation is over once the withdraw queue is emptied
+    let finishing_liquidation = vault.in_liquidation() && vault.withdraw_queue_shares == 0;
+
+    drop(vault);
+    drop(withdraw_epoch);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
+    if settle_amount > 0 {
+        ctx.drift_withdraw(settle_amount)?;
+    }
+
+    if finishing_liquidation {
+        let mut vault = ctx.accounts.vault.load_mut()?;
+        vault.reset_liquidation_delegate();
+        let vault_delegate = vault.get_drift_delegate(clock.unix_timestamp);
+        let reduce_only = vault.in_drawdown_breach();
+        drop(vault);
+
+        ctx.drift_update_user_delegate(vault_delegate)?;
+        ctx.drift_update_user_reduce_only(reduce_only)?;
+        ctx.drift_update_sub_accounts_delegate(&equity_accounts.sub_accounts, vault_delegate)?;
+        ctx.drift_update_sub_accounts_reduce_only(&equity_accounts.sub_accounts, reduce_only)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleWithdrawEpoch<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"withdraw_epoch", vault.key().as_ref(), vault.load()?.withdraw_epoch.to_le_bytes().as_ref()],
+        space = WithdrawEpoch::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub withdraw_epoch: AccountLoader<'info, WithdrawEpoch>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, SettleWithdrawEpoch<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, SettleWithdrawEpoch<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, SettleWithdrawEpoch<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountDelegateCPI<'info>
+    for Context<'_, '_, '_, 'info, SettleWithdrawEpoch<'info>>
+{
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountReduceOnlyCPI<'info>
+    for Context<'_, '_, '_, 'info, SettleWithdrawEpoch<'info>>
+{
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()> {
+        implement_update_sub_account_reduce_only_cpi!(
+            self,
+            sub_account,
+            sub_account_id,
+            reduce_only
+        );
+        Ok(())
+    }
+}
+
+implement_drawdown_reduce_only_cpi!(SettleWithdrawEpoch);
@@ -1,6 +1,7 @@
 use crate::constraints::{
     is_authority_for_vault_depositor, is_mint_for_tokenized_depositor,
-    is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_vault_depositor_migrated,
+    is_vault_migrated,
 };
 use crate::error::ErrorCode;
 use crate::state::traits::VaultDepositorBase;
@@ -25,6 +26,12 @@ pub fn tokenize_shares<'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
 
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+    validate!(
+        !vault.is_paused(clock.unix_timestamp),
+        ErrorCode::VaultPaused,
+        "vault is paused until {}",
+        vault.paused_until_ts
+    )?;
 
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
     let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
@@ -53,15 +60,39 @@ pub fn tokenize_shares<'info>(
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
-
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     validate!(
         !vault_depositor.last_withdraw_request.pending(),
         ErrorCode::InvalidVaultDeposit,
         "Cannot tokenize shares with a pending withdraw request"
     )?;
+    validate!(
+        vault_depositor.withdraw_request_slot_count == 0,
+        ErrorCode::InvalidVaultDeposit,
+        "Cannot tokenize shares with pending withdraw request slots"
+    )?;
+    validate!(
+        !vault_depositor.frozen,
+        ErrorCode::VaultDepositorFrozen,
+        "vault depositor is frozen"
+    )?;
+    // tokens can be redeemed by any holder, so tokenizing would get around the destination timelock
+    validate!(
+        !vault_depositor
+            .withdraw_destination
+            .is_registered(clock.unix_timestamp),
+        ErrorCode::InvalidVaultDeposit,
+        "Cannot tokenize shares while a withdraw destination is registered"
+    )?;
 
     let total_supply_before = ctx.accounts.mint.supply;
 
@@ -131,10 +162,14 @@ pub fn tokenize_shares<'info>(
 
 #[derive(Accounts)]
 pub struct TokenizeShares<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
         constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use drift::cpi::accounts::TransferDeposit;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_delegate_for_vault, is_liquidation_delegate_for_vault, is_manager_for_vault,
+    is_sub_account_for_vault, is_user_stats_for_vault, is_vault_migrated,
+};
+use crate::declare_vault_seeds;
+use crate::drift_cpi::TransferDepositCPI;
+use crate::{error::ErrorCode, validate, Vault};
+
+/// Moves collateral between two drift sub-accounts owned by the vault. Vault equity is unchanged.
+/// During a liquidation only the liquidator can transfer, and only into the primary `user` that
+/// depositor withdrawals are paid from.
+pub fn transfer_sub_account_deposit<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, TransferSubAccountDeposit<'info>>,
+    market_index: u16,
+    amount: u64,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    if vault.in_liquidation() {
+        validate!(
+            vault.liquidation_delegate == ctx.accounts.manager.key()
+                && vault.user == ctx.accounts.to_user.key(),
+            ErrorCode::OngoingLiquidation,
+            "only the liquidator can transfer during a liquidation, into the primary user"
+        )?;
+    }
+    drop(vault);
+
+    validate!(
+        ctx.accounts.from_user.key() != ctx.accounts.to_user.key(),
+        ErrorCode::InvalidVaultUpdate,
+        "cannot transfer to the same sub-account"
+    )?;
+
+    ctx.drift_transfer_deposit(market_index, amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(market_index: u16)]
+pub struct TransferSubAccountDeposit<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?
+            || is_delegate_for_vault(&vault, &manager)?
+            || is_liquidation_delegate_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_sub_account_for_vault(&vault, &from_user)?
+    )]
+    pub from_user: AccountLoader<'info, User>,
+    #[account(
+        mut,
+        constraint = is_sub_account_for_vault(&vault, &to_user)?
+    )]
+    pub to_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        seeds = [b"spot_market_vault".as_ref(), market_index.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = drift_program.key(),
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> TransferDepositCPI for Context<'_, '_, '_, 'info, TransferSubAccountDeposit<'info>> {
+    fn drift_transfer_deposit(&self, market_index: u16, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = TransferDeposit {
+            from_user: self.accounts.from_user.to_account_info().clone(),
+            to_user: self.accounts.to_user.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            state: self.accounts.drift_state.clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        drift::cpi::transfer_deposit(cpi_context, market_index, amount)?;
+
+        Ok(())
+    }
+}
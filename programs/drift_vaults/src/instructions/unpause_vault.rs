@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_vault_migrated};
+use crate::drift_cpi::{UpdateSubAccountDelegateCPI, UpdateUserDelegateCPI};
+use crate::implement_update_sub_account_delegate_cpi;
+use crate::state::events::VaultPauseRecord;
+use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
+use crate::{AccountMapProvider, Vault};
+
+/// Lifts an emergency pause and restores the drift delegate. Before the pause expires only the manager or
+/// protocol that paused the vault can lift it, after that the manager can too.
+pub fn unpause_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UnpauseVault<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let authority = ctx.accounts.authority.key();
+    vault.unpause(authority, now)?;
+
+    emit!(VaultPauseRecord {
+        ts: now,
+        vault: vault.pubkey,
+        authority,
+        paused_until_ts: 0,
+    });
+
+    // the vault's other drift sub-accounts are passed in remaining accounts and get the delegate back
+    let sub_accounts = ctx.load_sub_accounts(false)?;
+    vault.validate_sub_accounts(&sub_accounts)?;
+
+    let drift_delegate = vault.get_drift_delegate(now);
+
+    drop(vault);
+
+    ctx.drift_update_user_delegate(drift_delegate)?;
+    ctx.drift_update_sub_accounts_delegate(&sub_accounts, drift_delegate)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnpauseVault<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, UnpauseVault<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountDelegateCPI<'info> for Context<'_, '_, '_, 'info, UnpauseVault<'info>> {
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
@@ -3,28 +3,50 @@ use drift::cpi::accounts::UpdateUser;
 use drift::program::Drift;
 use drift::state::user::User;
 
-use crate::constraints::{is_manager_for_vault, is_user_for_vault};
-use crate::drift_cpi::UpdateUserDelegateCPI;
-use crate::Vault;
+use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_vault_migrated};
+use crate::drift_cpi::{
+    UpdateSubAccountDelegateCPI, UpdateSubAccountReduceOnlyCPI, UpdateUserDelegateCPI,
+    UpdateUserReduceOnlyCPI,
+};
 use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
+use crate::{
+    implement_update_sub_account_delegate_cpi, implement_update_sub_account_reduce_only_cpi,
+    implement_update_user_reduce_only_cpi,
+};
+use crate::{AccountMapProvider, Vault};
 
-pub fn update_delegate<'info>(
-    ctx: Context<'_, '_, '_, 'info, UpdateDelegate<'info>>,
+pub fn update_delegate<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateDelegate<'info>>,
     delegate: Pubkey,
 ) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
     let mut vault = ctx.accounts.vault.load_mut()?;
 
-    if vault.in_liquidation() {
-        let now = Clock::get()?.unix_timestamp;
+    let exiting_liquidation = vault.in_liquidation();
+    if exiting_liquidation {
         vault.check_can_exit_liquidation(now)?;
         vault.reset_liquidation_delegate();
     }
 
     vault.delegate = delegate;
+    // while paused the drift users keep the null delegate until the vault is unpaused
+    let delegate = vault.get_drift_delegate(now);
+    let reduce_only = vault.in_drawdown_breach();
+
+    // the vault's other drift sub-accounts are passed in remaining accounts and get the same delegate
+    let sub_accounts = ctx.load_sub_accounts(false)?;
+    vault.validate_sub_accounts(&sub_accounts)?;
 
     drop(vault);
 
     ctx.drift_update_user_delegate(delegate)?;
+    ctx.drift_update_sub_accounts_delegate(&sub_accounts, delegate)?;
+
+    // the liquidation left every drift user reduce-only
+    if exiting_liquidation {
+        ctx.drift_update_user_reduce_only(reduce_only)?;
+        ctx.drift_update_sub_accounts_reduce_only(&sub_accounts, reduce_only)?;
+    }
 
     Ok(())
 }
@@ -33,6 +55,7 @@ pub fn update_delegate<'info>(
 pub struct UpdateDelegate<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?,
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -52,3 +75,43 @@ impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, UpdateDelegate<
         Ok(())
     }
 }
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, UpdateDelegate<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountDelegateCPI<'info>
+    for Context<'_, '_, '_, 'info, UpdateDelegate<'info>>
+{
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountReduceOnlyCPI<'info>
+    for Context<'_, '_, '_, 'info, UpdateDelegate<'info>>
+{
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()> {
+        implement_update_sub_account_reduce_only_cpi!(
+            self,
+            sub_account,
+            sub_account_id,
+            reduce_only
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use drift::state::insurance_fund_stake::InsuranceFundStake;
+
+use crate::constraints::{is_if_stake_for_vault, is_manager_for_vault, is_vault_migrated};
+use crate::{error::ErrorCode, validate, Vault};
+
+pub fn update_insurance_fund_stake_in_equity<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateInsuranceFundStakeInEquity<'info>>,
+    enabled: bool,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let if_stake = ctx.accounts.insurance_fund_stake.load()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    // switching between manager-funded and depositor-funded stake is only possible while the stake is empty
+    validate!(
+        if_stake.if_shares == 0 && if_stake.last_withdraw_request_shares == 0,
+        ErrorCode::InvalidVaultUpdate,
+        "insurance fund stake must be empty to change whether it is counted in vault equity"
+    )?;
+
+    vault.if_stake_in_equity = enabled;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateInsuranceFundStakeInEquity<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        seeds = [b"insurance_fund_stake", vault.key().as_ref(), vault.load()?.spot_market_index.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = drift::id(),
+        constraint = is_if_stake_for_vault(&insurance_fund_stake, &vault)?,
+    )]
+    pub insurance_fund_stake: AccountLoader<'info, InsuranceFundStake>,
+}
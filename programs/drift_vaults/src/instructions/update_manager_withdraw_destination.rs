@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constraints::{is_manager_for_vault, is_vault_migrated};
+use crate::state::events::WithdrawDestinationUpdateRecord;
+use crate::state::Vault;
+
+/// Registers the token account manager withdrawals are paid to. The change takes effect after the
+/// withdraw destination timelock, and is cleared when the vault changes manager.
+pub fn update_manager_withdraw_destination<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateManagerWithdrawDestination<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let old_destination = vault.manager_withdraw_destination.get(now);
+    let new_destination = ctx.accounts.withdraw_destination.key();
+    let effective_ts = vault
+        .manager_withdraw_destination
+        .propose(new_destination, now)?;
+
+    emit!(WithdrawDestinationUpdateRecord {
+        ts: now,
+        vault: vault.pubkey,
+        authority: vault.manager,
+        old_destination: old_destination.unwrap_or_default(),
+        new_destination,
+        effective_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateManagerWithdrawDestination<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        token::mint = vault_token_account.mint
+    )]
+    pub withdraw_destination: Box<Account<'info, TokenAccount>>,
+}
@@ -3,7 +3,7 @@ use drift::cpi::accounts::UpdateUser;
 use drift::program::Drift;
 use drift::state::user::User;
 
-use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_vault_migrated};
 use crate::drift_cpi::UpdateUserMarginTradingEnabledCPI;
 use crate::error::ErrorCode;
 use crate::Vault;
@@ -27,6 +27,7 @@ pub fn update_margin_trading_enabled<'info>(
 pub struct UpdateMarginTradingEnabled<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?,
     )]
     pub vault: AccountLoader<'info, Vault>,
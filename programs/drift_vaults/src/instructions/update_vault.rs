@@ -1,4 +1,4 @@
-use crate::constraints::is_manager_for_vault;
+use crate::constraints::{is_manager_for_vault, is_vault_migrated};
 use crate::{error::ErrorCode, validate, Vault};
 use anchor_lang::prelude::*;
 
@@ -27,6 +27,34 @@ pub fn update_vault<'info>(
         vault.min_deposit_amount = min_deposit_amount;
     }
 
+    if let Some(max_depositor_equity) = params.max_depositor_equity {
+        vault.max_depositor_equity = max_depositor_equity;
+    }
+
+    if let Some(min_depositor_balance) = params.min_depositor_balance {
+        vault.min_depositor_balance = min_depositor_balance;
+    }
+
+    if let Some(allowlist_merkle_root) = params.allowlist_merkle_root {
+        vault.allowlist_merkle_root = allowlist_merkle_root;
+    }
+
+    if let Some(access_mint) = params.access_mint {
+        vault.access_mint = access_mint;
+    }
+
+    if let Some(access_min_balance) = params.access_min_balance {
+        vault.access_min_balance = access_min_balance;
+    }
+
+    if let Some(access_collection) = params.access_collection {
+        vault.access_collection = access_collection;
+    }
+
+    if let Some(max_drawdown) = params.max_drawdown {
+        vault.update_max_drawdown(max_drawdown, Clock::get()?.unix_timestamp)?;
+    }
+
     if let Some(management_fee) = params.management_fee {
         validate!(
             management_fee < vault.management_fee,
@@ -54,6 +82,52 @@ pub fn update_vault<'info>(
         vault.hurdle_rate = hurdle_rate;
     }
 
+    if let Some(entry_fee) = params.entry_fee {
+        validate!(
+            entry_fee < vault.entry_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "new entry fee must be less than existing entry fee"
+        )?;
+        vault.entry_fee = entry_fee;
+    }
+
+    if let Some(exit_fee) = params.exit_fee {
+        validate!(
+            exit_fee < vault.exit_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "new exit fee must be less than existing exit fee"
+        )?;
+        vault.exit_fee = exit_fee;
+    }
+
+    // increases, including enabling instant withdraws, are announced with propose_vault_params
+    if let Some(early_withdraw_penalty) = params.early_withdraw_penalty {
+        validate!(
+            early_withdraw_penalty < vault.early_withdraw_penalty,
+            ErrorCode::InvalidVaultUpdate,
+            "new early withdraw penalty must be less than existing early withdraw penalty"
+        )?;
+        vault.early_withdraw_penalty = early_withdraw_penalty;
+    }
+
+    if let Some(early_withdraw_penalty_to_manager) = params.early_withdraw_penalty_to_manager {
+        validate!(
+            !early_withdraw_penalty_to_manager,
+            ErrorCode::InvalidVaultUpdate,
+            "early withdraw penalty can only be redirected to the vault"
+        )?;
+        vault.early_withdraw_penalty_to_manager = early_withdraw_penalty_to_manager;
+    }
+
+    if let Some(withdraw_queue) = params.withdraw_queue {
+        validate!(
+            vault.total_withdraw_requested == 0 && vault.withdraw_queue_shares == 0,
+            ErrorCode::InvalidVaultUpdate,
+            "cannot change withdraw queue while withdraw requests are pending"
+        )?;
+        vault.withdraw_queue = withdraw_queue;
+    }
+
     if let Some(permissioned) = params.permissioned {
         vault.permissioned = permissioned;
     }
@@ -72,12 +146,25 @@ pub struct UpdateVaultParams {
     pub profit_share: Option<u32>,
     pub hurdle_rate: Option<u32>,
     pub permissioned: Option<bool>,
+    pub entry_fee: Option<u32>,
+    pub exit_fee: Option<u32>,
+    pub early_withdraw_penalty: Option<u32>,
+    pub early_withdraw_penalty_to_manager: Option<bool>,
+    pub withdraw_queue: Option<bool>,
+    pub max_depositor_equity: Option<u64>,
+    pub min_depositor_balance: Option<u64>,
+    pub allowlist_merkle_root: Option<[u8; 32]>,
+    pub access_mint: Option<Pubkey>,
+    pub access_min_balance: Option<u64>,
+    pub access_collection: Option<Pubkey>,
+    pub max_drawdown: Option<u32>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateVault<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
         constraint = is_manager_for_vault(&vault, &manager)?,
     )]
     pub vault: AccountLoader<'info, Vault>,
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_manager_for_vault, is_vault_depositor_migrated, is_vault_for_vault_depositor,
+    is_vault_migrated,
+};
+use crate::state::events::VaultDepositorFreezeRecord;
+use crate::state::{Vault, VaultDepositor};
+
+/// Freezes or unfreezes a vault depositor. A frozen depositor can't deposit, request withdraws or tokenize
+/// shares, but can still be exited with `force_withdraw`.
+pub fn update_vault_depositor_frozen<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorFrozen<'info>>,
+    frozen: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+
+    vault_depositor.frozen = frozen;
+
+    emit!(VaultDepositorFreezeRecord {
+        ts: now,
+        vault: vault_depositor.vault,
+        vault_depositor: vault_depositor.pubkey,
+        depositor_authority: vault_depositor.authority,
+        manager: ctx.accounts.manager.key(),
+        frozen,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorFrozen<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub manager: Signer<'info>,
+}
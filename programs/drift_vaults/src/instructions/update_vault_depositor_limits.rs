@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_manager_for_vault, is_vault_depositor_migrated, is_vault_for_vault_depositor,
+    is_vault_migrated,
+};
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultDepositor};
+use crate::validate;
+
+/// Sets per-depositor overrides of the vault's max_depositor_equity and min_depositor_balance.
+/// 0 falls back to the vault setting.
+pub fn update_vault_depositor_limits<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorLimits<'info>>,
+    params: UpdateVaultDepositorLimitsParams,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+
+    if let Some(max_equity_override) = params.max_equity_override {
+        vault_depositor.max_equity_override = max_equity_override;
+    }
+
+    if let Some(min_balance_override) = params.min_balance_override {
+        vault_depositor.min_balance_override = min_balance_override;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct UpdateVaultDepositorLimitsParams {
+    pub max_equity_override: Option<u64>,
+    pub min_balance_override: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorLimits<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub manager: Signer<'info>,
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::constraints::{
+    is_protocol_for_vault, is_vault_migrated, is_vault_protocol_for_vault,
+    is_vault_protocol_migrated,
+};
 use crate::state::{Vault, VaultProtocol};
 use crate::{error::ErrorCode, validate};
 
@@ -33,6 +36,24 @@ pub fn update_vault_protocol<'c: 'info, 'info>(
             )?;
             vp.protocol_profit_share = new_protocol_profit_share;
         }
+
+        if let Some(new_protocol_entry_fee) = params.protocol_entry_fee {
+            validate!(
+                new_protocol_entry_fee < vp.protocol_entry_fee,
+                ErrorCode::InvalidVaultUpdate,
+                "new protocol entry fee must be less than existing protocol entry fee"
+            )?;
+            vp.protocol_entry_fee = new_protocol_entry_fee;
+        }
+
+        if let Some(new_protocol_exit_fee) = params.protocol_exit_fee {
+            validate!(
+                new_protocol_exit_fee < vp.protocol_exit_fee,
+                ErrorCode::InvalidVaultUpdate,
+                "new protocol exit fee must be less than existing protocol exit fee"
+            )?;
+            vp.protocol_exit_fee = new_protocol_exit_fee;
+        }
     }
 
     drop(vault);
@@ -44,12 +65,16 @@ pub fn update_vault_protocol<'c: 'info, 'info>(
 pub struct UpdateVaultProtocolParams {
     pub protocol_fee: Option<u64>,
     pub protocol_profit_share: Option<u32>,
+    pub protocol_entry_fee: Option<u32>,
+    pub protocol_exit_fee: Option<u32>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateVaultProtocol<'info> {
     #[account(
         mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_vault_protocol_migrated(&vault_protocol)?,
         constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
     )]
     pub vault: AccountLoader<'info, Vault>,
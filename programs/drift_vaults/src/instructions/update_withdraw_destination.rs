@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_vault_depositor_migrated, is_vault_migrated,
+};
+use crate::state::events::WithdrawDestinationUpdateRecord;
+use crate::state::{Vault, VaultDepositor};
+
+/// Registers the token account the vault depositor's withdrawals are paid to. The change takes effect
+/// after the withdraw destination timelock.
+pub fn update_withdraw_destination<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateWithdrawDestination<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+
+    let old_destination = vault_depositor.withdraw_destination.get(now);
+    let new_destination = ctx.accounts.withdraw_destination.key();
+    let effective_ts = vault_depositor
+        .withdraw_destination
+        .propose(new_destination, now)?;
+
+    emit!(WithdrawDestinationUpdateRecord {
+        ts: now,
+        vault: vault_depositor.vault,
+        authority: vault_depositor.authority,
+        old_destination: old_destination.unwrap_or_default(),
+        new_destination,
+        effective_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateWithdrawDestination<'info> {
+    #[account(
+        constraint = is_vault_migrated(&vault)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        token::mint = vault_token_account.mint
+    )]
+    pub withdraw_destination: Box<Account<'info, TokenAccount>>,
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::AddInsuranceFundStake as DriftAddInsuranceFundStake;
+use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::program::Drift;
+use drift::state::insurance_fund_stake::InsuranceFundStake;
+use drift::state::spot_market::SpotMarket;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_delegate_for_vault, is_if_stake_for_vault, is_manager_for_vault, is_user_for_vault,
+    is_user_stats_for_vault, is_vault_migrated,
+};
+use crate::drift_cpi::{AddInsuranceFundStakeCPI, WithdrawCPI};
+use crate::error::ErrorCode;
+use crate::{declare_vault_seeds, implement_withdraw, validate, Vault};
+
+/// Moves vault collateral from the drift user into the vault's insurance fund stake. Vault equity is
+/// unchanged since the stake is counted in equity.
+pub fn vault_add_insurance_fund_stake<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, VaultAddInsuranceFundStake<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+    validate!(
+        vault.if_stake_in_equity,
+        ErrorCode::InvalidVaultUpdate,
+        "insurance fund stake is not counted in vault equity"
+    )?;
+    let spot_market_index = vault.spot_market_index;
+    drop(vault);
+
+    ctx.drift_withdraw(amount)?;
+    ctx.drift_add_insurance_fund_stake(spot_market_index, amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VaultAddInsuranceFundStake<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &authority)? || is_delegate_for_vault(&vault, &authority)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"spot_market", vault.load()?.spot_market_index.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = drift_program.key(),
+    )]
+    pub drift_spot_market: AccountLoader<'info, SpotMarket>,
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault".as_ref(), vault.load()?.spot_market_index.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = drift_program.key(),
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"insurance_fund_stake", vault.key().as_ref(), vault.load()?.spot_market_index.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = drift_program.key(),
+        constraint = is_if_stake_for_vault(&insurance_fund_stake, &vault)?,
+    )]
+    pub insurance_fund_stake: AccountLoader<'info, InsuranceFundStake>,
+    #[account(
+        mut,
+        seeds = [b"insurance_fund_vault".as_ref(), vault.load()?.spot_market_index.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = drift_program.key(),
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    /// CHECK: forced drift_signer
+    pub drift_signer: AccountInfo<'info>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, VaultAddInsuranceFundStake<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> AddInsuranceFundStakeCPI
+    for Context<'_, '_, '_, 'info, VaultAddInsuranceFundStake<'info>>
+{
+    fn drift_add_insurance_fund_stake(&self, market_index: u16, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = DriftAddInsuranceFundStake {
+            state: self.accounts.drift_state.clone(),
+            spot_market: self.accounts.drift_spot_market.to_account_info().clone(),
+            insurance_fund_stake: self.accounts.insurance_fund_stake.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+            insurance_fund_vault: self.accounts.insurance_fund_vault.to_account_info().clone(),
+            user_token_account: self.accounts.vault_token_account.to_account_info().clone(),
+            token_program: self.accounts.token_program.to_account_info().clone(),
+            drift_signer: self.accounts.drift_signer.clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds);
+        drift::cpi::add_insurance_fund_stake(cpi_context, market_index, amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_vault_migrated};
+use crate::state::events::VaultWindDownRecord;
+use crate::state::{Vault, VaultFee, VaultProtocolProvider};
+use crate::AccountMapProvider;
+
+/// Starts winding down the vault. This can't be undone: deposits are rejected, the redeem period is zeroed,
+/// management and protocol fees stop accruing and exit fees are waived. Depositors are processed out with
+/// `wind_down_withdraw`, after which the vault can be closed with `close_vault`. A withdraw queue stays
+/// enabled and is drained by `wind_down_withdraw`, which pays queued requests out directly.
+pub fn wind_down_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WindDownVault<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = clock.unix_timestamp;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
+
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let VaultFee {
+        management_fee_payment,
+        protocol_fee_payment,
+        ..
+    } = vault.start_wind_down(&mut vp, vault_equity, now)?;
+
+    emit!(VaultWindDownRecord {
+        ts: now,
+        vault: vault.pubkey,
+        manager: vault.manager,
+        vault_equity,
+        total_shares: vault.total_shares,
+        management_fee: management_fee_payment,
+        protocol_fee: protocol_fee_payment,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WindDownVault<'info> {
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -8,16 +8,49 @@ use drift::state::user::User;
 
 use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_depositor_migrated, is_vault_migrated, is_withdraw_destination_for_vault_depositor,
 };
-use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI};
+use crate::drift_cpi::{
+    DrawdownReduceOnlyCPI, UpdateSubAccountDelegateCPI, UpdateSubAccountReduceOnlyCPI,
+    UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI,
+};
+use crate::error::ErrorCode;
 use crate::state::{Vault, VaultDepositor, VaultProtocolProvider};
 use crate::token_cpi::TokenTransferCPI;
 use crate::{
-    declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
-    implement_withdraw, AccountMapProvider,
+    declare_vault_seeds, implement_drawdown_reduce_only_cpi, implement_update_user_delegate_cpi,
+    implement_update_user_reduce_only_cpi, implement_withdraw, validate, AccountMapProvider,
+};
+use crate::{
+    implement_update_sub_account_delegate_cpi, implement_update_sub_account_reduce_only_cpi,
 };
 
-pub fn withdraw<'c: 'info, 'info>(ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>) -> Result<()> {
+pub fn withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    min_tokens_out: Option<u64>,
+) -> Result<()> {
+    process_withdraw(ctx, min_tokens_out, WithdrawMode::AfterRedeemPeriod)
+}
+
+/// How [`process_withdraw`] pays out the pending withdraw request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawMode {
+    /// The full request once the redeem period has passed
+    AfterRedeemPeriod,
+    /// The full request before the redeem period ends, charged the vault's early withdraw penalty
+    Instant,
+    /// As much of the request as the drift user's free collateral allows once the redeem period has passed,
+    /// leaving the remainder pending
+    Partial,
+}
+
+/// Pays out the depositor's pending withdraw request from the vault's drift user, shared by the withdraw
+/// instructions that take the [`Withdraw`] accounts.
+pub fn process_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    min_tokens_out: Option<u64>,
+    mode: WithdrawMode,
+) -> Result<()> {
     let clock = &Clock::get()?;
     let mut vault = ctx.accounts.vault.load_mut()?;
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
@@ -27,7 +60,7 @@ pub fn withdraw<'c: 'info, 'info>(ctx: Context<'_, '_, 'c, 'info, Withdraw<'info
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
-    let user = ctx.accounts.drift_user.load()?;
+    let mut user = ctx.accounts.drift_user.load_mut()?;
     let spot_market_index = vault.spot_market_index;
 
     let AccountMaps {
@@ -35,31 +68,83 @@ pub fn withdraw<'c: 'info, 'info>(ctx: Context<'_, '_, 'c, 'info, Withdraw<'info
         spot_market_map,
         mut oracle_map,
     } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some())?;
+    let equity_accounts = ctx.load_equity_accounts(vp.is_some())?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &equity_accounts,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
-    let (user_withdraw_amount, finishing_liquidation) =
-        vault_depositor.withdraw(vault_equity, &mut vault, &mut vp, clock.unix_timestamp)?;
+    let drawdown_reduce_only = vault.enforce_drawdown(vault_equity, clock.unix_timestamp)?;
+
+    let (user_withdraw_amount, finishing_liquidation) = match mode {
+        WithdrawMode::AfterRedeemPeriod => {
+            vault_depositor.withdraw(vault_equity, &mut vault, &mut vp, clock.unix_timestamp)?
+        }
+        WithdrawMode::Instant => vault_depositor.instant_withdraw(
+            vault_equity,
+            &mut vault,
+            &mut vp,
+            clock.unix_timestamp,
+        )?,
+        WithdrawMode::Partial => {
+            let max_withdraw_amount = vault_depositor.calculate_max_withdraw_amount(
+                &vault,
+                vault_equity,
+                &mut user,
+                &perp_market_map,
+                &spot_market_map,
+                &mut oracle_map,
+            )?;
+
+            msg!("max_withdraw_amount: {}", max_withdraw_amount);
+
+            vault_depositor.partial_withdraw(
+                max_withdraw_amount,
+                vault_equity,
+                &mut vault,
+                &mut vp,
+                clock.unix_timestamp,
+            )?
+        }
+    };
 
     msg!("user_withdraw_amount: {}", user_withdraw_amount);
 
+    if let Some(min_tokens_out) = min_tokens_out {
+        validate!(
+            user_withdraw_amount >= min_tokens_out,
+            ErrorCode::SlippageExceeded,
+            "withdraw amount {} < min_tokens_out {}",
+            user_withdraw_amount,
+            min_tokens_out
+        )?;
+    }
+
     drop(vault);
     drop(user);
     drop(vp);
 
+    ctx.drift_enforce_drawdown(drawdown_reduce_only, &equity_accounts.sub_accounts)?;
+
     ctx.drift_withdraw(user_withdraw_amount)?;
 
     ctx.token_transfer(user_withdraw_amount)?;
 
     if finishing_liquidation {
         let mut vault = ctx.accounts.vault.load_mut()?;
-        let vault_delegate = vault.delegate;
         vault.reset_liquidation_delegate();
+        let vault_delegate = vault.get_drift_delegate(clock.unix_timestamp);
+        let reduce_only = vault.in_drawdown_breach();
         drop(vault);
 
         ctx.drift_update_user_delegate(vault_delegate)?;
-        ctx.drift_update_user_reduce_only(false)?;
+        ctx.drift_update_user_reduce_only(reduce_only)?;
+        ctx.drift_update_sub_accounts_delegate(&equity_accounts.sub_accounts, vault_delegate)?;
+        ctx.drift_update_sub_accounts_reduce_only(&equity_accounts.sub_accounts, reduce_only)?;
     }
 
     Ok(())
@@ -67,10 +152,14 @@ pub fn withdraw<'c: 'info, 'info>(ctx: Context<'_, '_, 'c, 'info, Withdraw<'info
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_vault_migrated(&vault)?
+    )]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
+        constraint = is_vault_depositor_migrated(&vault_depositor)?,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
         constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
@@ -106,7 +195,7 @@ pub struct Withdraw<'info> {
     pub drift_signer: AccountInfo<'info>,
     #[account(
         mut,
-        token::authority = authority,
+        constraint = is_withdraw_destination_for_vault_depositor(&vault_depositor, &user_token_account)?,
         token::mint = vault_token_account.mint
     )]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
@@ -152,3 +241,34 @@ impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, Withdraw<'inf
         Ok(())
     }
 }
+
+impl<'info> UpdateSubAccountDelegateCPI<'info> for Context<'_, '_, '_, 'info, Withdraw<'info>> {
+    fn drift_update_sub_account_delegate(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        implement_update_sub_account_delegate_cpi!(self, sub_account, sub_account_id, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSubAccountReduceOnlyCPI<'info> for Context<'_, '_, '_, 'info, Withdraw<'info>> {
+    fn drift_update_sub_account_reduce_only(
+        &self,
+        sub_account: AccountInfo<'info>,
+        sub_account_id: u16,
+        reduce_only: bool,
+    ) -> Result<()> {
+        implement_update_sub_account_reduce_only_cpi!(
+            self,
+            sub_account,
+            sub_account_id,
+            reduce_only
+        );
+        Ok(())
+    }
+}
+
+implement_drawdown_reduce_only_cpi!(Withdraw);
@@ -31,6 +31,27 @@ pub mod drift_vaults {
         instructions::initialize_vault_with_protocol(ctx, params)
     }
 
+    pub fn initialize_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultProtocol<'info>>,
+        params: VaultProtocolParams,
+    ) -> Result<()> {
+        instructions::initialize_vault_protocol(ctx, params)
+    }
+
+    pub fn initialize_vault_sub_account<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultSubAccount<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_sub_account(ctx)
+    }
+
+    pub fn transfer_sub_account_deposit<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, TransferSubAccountDeposit<'info>>,
+        market_index: u16,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_sub_account_deposit(ctx, market_index, amount)
+    }
+
     pub fn update_delegate<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, UpdateDelegate<'info>>,
         delegate: Pubkey,
@@ -38,6 +59,12 @@ pub mod drift_vaults {
         instructions::update_delegate(ctx, delegate)
     }
 
+    pub fn update_manager_withdraw_destination<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateManagerWithdrawDestination<'info>>,
+    ) -> Result<()> {
+        instructions::update_manager_withdraw_destination(ctx)
+    }
+
     pub fn update_margin_trading_enabled<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, UpdateMarginTradingEnabled<'info>>,
         enabled: bool,
@@ -59,10 +86,122 @@ pub mod drift_vaults {
         instructions::update_vault(ctx, params)
     }
 
-    pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Result<()> {
+    pub fn update_vault_depositor_limits<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorLimits<'info>>,
+        params: UpdateVaultDepositorLimitsParams,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_limits(ctx, params)
+    }
+
+    pub fn update_vault_depositor_frozen<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorFrozen<'info>>,
+        frozen: bool,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_frozen(ctx, frozen)
+    }
+
+    pub fn close_vault_depositor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseVaultDepositor<'info>>,
+    ) -> Result<()> {
+        instructions::close_vault_depositor(ctx)
+    }
+
+    pub fn manager_close_vault_depositor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ManagerCloseVaultDepositor<'info>>,
+    ) -> Result<()> {
+        instructions::manager_close_vault_depositor(ctx)
+    }
+
+    pub fn propose_vault_manager<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProposeVaultManager<'info>>,
+        new_manager: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_vault_manager(ctx, new_manager)
+    }
+
+    pub fn accept_vault_manager<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AcceptVaultManager<'info>>,
+    ) -> Result<()> {
+        instructions::accept_vault_manager(ctx)
+    }
+
+    pub fn propose_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProposeVaultProtocol<'info>>,
+        new_protocol: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_vault_protocol(ctx, new_protocol)
+    }
+
+    pub fn accept_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AcceptVaultProtocol<'info>>,
+    ) -> Result<()> {
+        instructions::accept_vault_protocol(ctx)
+    }
+
+    pub fn renounce_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RenounceVaultProtocol<'info>>,
+    ) -> Result<()> {
+        instructions::renounce_vault_protocol(ctx)
+    }
+
+    pub fn remove_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RemoveVaultProtocol<'info>>,
+    ) -> Result<()> {
+        instructions::remove_vault_protocol(ctx)
+    }
+
+    pub fn propose_vault_params<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProposeVaultParams<'info>>,
+        params: ProposeVaultParamsParams,
+    ) -> Result<()> {
+        instructions::propose_vault_params(ctx, params)
+    }
+
+    pub fn propose_vault_protocol_params<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProposeVaultProtocolParams<'info>>,
+        params: ProposeVaultProtocolParamsParams,
+    ) -> Result<()> {
+        instructions::propose_vault_protocol_params(ctx, params)
+    }
+
+    pub fn migrate_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, MigrateVault<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_vault(ctx)
+    }
+
+    pub fn migrate_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, MigrateVaultProtocol<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_vault_protocol(ctx)
+    }
+
+    pub fn migrate_vault_depositor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, MigrateVaultDepositor<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_vault_depositor(ctx)
+    }
+
+    pub fn initialize_vault_depositor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositor<'info>>,
+    ) -> Result<()> {
         instructions::initialize_vault_depositor(ctx)
     }
 
+    pub fn initialize_vault_depositor_with_proof(
+        ctx: Context<InitializeVaultDepositorWithProof>,
+        max_equity: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_with_proof(ctx, max_equity, proof)
+    }
+
+    pub fn update_withdraw_destination<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateWithdrawDestination<'info>>,
+    ) -> Result<()> {
+        instructions::update_withdraw_destination(ctx)
+    }
+
     pub fn initialize_tokenized_vault_depositor(
         ctx: Context<InitializeTokenizedVaultDepositor>,
         params: InitializeTokenizedVaultDepositorParams,
@@ -88,16 +227,26 @@ pub mod drift_vaults {
     pub fn deposit<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
         amount: u64,
+        min_shares_out: Option<u128>,
     ) -> Result<()> {
-        instructions::deposit(ctx, amount)
+        instructions::deposit(ctx, amount, min_shares_out)
+    }
+
+    pub fn deposit_for<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DepositFor<'info>>,
+        amount: u64,
+        min_shares_out: Option<u128>,
+    ) -> Result<()> {
+        instructions::deposit_for(ctx, amount, min_shares_out)
     }
 
     pub fn request_withdraw<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, RequestWithdraw<'info>>,
         withdraw_amount: u64,
         withdraw_unit: WithdrawUnit,
+        max_shares_burned: Option<u128>,
     ) -> Result<()> {
-        instructions::request_withdraw(ctx, withdraw_amount, withdraw_unit)
+        instructions::request_withdraw(ctx, withdraw_amount, withdraw_unit, max_shares_burned)
     }
 
     pub fn cancel_request_withdraw<'c: 'info, 'info>(
@@ -106,16 +255,74 @@ pub mod drift_vaults {
         instructions::cancel_withdraw_request(ctx)
     }
 
+    pub fn settle_withdraw_epoch<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleWithdrawEpoch<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::settle_withdraw_epoch(ctx, amount)
+    }
+
+    pub fn claim_withdraw_epoch<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimWithdrawEpoch<'info>>,
+    ) -> Result<()> {
+        instructions::claim_withdraw_epoch(ctx)
+    }
+
     pub fn withdraw<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+        min_tokens_out: Option<u64>,
+    ) -> Result<()> {
+        instructions::withdraw(ctx, min_tokens_out)
+    }
+
+    pub fn partial_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+        min_tokens_out: Option<u64>,
+    ) -> Result<()> {
+        instructions::partial_withdraw(ctx, min_tokens_out)
+    }
+
+    pub fn instant_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+        min_tokens_out: Option<u64>,
+    ) -> Result<()> {
+        instructions::instant_withdraw(ctx, min_tokens_out)
+    }
+
+    pub fn initialize_withdraw_request_slots(
+        ctx: Context<InitializeWithdrawRequestSlots>,
+    ) -> Result<()> {
+        instructions::initialize_withdraw_request_slots(ctx)
+    }
+
+    pub fn request_withdraw_slot<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RequestWithdrawSlot<'info>>,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+    ) -> Result<()> {
+        instructions::request_withdraw_slot(ctx, withdraw_amount, withdraw_unit)
+    }
+
+    pub fn cancel_withdraw_request_slot<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CancelWithdrawRequestSlot<'info>>,
+        slot: u8,
+    ) -> Result<()> {
+        instructions::cancel_withdraw_request_slot(ctx, slot)
+    }
+
+    pub fn withdraw_slot<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawSlot<'info>>,
+        slot: u8,
+        min_tokens_out: Option<u64>,
     ) -> Result<()> {
-        instructions::withdraw(ctx)
+        instructions::withdraw_slot(ctx, slot, min_tokens_out)
     }
 
     pub fn liquidate<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, Liquidate<'info>>,
+        liquidator: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::liquidate(ctx)
+        instructions::liquidate(ctx, liquidator)
     }
 
     pub fn reset_delegate<'c: 'info, 'info>(
@@ -169,6 +376,49 @@ pub mod drift_vaults {
         instructions::apply_rebase_tokenized_depositor(ctx)
     }
 
+    pub fn wind_down_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WindDownVault<'info>>,
+    ) -> Result<()> {
+        instructions::wind_down_vault(ctx)
+    }
+
+    pub fn wind_down_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WindDownWithdraw<'info>>,
+    ) -> Result<()> {
+        instructions::wind_down_withdraw(ctx)
+    }
+
+    pub fn close_vault_protocol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseVaultProtocol<'info>>,
+    ) -> Result<()> {
+        instructions::close_vault_protocol(ctx)
+    }
+
+    pub fn close_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseVault<'info>>,
+    ) -> Result<()> {
+        instructions::close_vault(ctx)
+    }
+
+    pub fn pause_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PauseVault<'info>>,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::pause_vault(ctx, duration)
+    }
+
+    pub fn unpause_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UnpauseVault<'info>>,
+    ) -> Result<()> {
+        instructions::unpause_vault(ctx)
+    }
+
+    pub fn reset_drawdown_breach<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ResetDrawdownBreach<'info>>,
+    ) -> Result<()> {
+        instructions::reset_drawdown_breach(ctx)
+    }
+
     pub fn force_withdraw<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, ForceWithdraw<'info>>,
     ) -> Result<()> {
@@ -212,6 +462,26 @@ pub mod drift_vaults {
         instructions::cancel_request_remove_insurance_fund_stake(ctx, market_index)
     }
 
+    pub fn update_insurance_fund_stake_in_equity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateInsuranceFundStakeInEquity<'info>>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_insurance_fund_stake_in_equity(ctx, enabled)
+    }
+
+    pub fn vault_add_insurance_fund_stake<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, VaultAddInsuranceFundStake<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::vault_add_insurance_fund_stake(ctx, amount)
+    }
+
+    pub fn vault_remove_insurance_fund_stake<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, VaultRemoveInsuranceFundStake<'info>>,
+    ) -> Result<()> {
+        instructions::vault_remove_insurance_fund_stake(ctx)
+    }
+
     pub fn protocol_request_withdraw<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, ProtocolRequestWithdraw<'info>>,
         withdraw_amount: u64,
@@ -64,6 +64,71 @@ macro_rules! implement_update_user_reduce_only_cpi {
     };
 }
 
+#[macro_export]
+macro_rules! implement_update_sub_account_delegate_cpi {
+    ( $self:expr, $sub_account:expr, $sub_account_id:expr, $delegate:expr ) => {
+        declare_vault_seeds!($self.accounts.vault, seeds);
+
+        let cpi_accounts = UpdateUser {
+            user: $sub_account,
+            authority: $self.accounts.vault.to_account_info().clone(),
+        };
+
+        let drift_program = $self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds);
+        drift::cpi::update_user_delegate(cpi_context, $sub_account_id, $delegate)?;
+    };
+}
+
+#[macro_export]
+macro_rules! implement_update_sub_account_reduce_only_cpi {
+    ( $self:expr, $sub_account:expr, $sub_account_id:expr, $reduce_only:expr ) => {
+        declare_vault_seeds!($self.accounts.vault, seeds);
+
+        let cpi_accounts = UpdateUser {
+            user: $sub_account,
+            authority: $self.accounts.vault.to_account_info().clone(),
+        };
+
+        let drift_program = $self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds);
+        drift::cpi::update_user_reduce_only(cpi_context, $sub_account_id, $reduce_only)?;
+    };
+}
+
+#[macro_export]
+macro_rules! implement_drawdown_reduce_only_cpi {
+    ( $accounts:ident ) => {
+        impl<'info> $crate::drift_cpi::DrawdownReduceOnlyCPI<'info>
+            for Context<'_, '_, '_, 'info, $accounts<'info>>
+        {
+            fn drift_enforce_drawdown(
+                &self,
+                reduce_only: bool,
+                sub_accounts: &[AccountLoader<'info, drift::state::user::User>],
+            ) -> Result<()> {
+                if !reduce_only {
+                    return Ok(());
+                }
+
+                $crate::implement_update_user_reduce_only_cpi!(self, true);
+
+                for sub_account in sub_accounts {
+                    let sub_account_id = sub_account.load()?.sub_account_id;
+                    $crate::implement_update_sub_account_reduce_only_cpi!(
+                        self,
+                        sub_account.to_account_info(),
+                        sub_account_id,
+                        true
+                    );
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! implement_withdraw {
     ( $self:expr, $amount:expr ) => {
@@ -1,9 +1,28 @@
-use anchor_lang::prelude::Context;
+use anchor_lang::prelude::{Account, AccountLoader, Context};
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
 use drift::error::DriftResult;
 use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+use drift::state::insurance_fund_stake::InsuranceFundStake;
 use drift::state::spot_market_map::get_writable_spot_market_set;
+use drift::state::user::User;
 use std::collections::BTreeSet;
 
+/// Accounts besides the primary drift user that make up vault equity, passed in the remaining_accounts
+/// after the markets.
+pub struct VaultEquityAccounts<'a> {
+    pub sub_accounts: Vec<AccountLoader<'a, User>>,
+    pub insurance_fund_stake: Option<AccountLoader<'a, InsuranceFundStake>>,
+    /// the insurance fund vault is found among these by key
+    pub token_accounts: Vec<Account<'a, TokenAccount>>,
+}
+
+/// Accounts proving a depositor holds the vault's access token, passed in the remaining_accounts.
+pub struct VaultAccessAccounts<'a> {
+    pub token_accounts: Vec<Account<'a, TokenAccount>>,
+    pub metadata_accounts: Vec<Account<'a, MetadataAccount>>,
+}
+
 pub trait AccountMapProvider<'a> {
     fn load_maps(
         &self,
@@ -11,6 +30,18 @@ pub trait AccountMapProvider<'a> {
         writable_spot_market: Option<u16>,
         has_vault_protocol: bool,
     ) -> DriftResult<AccountMaps<'a>>;
+
+    fn load_sub_accounts(
+        &self,
+        has_vault_protocol: bool,
+    ) -> anchor_lang::Result<Vec<AccountLoader<'a, User>>>;
+
+    fn load_equity_accounts(
+        &self,
+        has_vault_protocol: bool,
+    ) -> anchor_lang::Result<VaultEquityAccounts<'a>>;
+
+    fn load_access_accounts(&self) -> VaultAccessAccounts<'a>;
 }
 
 impl<'a: 'info, 'info, T: anchor_lang::Bumps> AccountMapProvider<'a>
@@ -35,4 +66,51 @@ impl<'a: 'info, 'info, T: anchor_lang::Bumps> AccountMapProvider<'a>
             None,
         )
     }
+
+    /// The vault's non-primary drift sub-accounts, passed in the remaining_accounts after the markets.
+    fn load_sub_accounts(
+        &self,
+        has_vault_protocol: bool,
+    ) -> anchor_lang::Result<Vec<AccountLoader<'a, User>>> {
+        let end_index = self.remaining_accounts.len() - (has_vault_protocol as usize);
+        Ok(self.remaining_accounts[..end_index]
+            .iter()
+            .filter_map(|acct| AccountLoader::<'a, User>::try_from(acct).ok())
+            .collect())
+    }
+
+    /// The sub-accounts plus, if present, the vault's insurance fund stake and the token accounts.
+    fn load_equity_accounts(
+        &self,
+        has_vault_protocol: bool,
+    ) -> anchor_lang::Result<VaultEquityAccounts<'a>> {
+        let end_index = self.remaining_accounts.len() - (has_vault_protocol as usize);
+        let remaining_accounts = &self.remaining_accounts[..end_index];
+        Ok(VaultEquityAccounts {
+            sub_accounts: self.load_sub_accounts(has_vault_protocol)?,
+            insurance_fund_stake: remaining_accounts
+                .iter()
+                .find_map(|acct| AccountLoader::<'a, InsuranceFundStake>::try_from(acct).ok()),
+            token_accounts: remaining_accounts
+                .iter()
+                .filter_map(|acct| Account::<'a, TokenAccount>::try_from(acct).ok())
+                .collect(),
+        })
+    }
+
+    /// The token accounts and token metadata accounts in the remaining_accounts.
+    fn load_access_accounts(&self) -> VaultAccessAccounts<'a> {
+        VaultAccessAccounts {
+            token_accounts: self
+                .remaining_accounts
+                .iter()
+                .filter_map(|acct| Account::<'a, TokenAccount>::try_from(acct).ok())
+                .collect(),
+            metadata_accounts: self
+                .remaining_accounts
+                .iter()
+                .filter_map(|acct| Account::<'a, MetadataAccount>::try_from(acct).ok())
+                .collect(),
+        }
+    }
 }
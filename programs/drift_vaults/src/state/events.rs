@@ -33,6 +33,8 @@ pub struct VaultDepositorRecord {
     pub profit_share: u64,
     pub management_fee: i64,
     pub management_fee_shares: i64,
+
+    pub entry_exit_fee: u64,
 }
 
 #[event]
@@ -66,6 +68,9 @@ pub struct VaultDepositorV1Record {
     pub manager_profit_share: u64,
     pub management_fee: i64,
     pub management_fee_shares: i64,
+
+    pub protocol_entry_exit_fee: u64,
+    pub manager_entry_exit_fee: u64,
 }
 
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Default)]
@@ -78,6 +83,8 @@ pub enum VaultDepositorAction {
     FeePayment,
     TokenizeShares,
     RedeemTokens,
+    InstantWithdraw,
+    EpochWithdraw,
 }
 
 #[event]
@@ -94,3 +101,168 @@ pub struct ShareTransferRecord {
     pub to_depositor_shares_before: u128,
     pub to_depositor_shares_after: u128,
 }
+
+#[event]
+#[derive(Default)]
+pub struct VaultManagerTransferRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub old_manager: Pubkey,
+    pub new_manager: Pubkey,
+    pub delegate: Pubkey,
+    pub manager_shares: u128,
+    pub manager_withdraw_request_shares: u128,
+    pub manager_withdraw_request_value: u64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultProtocolTransferRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub old_protocol: Pubkey,
+    pub new_protocol: Pubkey,
+    pub protocol_shares: u128,
+    pub protocol_withdraw_request_shares: u128,
+    pub protocol_withdraw_request_value: u64,
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Default)]
+pub enum VaultParamsUpdateAction {
+    #[default]
+    Propose,
+    Apply,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultParamsUpdateRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub action: VaultParamsUpdateAction,
+    pub effective_ts: i64,
+    pub management_fee: i64,
+    pub redeem_period: i64,
+    pub profit_share: u32,
+    pub hurdle_rate: u32,
+    pub entry_fee: u32,
+    pub exit_fee: u32,
+    pub early_withdraw_penalty: u32,
+    pub profit_share_crystallization_period: i64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultProtocolParamsUpdateRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub action: VaultParamsUpdateAction,
+    pub effective_ts: i64,
+    pub protocol_fee: u64,
+    pub protocol_profit_share: u32,
+    pub protocol_entry_fee: u32,
+    pub protocol_exit_fee: u32,
+}
+
+#[event]
+#[derive(Default)]
+pub struct WithdrawEpochRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub vault_equity_before: u64,
+    pub requested_shares: u128,
+    pub filled_shares: u128,
+    pub filled_amount: u64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct DepositForRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_depositor: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub shares: u128,
+}
+
+#[event]
+#[derive(Default)]
+pub struct WithdrawDestinationUpdateRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    /// the vault depositor authority, or the manager for manager withdrawals
+    pub authority: Pubkey,
+    pub old_destination: Pubkey,
+    pub new_destination: Pubkey,
+    pub effective_ts: i64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorFreezeRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_depositor: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub manager: Pubkey,
+    pub frozen: bool,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorCloseRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_depositor: Pubkey,
+    pub depositor_authority: Pubkey,
+    /// the depositor authority, or the manager when sweeping an abandoned account
+    pub closed_by: Pubkey,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultWindDownRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub vault_equity: u64,
+    pub total_shares: u128,
+    pub management_fee: i64,
+    pub protocol_fee: i64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultCloseRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    /// the number of drift sub-accounts deleted, including the primary `user`
+    pub sub_accounts_deleted: u16,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultPauseRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    /// the manager or protocol that paused or unpaused the vault, or anyone unpausing an expired pause
+    pub authority: Pubkey,
+    /// 0 when the vault is unpaused
+    pub paused_until_ts: i64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultDrawdownRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub max_drawdown: u32,
+    pub high_water_mark_share_price: u64,
+    pub share_price: u64,
+    /// true when the max drawdown is breached, false when the manager resets the breach
+    pub breached: bool,
+}
@@ -4,6 +4,9 @@ pub use traits::*;
 pub use vault::*;
 pub use vault_depositor::*;
 pub use vault_protocol::*;
+pub use withdraw_destination::*;
+pub use withdraw_epoch::*;
+pub use withdraw_request_slots::*;
 pub use withdraw_unit::*;
 
 pub mod account_maps;
@@ -13,5 +16,8 @@ pub mod traits;
 pub mod vault;
 pub mod vault_depositor;
 pub mod vault_protocol;
+pub mod withdraw_destination;
+pub mod withdraw_epoch;
 pub mod withdraw_request;
+pub mod withdraw_request_slots;
 pub mod withdraw_unit;
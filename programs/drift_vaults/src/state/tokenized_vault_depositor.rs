@@ -51,7 +51,11 @@ pub struct TokenizedVaultDepositor {
     /// The bump for the vault pda
     pub bump: u8,
     pub padding1: [u8; 3],
-    pub padding: [u64; 10],
+    /// the last ts profit share was crystallized on the whole position, the hurdle accrues from this ts
+    pub last_profit_share_ts: i64,
+    /// the last ts the vault's profit share crystallization schedule was applied to the depositor
+    pub last_crystallization_ts: i64,
+    pub padding: [u64; 8],
 }
 
 impl Size for TokenizedVaultDepositor {
@@ -105,6 +109,20 @@ impl VaultDepositorBase for TokenizedVaultDepositor {
     fn set_profit_share_fee_paid(&mut self, amount: u64) {
         self.profit_share_fee_paid = amount;
     }
+
+    fn get_last_profit_share_ts(&self) -> i64 {
+        self.last_profit_share_ts
+    }
+    fn set_last_profit_share_ts(&mut self, ts: i64) {
+        self.last_profit_share_ts = ts;
+    }
+
+    fn get_last_crystallization_ts(&self) -> i64 {
+        self.last_crystallization_ts
+    }
+    fn set_last_crystallization_ts(&mut self, ts: i64) {
+        self.last_crystallization_ts = ts;
+    }
 }
 
 impl TokenizedVaultDepositor {
@@ -131,7 +149,9 @@ impl TokenizedVaultDepositor {
             vault_shares_base,
             bump,
             padding1: [0; 3],
-            padding: [0; 10],
+            last_profit_share_ts: now,
+            last_crystallization_ts: now,
+            padding: [0; 8],
         }
     }
 
@@ -172,7 +192,7 @@ impl TokenizedVaultDepositor {
             protocol_fee_shares,
         } = vault.apply_fee(vault_protocol, vault_equity, now)?;
         let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_protocol, now)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -226,6 +246,7 @@ impl TokenizedVaultDepositor {
                         .cast()?,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(_) => {
@@ -251,6 +272,8 @@ impl TokenizedVaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after: vault.get_protocol_shares(vault_protocol),
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         }
@@ -276,7 +299,7 @@ impl TokenizedVaultDepositor {
             protocol_fee_shares,
         } = vault.apply_fee(vault_protocol, vault_equity, now)?;
         let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_protocol, now)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -320,6 +343,7 @@ impl TokenizedVaultDepositor {
                         .cast()?,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(_) => {
@@ -345,6 +369,8 @@ impl TokenizedVaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after: vault.get_protocol_shares(vault_protocol),
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         }
@@ -565,7 +591,7 @@ mod tests {
 
         let tvd_shares_before = tvd.get_vault_shares();
         let (manager_profit_share, protocol_profit_share) = tvd
-            .apply_profit_share(vault_equity + profit, vault, &mut None)
+            .apply_profit_share(vault_equity + profit, vault, &mut None, now)
             .unwrap();
         let tvd_shares_after = tvd.get_vault_shares();
 
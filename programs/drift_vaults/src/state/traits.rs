@@ -9,7 +9,7 @@ use crate::{validate, VaultFee, VaultProtocol, WithdrawUnit};
 use anchor_lang::prelude::*;
 
 use drift::math::casting::Cast;
-use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::constants::{ONE_YEAR, PERCENTAGE_PRECISION};
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
@@ -39,6 +39,12 @@ pub trait VaultDepositorBase {
     fn get_profit_share_fee_paid(&self) -> u64;
     fn set_profit_share_fee_paid(&mut self, amount: u64);
 
+    fn get_last_profit_share_ts(&self) -> i64;
+    fn set_last_profit_share_ts(&mut self, ts: i64);
+
+    fn get_last_crystallization_ts(&self) -> i64;
+    fn set_last_crystallization_ts(&mut self, ts: i64);
+
     fn validate_base(&self, vault: &Vault) -> Result<()> {
         validate!(
             self.get_vault_shares_base() == vault.shares_base,
@@ -78,34 +84,140 @@ pub trait VaultDepositorBase {
         Ok(())
     }
 
+    /// The token amount of returns the depositor is owed before profit share is charged. The hurdle
+    /// accrues linearly at the vault's annualized `hurdle_rate` on the depositor's high-water mark
+    /// (net deposits plus profits already charged) since the last crystallization.
+    fn calculate_hurdle_amount(&self, vault: &Vault, now: i64) -> Result<u128> {
+        if vault.hurdle_rate == 0 {
+            return Ok(0);
+        }
+
+        let high_water_mark = self
+            .get_net_deposits()
+            .safe_add(self.get_cumulative_profit_share_amount())?;
+        if high_water_mark <= 0 {
+            return Ok(0);
+        }
+
+        // accounts created before hurdle tracking have no ts, hurdle cannot accrue before the vault existed
+        let last_profit_share_ts = self.get_last_profit_share_ts().max(vault.init_ts);
+        let since_last = now.safe_sub(last_profit_share_ts)?.max(0);
+
+        let hurdle_amount = high_water_mark
+            .cast::<u128>()?
+            .safe_mul(vault.hurdle_rate.cast()?)?
+            .safe_mul(since_last.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .safe_div(ONE_YEAR)?;
+
+        Ok(hurdle_amount)
+    }
+
+    /// Shifts the hurdle accrual start forward so a deposit only accrues hurdle from the time it was made,
+    /// while preserving the hurdle already accrued on the existing high-water mark.
+    fn update_last_profit_share_ts_for_deposit(
+        &mut self,
+        amount: u64,
+        vault: &Vault,
+        now: i64,
+    ) -> Result<()> {
+        let high_water_mark = self
+            .get_net_deposits()
+            .safe_add(self.get_cumulative_profit_share_amount())?;
+        let last_profit_share_ts = self.get_last_profit_share_ts().max(vault.init_ts);
+
+        if high_water_mark <= 0 || last_profit_share_ts >= now {
+            self.set_last_profit_share_ts(now);
+            return Ok(());
+        }
+
+        let since_last = now.safe_sub(last_profit_share_ts)?.cast::<i128>()?;
+        let ts_delta = since_last
+            .safe_mul(amount.cast()?)?
+            .safe_div(high_water_mark.safe_add(amount.cast()?)?.cast()?)?;
+
+        self.set_last_profit_share_ts(last_profit_share_ts.safe_add(ts_delta.cast()?)?);
+
+        Ok(())
+    }
+
     fn calculate_profit_share_and_update(
         &mut self,
         total_amount: u64,
         vault: &Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u128, u128)> {
+        self.calculate_partial_profit_share_and_update(
+            total_amount,
+            total_amount,
+            vault,
+            vault_protocol,
+            now,
+        )
+    }
+
+    /// Profit share on the part of the depositor's gains attributable to `amount` of its `total_amount`.
+    /// Only crystallizing the whole position moves the last profit share ts. The profit share is capped at
+    /// `amount` so it can always be paid out of the depositor's shares.
+    fn calculate_partial_profit_share_and_update(
+        &mut self,
+        total_amount: u64,
+        amount: u64,
+        vault: &Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
     ) -> Result<(u128, u128)> {
         let profit = total_amount.cast::<i64>()?.safe_sub(
             self.get_net_deposits()
                 .safe_add(self.get_cumulative_profit_share_amount())?,
         )?;
         if profit > 0 {
-            let profit_u128 = profit.cast::<u128>()?;
+            let partial = amount < total_amount;
+            let mut profit_u128 = profit.cast::<u128>()?;
+            let mut hurdle_amount = self.calculate_hurdle_amount(vault, now)?;
+            if partial {
+                profit_u128 = profit_u128
+                    .safe_mul(amount.cast()?)?
+                    .safe_div(total_amount.cast()?)?;
+                hurdle_amount = hurdle_amount
+                    .safe_mul(amount.cast()?)?
+                    .safe_div(total_amount.cast()?)?;
+            }
+            if profit_u128 <= hurdle_amount {
+                return Ok((0, 0));
+            }
+            // only returns in excess of the hurdle are charged
+            let profit_above_hurdle = profit_u128.safe_sub(hurdle_amount)?;
 
-            let manager_profit_share_amount = profit_u128
+            let manager_profit_share_amount = profit_above_hurdle
                 .safe_mul(vault.profit_share.cast()?)?
-                .safe_div(PERCENTAGE_PRECISION)?;
+                .safe_div(PERCENTAGE_PRECISION)?
+                .min(amount.cast()?);
             let protocol_profit_share_amount = match vault_protocol {
                 None => 0,
-                Some(vp) => profit_u128
+                Some(vp) => profit_above_hurdle
                     .safe_mul(vp.protocol_profit_share.cast()?)?
-                    .safe_div(PERCENTAGE_PRECISION)?,
+                    .safe_div(PERCENTAGE_PRECISION)?
+                    .min(
+                        amount
+                            .cast::<u128>()?
+                            .safe_sub(manager_profit_share_amount)?,
+                    ),
             };
             let profit_share_amount =
                 manager_profit_share_amount.safe_add(protocol_profit_share_amount)?;
 
+            // a partial crystallization's fee comes out of the depositor's shares, so it is left out of the
+            // high-water mark to keep the rest of the position's uncharged gains intact
+            let high_water_mark_increase = if partial {
+                profit_u128.safe_sub(profit_share_amount)?
+            } else {
+                profit_u128
+            };
             self.set_cumulative_profit_share_amount(
                 self.get_cumulative_profit_share_amount()
-                    .safe_add(profit_u128.cast()?)?,
+                    .safe_add(high_water_mark_increase.cast()?)?,
             );
 
             self.set_profit_share_fee_paid(
@@ -113,26 +225,83 @@ pub trait VaultDepositorBase {
                     .safe_add(profit_share_amount.cast()?)?,
             );
 
+            if !partial {
+                self.set_last_profit_share_ts(now);
+            }
+
             return Ok((manager_profit_share_amount, protocol_profit_share_amount));
         }
 
         Ok((0, 0))
     }
 
+    /// Crystallizes profit share if the vault's crystallization schedule is due for this depositor
     fn apply_profit_share(
         &mut self,
         vault_equity: u64,
         vault: &mut Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, u64)> {
+        if !vault.is_profit_share_crystallization_due(self.get_last_crystallization_ts(), now)? {
+            return Ok((0, 0));
+        }
+
+        let profit_share =
+            self.crystallize_profit_share(vault_equity, vault, vault_protocol, now)?;
+
+        // the performance period rolls over even if no profit share was charged, the hurdle keeps accruing
+        // from the last ts profit share was charged
+        self.set_last_crystallization_ts(now);
+
+        Ok(profit_share)
+    }
+
+    /// Charges profit share on gains above the high-water mark and hurdle, regardless of the vault's
+    /// crystallization schedule
+    fn crystallize_profit_share(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
     ) -> Result<(u64, u64)> {
-        let total_amount = depositor_shares_to_vault_amount(
+        self.crystallize_partial_profit_share(
             self.get_vault_shares(),
-            vault.total_shares,
             vault_equity,
-        )?;
+            vault,
+            vault_protocol,
+            now,
+        )
+    }
 
-        let (manager_profit_share, protocol_profit_share) =
-            self.calculate_profit_share_and_update(total_amount, vault, vault_protocol)?;
+    /// Charges profit share on the gains attributable to `shares` of the depositor's vault shares, e.g. the
+    /// shares being withdrawn before the crystallization schedule is due
+    fn crystallize_partial_profit_share(
+        &mut self,
+        shares: u128,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, u64)> {
+        let vault_shares = self.get_vault_shares();
+        let total_amount =
+            depositor_shares_to_vault_amount(vault_shares, vault.total_shares, vault_equity)?;
+        let amount = if shares >= vault_shares {
+            total_amount
+        } else {
+            depositor_shares_to_vault_amount(shares, vault.total_shares, vault_equity)?
+        };
+
+        let (manager_profit_share, protocol_profit_share) = self
+            .calculate_partial_profit_share_and_update(
+                total_amount,
+                amount,
+                vault,
+                vault_protocol,
+                now,
+            )?;
         let manager_profit_share: u64 = manager_profit_share.cast()?;
         let protocol_profit_share: u64 = protocol_profit_share.cast()?;
         let profit_share = manager_profit_share
@@ -253,10 +422,30 @@ pub trait VaultDepositorBase {
             protocol_fee_shares,
         } = vault.apply_fee(vault_protocol, vault_equity, now)?;
 
-        let (from_manager_profit_share, from_protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+        // before the crystallization schedule is due, the transferred fraction is crystallized so the
+        // receiver's high-water mark doesn't take on gains that were never charged
+        let (from_manager_profit_share, from_protocol_profit_share) = if vault
+            .is_profit_share_crystallization_due(self.get_last_crystallization_ts(), now)?
+        {
+            self.apply_profit_share(vault_equity, vault, vault_protocol, now)?
+        } else {
+            let (_, requested_shares) = withdraw_unit.get_withdraw_value_and_shares(
+                withdraw_amount,
+                vault_equity,
+                self.get_vault_shares(),
+                vault.total_shares,
+                from_rebase_divisor,
+            )?;
+            self.crystallize_partial_profit_share(
+                requested_shares,
+                vault_equity,
+                vault,
+                vault_protocol,
+                now,
+            )?
+        };
         let (to_manager_profit_share, to_protocol_profit_share) =
-            to.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            to.apply_profit_share(vault_equity, vault, vault_protocol, now)?;
 
         let (withdraw_value, n_shares) = withdraw_unit.get_withdraw_value_and_shares(
             withdraw_amount,
@@ -281,6 +470,8 @@ pub trait VaultDepositorBase {
         let from_depositor_shares_before = self.checked_vault_shares(vault)?;
         let to_depositor_shares_before = to.checked_vault_shares(vault)?;
 
+        to.update_last_profit_share_ts_for_deposit(withdraw_value, vault, now)?;
+
         self.decrease_vault_shares(n_shares, vault)?;
         to.increase_vault_shares(n_shares, vault)?;
 
@@ -332,6 +523,7 @@ pub trait VaultDepositorBase {
                         .cast()?,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
 
                 emit!(VaultDepositorRecord {
@@ -353,6 +545,7 @@ pub trait VaultDepositorBase {
                         .cast()?,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(_) => {
@@ -378,6 +571,8 @@ pub trait VaultDepositorBase {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after: vault.get_protocol_shares(vault_protocol),
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
 
                 emit!(VaultDepositorV1Record {
@@ -402,6 +597,8 @@ pub trait VaultDepositorBase {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after: vault.get_protocol_shares(vault_protocol),
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         }
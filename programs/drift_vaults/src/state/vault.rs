@@ -1,8 +1,12 @@
 use std::cell::RefMut;
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
 use drift::math::casting::Cast;
-use drift::math::constants::{ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128};
+use drift::math::constants::{
+    ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128, PERCENTAGE_PRECISION_U64,
+    PRICE_PRECISION,
+};
 use drift::math::insurance::calculate_rebase_info;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
@@ -17,12 +21,21 @@ use drift::state::user::User;
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
-use crate::constants::TIME_FOR_LIQUIDATION;
+use crate::constants::{
+    permissioned_liquidator, DRAWDOWN_COOLDOWN, MAX_LIQUIDATORS, MAX_PAUSE_DURATION,
+    MAX_TIME_FOR_LIQUIDATION, MIN_PARAMS_TIMELOCK, ONE_DAY, PAUSE_COOLDOWN, TIME_FOR_LIQUIDATION,
+};
 use crate::error::{ErrorCode, VaultResult};
-use crate::events::{VaultDepositorAction, VaultDepositorV1Record};
+use crate::events::{
+    VaultDepositorAction, VaultDepositorV1Record, VaultDrawdownRecord, VaultParamsUpdateAction,
+    VaultParamsUpdateRecord, WithdrawEpochRecord,
+};
 use crate::state::events::VaultDepositorRecord;
 use crate::state::withdraw_request::WithdrawRequest;
-use crate::state::{VaultFee, VaultProtocol};
+use crate::state::{
+    VaultAccessAccounts, VaultEntryExitFee, VaultEquityAccounts, VaultFee, VaultProtocol,
+    WithdrawDestination, WithdrawEpoch,
+};
 use crate::{validate, Size, WithdrawUnit};
 
 #[assert_no_slop]
@@ -91,7 +104,7 @@ pub struct Vault {
     pub shares_base: u32,
     /// Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION
     pub profit_share: u32,
-    /// Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION
+    /// Vault manager only collect incentive fees during periods when returns are higher than this annualized amount: PERCENTAGE_PRECISION
     pub hurdle_rate: u32,
     /// The spot market index the vault deposits into/withdraws from
     pub spot_market_index: u16,
@@ -101,8 +114,115 @@ pub struct Vault {
     pub permissioned: bool,
     /// The optional [`VaultProtocol`] account.
     pub vault_protocol: bool,
-    pub padding1: [u8; 7],
-    pub padding: [u64; 7],
+    /// Whether the early withdraw penalty is paid to the manager instead of left in the vault for remaining depositors
+    pub early_withdraw_penalty_to_manager: bool,
+    /// The number of drift sub-accounts owned by the vault. Sub-account 0 (`user`) is the primary sub-account
+    /// that deposits and withdrawals go through. 0 for vaults created before sub-accounts were supported.
+    pub sub_account_count: u16,
+    /// Whether the vault's insurance fund stake in `spot_market_index` holds depositor capital and is counted
+    /// in vault equity, instead of being a manager-funded stake.
+    pub if_stake_in_equity: bool,
+    /// Whether withdraw requests are batched into epochs that the manager settles pro-rata, instead of each
+    /// depositor withdrawing directly from drift after the redeem period.
+    pub withdraw_queue: bool,
+    /// Whether the vault is winding down: no new deposits, no redeem period and no management or protocol
+    /// fees. Depositors are processed out with `wind_down_withdraw` until the vault can be closed.
+    pub wind_down: bool,
+    pub padding1: [u8; 1],
+    /// The period (in seconds) between profit share crystallizations, aligned to init_ts.
+    /// Depositor profit share is only charged at period boundaries or on full exit. 0 means profit share is
+    /// charged on every depositor action.
+    pub profit_share_crystallization_period: i64,
+    /// Percentage the manager charges on deposits: PERCENTAGE_PRECISION
+    pub entry_fee: u32,
+    /// Percentage the manager charges on withdrawals: PERCENTAGE_PRECISION
+    pub exit_fee: u32,
+    /// Percentage a depositor pays to withdraw immediately after requesting a withdrawal, decaying linearly to 0
+    /// over the redeem period: PERCENTAGE_PRECISION. 0 means instant withdraws are disabled.
+    pub early_withdraw_penalty: u32,
+    /// The early withdraw penalty that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_early_withdraw_penalty: u32,
+    /// The manager proposed by the current manager. Becomes the manager once they accept the handover.
+    pub pending_manager: Pubkey,
+    /// The management fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_management_fee: i64,
+    /// The redeem period that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_redeem_period: i64,
+    /// When the pending parameter increases take effect. 0 means there are no pending changes.
+    pub pending_params_effective_ts: i64,
+    /// The profit share that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_profit_share: u32,
+    /// The hurdle rate that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_hurdle_rate: u32,
+    /// The entry fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_entry_fee: u32,
+    /// The exit fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_exit_fee: u32,
+    /// The withdraw epoch currently accepting requests. Epochs before it have been settled.
+    pub withdraw_epoch: u64,
+    /// The shares queued for withdraw in the current epoch, including unfilled shares rolled over from
+    /// settled epochs.
+    pub withdraw_queue_shares: u128,
+    /// The token account manager withdrawals are paid to, if the manager registered one
+    pub manager_withdraw_destination: WithdrawDestination,
+    /// The max equity a single depositor can hold after a deposit. 0 means no limit.
+    pub max_depositor_equity: u64,
+    /// The min equity a depositor must keep unless fully exiting. 0 means no minimum.
+    pub min_depositor_balance: u64,
+    /// Merkle root of the (authority, max equity) leaves allowed to self-initialize a vault depositor in a
+    /// permissioned vault. All zeros disables the allowlist.
+    pub allowlist_merkle_root: [u8; 32],
+    /// Depositors must hold `access_min_balance` of this mint to join and deposit. Default disables the gate.
+    pub access_mint: Pubkey,
+    pub access_min_balance: u64,
+    /// Depositors must hold an NFT of this verified collection to join and deposit. Default disables the gate.
+    /// If both gates are set either one grants access.
+    pub access_collection: Pubkey,
+    /// The ts the emergency pause expires. While paused, deposits and tokenizing are halted and the drift
+    /// delegate is set to the null key. 0 if never paused.
+    pub paused_until_ts: i64,
+    /// The manager or protocol that paused the vault
+    pub paused_by: Pubkey,
+    /// The max drawdown of the share price from its high water mark before the vault's drift user is set to
+    /// reduce-only: PERCENTAGE_PRECISION. 0 disables the circuit breaker.
+    pub max_drawdown: u32,
+    /// The looser max drawdown that takes effect at `pending_max_drawdown_ts`. 0 disables the circuit breaker.
+    pub pending_max_drawdown: u32,
+    /// The highest share price seen since the circuit breaker was last reset: PRICE_PRECISION
+    pub high_water_mark_share_price: u64,
+    /// The ts the max drawdown was breached. 0 if not breached.
+    pub drawdown_breach_ts: i64,
+    /// How long (in seconds) a liquidator has to unwind the vault before the manager can reset the delegate.
+    /// 0 uses the default [`TIME_FOR_LIQUIDATION`].
+    pub liquidation_window: i64,
+    /// The liquidators a depositor can hand the vault to in `liquidate`. If all default, only the
+    /// [`permissioned_liquidator`] is allowed.
+    pub liquidators: [Pubkey; MAX_LIQUIDATORS],
+    /// Whether a depositor can hand the vault to any liquidator, including themselves
+    pub permissionless_liquidation: bool,
+    /// The `permissionless_liquidation` that takes effect at `pending_liquidation_settings_ts`
+    pub pending_permissionless_liquidation: bool,
+    /// Whether the max drawdown was breached in an instruction that can't set the drift users reduce-only,
+    /// the next instruction that can does so
+    pub drawdown_reduce_only_pending: bool,
+    pub padding6: [u8; 5],
+    /// When the oldest request in the withdraw queue was made. 0 when the queue is empty.
+    pub withdraw_queue_start_ts: i64,
+    /// When `pending_max_drawdown` takes effect. 0 means there is no pending change.
+    pub pending_max_drawdown_ts: i64,
+    /// When the last emergency pause ended, by expiring or being lifted. 0 if never paused.
+    pub pause_end_ts: i64,
+    /// The `liquidation_window` that takes effect at `pending_liquidation_settings_ts`
+    pub pending_liquidation_window: i64,
+    /// When the pending liquidation settings take effect, or once the vault leaves liquidation if later.
+    /// 0 means there are no pending changes.
+    pub pending_liquidation_settings_ts: i64,
+    /// The `liquidators` that take effect at `pending_liquidation_settings_ts`
+    pub pending_liquidators: [Pubkey; MAX_LIQUIDATORS],
+    /// The profit share crystallization period that takes effect at `pending_params_effective_ts`. 0 means
+    /// unchanged.
+    pub pending_profit_share_crystallization_period: i64,
+    pub padding4: [u64; 4],
 }
 
 impl Vault {
@@ -112,16 +232,59 @@ impl Vault {
 }
 
 impl Size for Vault {
-    const SIZE: usize = 528 + 8;
+    const SIZE: usize = 1200 + 8;
 }
 const_assert_eq!(Vault::SIZE, std::mem::size_of::<Vault>() + 8);
 
+/// Liquidation settings proposed along with the vault params. `None` keeps the current value.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct LiquidationSettings {
+    pub liquidators: Option<Vec<Pubkey>>,
+    pub liquidation_window: Option<i64>,
+    pub permissionless_liquidation: Option<bool>,
+}
+
+impl LiquidationSettings {
+    pub fn is_empty(&self) -> bool {
+        self.liquidators.is_none()
+            && self.liquidation_window.is_none()
+            && self.permissionless_liquidation.is_none()
+    }
+}
+
 impl Vault {
+    /// Charges the management and protocol fees accrued since the last fee update. Pending parameter changes
+    /// that became effective since then are applied, with fees up to the effective ts charged at the old rates.
     pub fn apply_fee(
         &mut self,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
         now: i64,
+    ) -> Result<VaultFee> {
+        let mut vault_fee = VaultFee::default();
+
+        self.apply_pending_liquidation_settings(now);
+
+        if self.wind_down {
+            return Ok(vault_fee);
+        }
+
+        while let Some(effective_ts) = self.next_pending_params_ts(vault_protocol, now) {
+            let fee_ts = effective_ts.max(self.last_fee_update_ts);
+            vault_fee.add(&self.accrue_fee(vault_protocol, vault_equity, fee_ts)?)?;
+            self.apply_pending_params(vault_protocol, effective_ts)?;
+        }
+
+        vault_fee.add(&self.accrue_fee(vault_protocol, vault_equity, now)?)?;
+
+        Ok(vault_fee)
+    }
+
+    fn accrue_fee(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        vault_equity: u64,
+        now: i64,
     ) -> Result<VaultFee> {
         let depositor_equity =
             depositor_shares_to_vault_amount(self.user_shares, self.total_shares, vault_equity)?
@@ -327,6 +490,271 @@ impl Vault {
         })
     }
 
+    fn next_pending_params_ts(
+        &self,
+        vault_protocol: &Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Option<i64> {
+        let vault_ts = self.pending_params_effective_ts;
+        let vp_ts = vault_protocol
+            .as_ref()
+            .map_or(0, |vp| vp.pending_params_effective_ts);
+
+        [vault_ts, vp_ts]
+            .into_iter()
+            .filter(|ts| *ts != 0 && *ts <= now)
+            .min()
+    }
+
+    fn apply_pending_params(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        effective_ts: i64,
+    ) -> Result<()> {
+        if self.pending_params_effective_ts == effective_ts {
+            if self.pending_management_fee != 0 {
+                self.management_fee = self.pending_management_fee;
+            }
+            if self.pending_redeem_period != 0 {
+                self.redeem_period = self.pending_redeem_period;
+            }
+            if self.pending_profit_share != 0 {
+                self.profit_share = self.pending_profit_share;
+            }
+            if self.pending_hurdle_rate != 0 {
+                self.hurdle_rate = self.pending_hurdle_rate;
+            }
+            if self.pending_entry_fee != 0 {
+                self.entry_fee = self.pending_entry_fee;
+            }
+            if self.pending_exit_fee != 0 {
+                self.exit_fee = self.pending_exit_fee;
+            }
+            if self.pending_early_withdraw_penalty != 0 {
+                self.early_withdraw_penalty = self.pending_early_withdraw_penalty;
+            }
+            if self.pending_profit_share_crystallization_period != 0 {
+                self.profit_share_crystallization_period =
+                    self.pending_profit_share_crystallization_period;
+            }
+
+            self.emit_pending_params_record(VaultParamsUpdateAction::Apply, effective_ts);
+            self.reset_pending_params();
+        }
+
+        if let Some(vp) = vault_protocol {
+            if vp.pending_params_effective_ts == effective_ts {
+                vp.apply_pending_params(self.pubkey, effective_ts);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Announces fee and parameter increases that take effect at `effective_ts`, which must be at least a
+    /// redeem period and [`MIN_PARAMS_TIMELOCK`] away so depositors can exit before. A value of 0 leaves the parameter unchanged,
+    /// and proposing no changes cancels the pending ones. Liquidation settings changes, increases or not, go
+    /// through the same timelock.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_params(
+        &mut self,
+        management_fee: i64,
+        redeem_period: i64,
+        profit_share: u32,
+        hurdle_rate: u32,
+        entry_fee: u32,
+        exit_fee: u32,
+        early_withdraw_penalty: u32,
+        profit_share_crystallization_period: i64,
+        liquidation_settings: LiquidationSettings,
+        effective_ts: i64,
+        now: i64,
+    ) -> VaultResult {
+        self.reset_pending_params();
+        self.reset_pending_liquidation_settings();
+
+        let fees_changed = management_fee != 0
+            || redeem_period != 0
+            || profit_share != 0
+            || hurdle_rate != 0
+            || entry_fee != 0
+            || exit_fee != 0
+            || early_withdraw_penalty != 0
+            || profit_share_crystallization_period != 0;
+        if !fees_changed && liquidation_settings.is_empty() {
+            return Ok(());
+        }
+
+        // the redeem period can be shortened right before proposing, so a minimum notice always applies
+        validate!(
+            effective_ts >= now.safe_add(self.redeem_period.max(MIN_PARAMS_TIMELOCK))?,
+            ErrorCode::InvalidVaultUpdate,
+            "pending params must take effect at least a redeem period and {}s from now",
+            MIN_PARAMS_TIMELOCK
+        )?;
+
+        validate!(
+            management_fee == 0 || management_fee > self.management_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "pending management fee must be greater than existing management fee"
+        )?;
+        validate!(
+            redeem_period == 0 || redeem_period > self.redeem_period,
+            ErrorCode::InvalidVaultUpdate,
+            "pending redeem period must be longer than existing redeem period"
+        )?;
+        validate!(
+            profit_share == 0 || profit_share > self.profit_share,
+            ErrorCode::InvalidVaultUpdate,
+            "pending profit share must be greater than existing profit share"
+        )?;
+        validate!(
+            hurdle_rate == 0 || hurdle_rate > self.hurdle_rate,
+            ErrorCode::InvalidVaultUpdate,
+            "pending hurdle rate must be greater than existing hurdle rate"
+        )?;
+        validate!(
+            entry_fee == 0 || entry_fee > self.entry_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "pending entry fee must be greater than existing entry fee"
+        )?;
+        validate!(
+            exit_fee == 0 || exit_fee > self.exit_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "pending exit fee must be greater than existing exit fee"
+        )?;
+        // enabling instant withdraws counts as an increase from 0
+        validate!(
+            early_withdraw_penalty == 0 || early_withdraw_penalty > self.early_withdraw_penalty,
+            ErrorCode::InvalidVaultUpdate,
+            "pending early withdraw penalty must be greater than existing early withdraw penalty"
+        )?;
+        validate!(
+            profit_share_crystallization_period == 0
+                || (profit_share_crystallization_period > self.profit_share_crystallization_period
+                    && profit_share_crystallization_period <= ONE_DAY * 365),
+            ErrorCode::InvalidVaultUpdate,
+            "pending profit share crystallization period must be longer than existing period and <= 365 days"
+        )?;
+
+        if !liquidation_settings.is_empty() {
+            self.propose_liquidation_settings(liquidation_settings, effective_ts)?;
+        }
+
+        if !fees_changed {
+            return Ok(());
+        }
+
+        self.pending_management_fee = management_fee;
+        self.pending_redeem_period = redeem_period;
+        self.pending_profit_share = profit_share;
+        self.pending_hurdle_rate = hurdle_rate;
+        self.pending_entry_fee = entry_fee;
+        self.pending_exit_fee = exit_fee;
+        self.pending_early_withdraw_penalty = early_withdraw_penalty;
+        self.pending_profit_share_crystallization_period = profit_share_crystallization_period;
+        self.pending_params_effective_ts = effective_ts;
+
+        self.emit_pending_params_record(VaultParamsUpdateAction::Propose, now);
+
+        Ok(())
+    }
+
+    /// Stages the liquidation settings to replace the current ones at `effective_ts`. Settings left out keep
+    /// their current value.
+    fn propose_liquidation_settings(
+        &mut self,
+        liquidation_settings: LiquidationSettings,
+        effective_ts: i64,
+    ) -> VaultResult {
+        let LiquidationSettings {
+            liquidators,
+            liquidation_window,
+            permissionless_liquidation,
+        } = liquidation_settings;
+
+        self.pending_liquidators = self.liquidators;
+        if let Some(liquidators) = liquidators {
+            validate!(
+                liquidators.len() <= MAX_LIQUIDATORS,
+                ErrorCode::InvalidVaultUpdate,
+                "at most {} liquidators can be allowed",
+                MAX_LIQUIDATORS
+            )?;
+            self.pending_liquidators = [Pubkey::default(); MAX_LIQUIDATORS];
+            self.pending_liquidators[..liquidators.len()].copy_from_slice(&liquidators);
+        }
+
+        let liquidation_window = liquidation_window.unwrap_or(self.liquidation_window);
+        validate!(
+            liquidation_window == 0
+                || (TIME_FOR_LIQUIDATION..=MAX_TIME_FOR_LIQUIDATION).contains(&liquidation_window),
+            ErrorCode::InvalidVaultUpdate,
+            "liquidation window must be between {} and {} seconds",
+            TIME_FOR_LIQUIDATION,
+            MAX_TIME_FOR_LIQUIDATION
+        )?;
+        self.pending_liquidation_window = liquidation_window;
+
+        self.pending_permissionless_liquidation =
+            permissionless_liquidation.unwrap_or(self.permissionless_liquidation);
+        self.pending_liquidation_settings_ts = effective_ts;
+
+        Ok(())
+    }
+
+    /// Applies the pending liquidation settings once effective. A vault in liquidation keeps its settings
+    /// until the liquidation ends.
+    pub fn apply_pending_liquidation_settings(&mut self, now: i64) {
+        if self.pending_liquidation_settings_ts == 0
+            || now < self.pending_liquidation_settings_ts
+            || self.in_liquidation()
+        {
+            return;
+        }
+
+        self.liquidators = self.pending_liquidators;
+        self.liquidation_window = self.pending_liquidation_window;
+        self.permissionless_liquidation = self.pending_permissionless_liquidation;
+        self.reset_pending_liquidation_settings();
+    }
+
+    fn reset_pending_liquidation_settings(&mut self) {
+        self.pending_liquidators = [Pubkey::default(); MAX_LIQUIDATORS];
+        self.pending_liquidation_window = 0;
+        self.pending_permissionless_liquidation = false;
+        self.pending_liquidation_settings_ts = 0;
+    }
+
+    fn reset_pending_params(&mut self) {
+        self.pending_management_fee = 0;
+        self.pending_redeem_period = 0;
+        self.pending_profit_share = 0;
+        self.pending_hurdle_rate = 0;
+        self.pending_entry_fee = 0;
+        self.pending_exit_fee = 0;
+        self.pending_early_withdraw_penalty = 0;
+        self.pending_profit_share_crystallization_period = 0;
+        self.pending_params_effective_ts = 0;
+    }
+
+    fn emit_pending_params_record(&self, action: VaultParamsUpdateAction, ts: i64) {
+        emit!(VaultParamsUpdateRecord {
+            ts,
+            vault: self.pubkey,
+            action,
+            effective_ts: self.pending_params_effective_ts,
+            management_fee: self.pending_management_fee,
+            redeem_period: self.pending_redeem_period,
+            profit_share: self.pending_profit_share,
+            hurdle_rate: self.pending_hurdle_rate,
+            entry_fee: self.pending_entry_fee,
+            exit_fee: self.pending_exit_fee,
+            early_withdraw_penalty: self.pending_early_withdraw_penalty,
+            profit_share_crystallization_period: self.pending_profit_share_crystallization_period,
+        });
+    }
+
     pub fn get_manager_shares(
         &self,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
@@ -367,7 +795,12 @@ impl Vault {
             if expo_diff != 0 {
                 self.total_shares = self.total_shares.safe_div(_rebase_divisor)?;
                 self.user_shares = self.user_shares.safe_div(_rebase_divisor)?;
+                self.withdraw_queue_shares =
+                    self.withdraw_queue_shares.safe_div(_rebase_divisor)?;
                 self.shares_base = self.shares_base.safe_add(expo_diff)?;
+                self.high_water_mark_share_price = self
+                    .high_water_mark_share_price
+                    .safe_mul(_rebase_divisor.cast()?)?;
                 if let Some(vp) = vault_protocol {
                     vp.protocol_profit_and_fee_shares = vp
                         .protocol_profit_and_fee_shares
@@ -387,16 +820,64 @@ impl Vault {
         Ok(rebase_divisor)
     }
 
+    pub fn get_sub_account_count(&self) -> u16 {
+        self.sub_account_count.max(1)
+    }
+
+    /// Checks `sub_accounts` are exactly the vault's drift sub-accounts besides the primary `user`:
+    /// owned by the vault, each id in `1..sub_account_count` passed once.
+    pub fn validate_sub_accounts(&self, sub_accounts: &[AccountLoader<User>]) -> Result<()> {
+        validate!(
+            sub_accounts.len() == self.get_sub_account_count().safe_sub(1)?.cast::<usize>()?,
+            ErrorCode::InvalidVaultSubAccounts,
+            "expected {} sub-accounts in remaining accounts, got {}",
+            self.get_sub_account_count().safe_sub(1)?,
+            sub_accounts.len()
+        )?;
+
+        let mut seen_sub_account_ids = 0_u64;
+        for sub_account in sub_accounts {
+            let sub_account = sub_account.load()?;
+
+            validate!(
+                sub_account.authority == self.pubkey
+                    && sub_account.sub_account_id != 0
+                    && sub_account.sub_account_id < self.get_sub_account_count()
+                    && seen_sub_account_ids & (1 << sub_account.sub_account_id) == 0,
+                ErrorCode::InvalidVaultSubAccounts,
+                "invalid sub-account {} for vault",
+                sub_account.sub_account_id
+            )?;
+            seen_sub_account_ids |= 1 << sub_account.sub_account_id;
+        }
+
+        Ok(())
+    }
+
+    /// The vault equity summed across the primary `user`, all other drift sub-accounts owned by the vault
+    /// and, if `if_stake_in_equity`, the value of the vault's insurance fund stake.
     pub fn calculate_equity(
         &self,
         user: &User,
+        equity_accounts: &VaultEquityAccounts,
         perp_market_map: &PerpMarketMap,
         spot_market_map: &SpotMarketMap,
         oracle_map: &mut OracleMap,
-    ) -> VaultResult<u64> {
-        let (vault_equity, all_oracles_valid) =
+    ) -> Result<u64> {
+        let (mut vault_equity, mut all_oracles_valid) =
             calculate_user_equity(user, perp_market_map, spot_market_map, oracle_map)?;
 
+        self.validate_sub_accounts(&equity_accounts.sub_accounts)?;
+
+        for sub_account in &equity_accounts.sub_accounts {
+            let sub_account = sub_account.load()?;
+
+            let (sub_account_equity, sub_account_oracles_valid) =
+                calculate_user_equity(&sub_account, perp_market_map, spot_market_map, oracle_map)?;
+            vault_equity = vault_equity.safe_add(sub_account_equity)?;
+            all_oracles_valid = all_oracles_valid && sub_account_oracles_valid;
+        }
+
         validate!(
             all_oracles_valid,
             ErrorCode::InvalidEquityValue,
@@ -415,10 +896,182 @@ impl Vault {
             .price
             .cast::<i128>()?;
 
-        Ok(vault_equity
+        let vault_equity = vault_equity
             .safe_mul(spot_market_precision)?
             .safe_div(oracle_price)?
-            .cast::<u64>()?)
+            .cast::<u64>()?;
+
+        if !self.if_stake_in_equity {
+            return Ok(vault_equity);
+        }
+
+        let if_vault = equity_accounts
+            .token_accounts
+            .iter()
+            .find(|acct| acct.key() == spot_market.insurance_fund.vault);
+        let (if_stake, if_vault) = match (&equity_accounts.insurance_fund_stake, if_vault) {
+            (Some(if_stake), Some(if_vault)) => (if_stake.load()?, if_vault),
+            _ => {
+                let ec = ErrorCode::InvalidEquityValue;
+                msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+                msg!("insurance fund stake and vault missing in remaining accounts");
+                return Err(anchor_lang::error::Error::from(ec));
+            }
+        };
+
+        validate!(
+            if_stake.authority == self.pubkey && if_stake.market_index == self.spot_market_index,
+            ErrorCode::InvalidEquityValue,
+            "invalid insurance fund stake for vault"
+        )?;
+
+        let if_stake_value = depositor_shares_to_vault_amount(
+            if_stake.checked_if_shares(&spot_market)?,
+            spot_market.insurance_fund.total_shares,
+            if_vault.amount,
+        )?;
+
+        Ok(vault_equity.safe_add(if_stake_value)?)
+    }
+
+    /// Whether a profit share crystallization boundary has passed since `last_crystallization_ts`
+    pub fn is_profit_share_crystallization_due(
+        &self,
+        last_crystallization_ts: i64,
+        now: i64,
+    ) -> VaultResult<bool> {
+        if self.profit_share_crystallization_period == 0 {
+            return Ok(true);
+        }
+
+        let since_init = now.safe_sub(self.init_ts)?.max(0);
+        let last_boundary_ts = self.init_ts.safe_add(
+            since_init
+                .safe_div(self.profit_share_crystallization_period)?
+                .safe_mul(self.profit_share_crystallization_period)?,
+        )?;
+
+        Ok(last_boundary_ts > last_crystallization_ts.max(self.init_ts))
+    }
+
+    /// Charges the manager and protocol entry fee on a depositor's deposit of `amount`.
+    /// The returned fee_shares are kept by the manager and protocol out of the shares minted for the deposit.
+    pub fn apply_entry_fee(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        amount: u64,
+        vault_equity: u64,
+    ) -> Result<VaultEntryExitFee> {
+        let protocol_entry_fee = vault_protocol
+            .as_ref()
+            .map_or(0, |vp| vp.protocol_entry_fee);
+        self.apply_entry_exit_fee(
+            vault_protocol,
+            amount,
+            vault_equity,
+            self.entry_fee,
+            protocol_entry_fee,
+        )
+    }
+
+    /// Charges the manager and protocol exit fee on a depositor's withdrawal of `amount`.
+    /// The returned fee_shares are kept by the manager and protocol out of the shares burned for the withdrawal.
+    pub fn apply_exit_fee(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        amount: u64,
+        vault_equity: u64,
+    ) -> Result<VaultEntryExitFee> {
+        if self.wind_down {
+            return self.apply_entry_exit_fee(vault_protocol, amount, vault_equity, 0, 0);
+        }
+
+        let protocol_exit_fee = vault_protocol.as_ref().map_or(0, |vp| vp.protocol_exit_fee);
+        self.apply_entry_exit_fee(
+            vault_protocol,
+            amount,
+            vault_equity,
+            self.exit_fee,
+            protocol_exit_fee,
+        )
+    }
+
+    /// Charges the early withdraw penalty on a depositor's withdrawal of `amount`.
+    /// Returns the penalty amount and, if paid to the manager, the shares kept by the manager.
+    pub fn apply_early_withdraw_penalty(
+        &mut self,
+        amount: u64,
+        vault_equity: u64,
+        early_withdraw_penalty: u32,
+    ) -> Result<(u64, u128)> {
+        let penalty_amount = amount
+            .cast::<u128>()?
+            .safe_mul(early_withdraw_penalty.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast::<u64>()?;
+
+        if penalty_amount == 0 || !self.early_withdraw_penalty_to_manager {
+            return Ok((penalty_amount, 0));
+        }
+
+        let penalty_shares =
+            vault_amount_to_depositor_shares(penalty_amount, self.total_shares, vault_equity)?;
+        self.manager_total_fee = self
+            .manager_total_fee
+            .saturating_add(penalty_amount.cast()?);
+
+        Ok((penalty_amount, penalty_shares))
+    }
+
+    fn apply_entry_exit_fee(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        amount: u64,
+        vault_equity: u64,
+        manager_fee_rate: u32,
+        protocol_fee_rate: u32,
+    ) -> Result<VaultEntryExitFee> {
+        let manager_fee = amount
+            .cast::<u128>()?
+            .safe_mul(manager_fee_rate.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast::<u64>()?;
+        let protocol_fee = amount
+            .cast::<u128>()?
+            .safe_mul(protocol_fee_rate.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast::<u64>()?;
+
+        let fee_shares = vault_amount_to_depositor_shares(
+            manager_fee.safe_add(protocol_fee)?,
+            self.total_shares,
+            vault_equity,
+        )?;
+
+        if manager_fee > 0 {
+            self.manager_total_fee = self.manager_total_fee.saturating_add(manager_fee.cast()?);
+        }
+
+        if let Some(vp) = vault_protocol {
+            if protocol_fee > 0 {
+                let protocol_fee_shares = vault_amount_to_depositor_shares(
+                    protocol_fee,
+                    self.total_shares,
+                    vault_equity,
+                )?
+                .min(fee_shares);
+                vp.protocol_profit_and_fee_shares = vp
+                    .protocol_profit_and_fee_shares
+                    .safe_add(protocol_fee_shares)?;
+                vp.protocol_total_fee = vp.protocol_total_fee.saturating_add(protocol_fee);
+            }
+        }
+
+        Ok(VaultEntryExitFee {
+            manager_fee,
+            protocol_fee,
+            fee_shares,
+        })
     }
 
     pub fn manager_deposit(
@@ -428,6 +1081,18 @@ impl Vault {
         vault_equity: u64,
         now: i64,
     ) -> Result<()> {
+        validate!(
+            !self.wind_down,
+            ErrorCode::VaultWindingDown,
+            "vault is winding down"
+        )?;
+        validate!(
+            !self.is_paused(now),
+            ErrorCode::VaultPaused,
+            "vault is paused until {}",
+            self.paused_until_ts
+        )?;
+
         self.apply_rebase(vault_protocol, vault_equity)?;
         let VaultFee {
             management_fee_payment,
@@ -490,7 +1155,7 @@ impl Vault {
         )?;
 
         validate!(
-            now.saturating_sub(self.liquidation_start_ts) > TIME_FOR_LIQUIDATION,
+            now.saturating_sub(self.liquidation_start_ts) > self.get_liquidation_window(),
             ErrorCode::VaultInLiquidation,
             "vault is still in liquidation"
         )?;
@@ -738,13 +1403,421 @@ impl Vault {
         Ok(n_tokens)
     }
 
+    pub fn propose_manager(&mut self, new_manager: Pubkey) -> VaultResult {
+        validate!(
+            new_manager != self.manager,
+            ErrorCode::InvalidVaultUpdate,
+            "new manager is already the manager"
+        )?;
+
+        // proposing the default pubkey cancels the pending handover
+        self.pending_manager = new_manager;
+
+        Ok(())
+    }
+
+    /// Hands the vault over to the pending manager and returns the previous manager.
+    /// Manager shares, the last manager withdraw request and the vault owned insurance fund stakes
+    /// are all controlled through `manager`, so they move to the new manager as is.
+    /// If the old manager was the vault delegate, the new manager becomes the delegate.
+    pub fn accept_manager(&mut self, new_manager: Pubkey) -> VaultResult<Pubkey> {
+        validate!(
+            self.pending_manager != Pubkey::default() && self.pending_manager == new_manager,
+            ErrorCode::InvalidVaultUpdate,
+            "signer is not the pending manager"
+        )?;
+
+        let old_manager = self.manager;
+        self.manager = new_manager;
+        self.pending_manager = Pubkey::default();
+        self.manager_withdraw_destination = WithdrawDestination::default();
+
+        if self.delegate == old_manager {
+            self.delegate = new_manager;
+        }
+
+        Ok(old_manager)
+    }
+
+    /// Checks a Merkle proof that `authority` is on the vault's allowlist with a `max_equity` cap. Leaves are
+    /// `keccak(authority || max_equity as le bytes)` and pairs are hashed in sorted order.
+    pub fn verify_allowlist_proof(
+        &self,
+        authority: &Pubkey,
+        max_equity: u64,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        if self.allowlist_merkle_root == [0u8; 32] {
+            return false;
+        }
+
+        let mut node = hashv(&[authority.as_ref(), &max_equity.to_le_bytes()]).to_bytes();
+        for sibling in proof.iter() {
+            node = if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+
+        node == self.allowlist_merkle_root
+    }
+
+    pub fn is_token_gated(&self) -> bool {
+        self.access_mint != Pubkey::default() || self.access_collection != Pubkey::default()
+    }
+
+    /// Checks `authority` holds the vault's access token, if the vault is token gated
+    pub fn validate_access(
+        &self,
+        authority: &Pubkey,
+        access_accounts: &VaultAccessAccounts,
+    ) -> Result<()> {
+        if !self.is_token_gated() {
+            return Ok(());
+        }
+
+        let holds = |mint: &Pubkey, min_balance: u64| {
+            access_accounts.token_accounts.iter().any(|token_account| {
+                token_account.owner == *authority
+                    && token_account.mint == *mint
+                    && token_account.amount >= min_balance.max(1)
+            })
+        };
+
+        let holds_access_mint = self.access_mint != Pubkey::default()
+            && holds(&self.access_mint, self.access_min_balance);
+        let holds_collection_nft = self.access_collection != Pubkey::default()
+            && access_accounts.metadata_accounts.iter().any(|metadata| {
+                metadata.collection.as_ref().map_or(false, |collection| {
+                    collection.verified && collection.key == self.access_collection
+                }) && holds(&metadata.mint, 1)
+            });
+
+        validate!(
+            holds_access_mint || holds_collection_nft,
+            ErrorCode::PermissionedVault,
+            "authority {} does not hold the vault access token",
+            authority
+        )?;
+
+        Ok(())
+    }
+
+    /// Starts winding down the vault, charging the management and protocol fees accrued so far. Afterwards
+    /// deposits are rejected, withdraws have no redeem period and no fees accrue. A withdraw queue is left
+    /// on: queued requests are overdue at once, and `wind_down_withdraw` takes them out of the queue and
+    /// pays them out directly.
+    pub fn start_wind_down(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<VaultFee> {
+        validate!(
+            !self.wind_down,
+            ErrorCode::VaultWindingDown,
+            "vault is already winding down"
+        )?;
+        validate!(!self.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+        self.apply_rebase(vault_protocol, vault_equity)?;
+        let vault_fee = self.apply_fee(vault_protocol, vault_equity, now)?;
+
+        self.wind_down = true;
+        self.redeem_period = 0;
+        self.reset_pending_params();
+
+        Ok(vault_fee)
+    }
+
+    /// Validates the vault has wound down with no shares left, so its accounts can be closed.
+    pub fn validate_can_close(&self) -> VaultResult {
+        validate!(
+            self.wind_down,
+            ErrorCode::InvalidVaultClose,
+            "vault must be winding down before it is closed"
+        )?;
+        validate!(
+            self.total_shares == 0 && self.user_shares == 0,
+            ErrorCode::InvalidVaultClose,
+            "vault still has {} shares",
+            self.total_shares
+        )?;
+        validate!(
+            !self.vault_protocol,
+            ErrorCode::InvalidVaultClose,
+            "vault protocol must be closed before the vault"
+        )?;
+
+        Ok(())
+    }
+
+    pub fn is_paused(&self, now: i64) -> bool {
+        now < self.paused_until_ts
+    }
+
+    /// Pauses the vault until `now + duration`. An active pause can't be extended, so a pause always expires
+    /// within [`MAX_PAUSE_DURATION`] of it starting, and the next one can only start [`PAUSE_COOLDOWN`] after
+    /// it ended.
+    pub fn pause(&mut self, authority: Pubkey, duration: i64, now: i64) -> VaultResult<i64> {
+        validate!(!self.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+        validate!(
+            !self.is_paused(now),
+            ErrorCode::VaultPaused,
+            "vault is already paused until {}",
+            self.paused_until_ts
+        )?;
+        validate!(
+            self.pause_end_ts == 0 || now >= self.pause_end_ts.safe_add(PAUSE_COOLDOWN)?,
+            ErrorCode::VaultPaused,
+            "vault can't be paused again until {}",
+            self.pause_end_ts.safe_add(PAUSE_COOLDOWN)?
+        )?;
+        validate!(
+            duration > 0 && duration <= MAX_PAUSE_DURATION,
+            ErrorCode::InvalidVaultUpdate,
+            "pause duration must be between 1 and {} seconds",
+            MAX_PAUSE_DURATION
+        )?;
+
+        self.paused_until_ts = now.safe_add(duration)?;
+        self.paused_by = authority;
+        // moved earlier if the pause is lifted before it expires
+        self.pause_end_ts = self.paused_until_ts;
+
+        Ok(self.paused_until_ts)
+    }
+
+    /// Lifts the pause. Before it expires only the authority that paused the vault can unpause it, after
+    /// that the manager can too.
+    pub fn unpause(&mut self, authority: Pubkey, now: i64) -> VaultResult {
+        validate!(
+            self.paused_until_ts != 0,
+            ErrorCode::InvalidVaultUpdate,
+            "vault is not paused"
+        )?;
+        validate!(
+            self.paused_by == authority || (!self.is_paused(now) && self.manager == authority),
+            ErrorCode::VaultPaused,
+            "{} can't unpause the vault paused by {} until {}",
+            authority,
+            self.paused_by,
+            self.paused_until_ts
+        )?;
+
+        if self.is_paused(now) {
+            self.pause_end_ts = now;
+        }
+        self.paused_until_ts = 0;
+        self.paused_by = Pubkey::default();
+
+        Ok(())
+    }
+
+    /// The delegate the vault's drift users should have: the liquidator during liquidation, the null key
+    /// while paused, otherwise the manager's delegate.
+    pub fn get_drift_delegate(&self, now: i64) -> Pubkey {
+        if self.in_liquidation() {
+            self.liquidation_delegate
+        } else if self.is_paused(now) {
+            Pubkey::default()
+        } else {
+            self.delegate
+        }
+    }
+
+    /// The vault equity per share: PRICE_PRECISION
+    pub fn get_share_price(&self, vault_equity: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(0);
+        }
+
+        Ok(vault_equity
+            .cast::<u128>()?
+            .safe_mul(PRICE_PRECISION)?
+            .safe_div(self.total_shares)?
+            .cast()?)
+    }
+
+    pub fn in_drawdown_breach(&self) -> bool {
+        self.drawdown_breach_ts != 0
+    }
+
+    /// Tightening the max drawdown applies immediately, loosening or disabling it only takes effect at least a
+    /// redeem period and [`MIN_PARAMS_TIMELOCK`] later so depositors can exit before. The high water mark is
+    /// only restarted when the circuit breaker is enabled.
+    pub fn update_max_drawdown(&mut self, max_drawdown: u32, now: i64) -> Result<()> {
+        validate!(
+            max_drawdown.cast::<u64>()? < PERCENTAGE_PRECISION_U64,
+            ErrorCode::InvalidVaultUpdate,
+            "max drawdown must be < 100%"
+        )?;
+
+        self.apply_pending_max_drawdown(now);
+        // a new update replaces the pending one
+        self.pending_max_drawdown = 0;
+        self.pending_max_drawdown_ts = 0;
+
+        if max_drawdown != 0 && (self.max_drawdown == 0 || max_drawdown <= self.max_drawdown) {
+            if self.max_drawdown == 0 {
+                // the high water mark restarts at the share price of the next equity check
+                self.high_water_mark_share_price = 0;
+            }
+            self.max_drawdown = max_drawdown;
+        } else {
+            self.pending_max_drawdown = max_drawdown;
+            self.pending_max_drawdown_ts =
+                now.safe_add(self.redeem_period.max(MIN_PARAMS_TIMELOCK))?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_pending_max_drawdown(&mut self, now: i64) {
+        if self.pending_max_drawdown_ts != 0 && now >= self.pending_max_drawdown_ts {
+            self.max_drawdown = self.pending_max_drawdown;
+            self.pending_max_drawdown = 0;
+            self.pending_max_drawdown_ts = 0;
+        }
+    }
+
+    /// Raises the share price high water mark and checks the drawdown from it against `max_drawdown`.
+    /// Returns true if the max drawdown was just breached, in which case the drift users must be set to
+    /// reduce-only. Instructions that can't set them reduce-only leave it to the next [`Vault::enforce_drawdown`].
+    pub fn check_drawdown(&mut self, vault_equity: u64, now: i64) -> Result<bool> {
+        self.apply_pending_max_drawdown(now);
+
+        if self.max_drawdown == 0 || self.total_shares == 0 || self.in_drawdown_breach() {
+            return Ok(false);
+        }
+
+        let share_price = self.get_share_price(vault_equity)?;
+        if share_price >= self.high_water_mark_share_price {
+            self.high_water_mark_share_price = share_price;
+            return Ok(false);
+        }
+
+        let drawdown = self
+            .high_water_mark_share_price
+            .safe_sub(share_price)?
+            .cast::<u128>()?
+            .safe_mul(PERCENTAGE_PRECISION)?
+            .safe_div(self.high_water_mark_share_price.cast()?)?;
+        if drawdown < self.max_drawdown.cast()? {
+            return Ok(false);
+        }
+
+        self.drawdown_breach_ts = now;
+        self.drawdown_reduce_only_pending = true;
+
+        emit!(VaultDrawdownRecord {
+            ts: now,
+            vault: self.pubkey,
+            max_drawdown: self.max_drawdown,
+            high_water_mark_share_price: self.high_water_mark_share_price,
+            share_price,
+            breached: true,
+        });
+
+        Ok(true)
+    }
+
+    /// Checks the max drawdown like [`Vault::check_drawdown`]. Returns true if the drift users must be set to
+    /// reduce-only, because the drawdown was just breached or an earlier breach hasn't set them reduce-only yet.
+    pub fn enforce_drawdown(&mut self, vault_equity: u64, now: i64) -> Result<bool> {
+        self.check_drawdown(vault_equity, now)?;
+
+        let reduce_only = self.drawdown_reduce_only_pending;
+        self.drawdown_reduce_only_pending = false;
+
+        Ok(reduce_only)
+    }
+
+    /// Exits the drawdown breach after [`DRAWDOWN_COOLDOWN`], restarting the high water mark at the current
+    /// share price.
+    pub fn reset_drawdown_breach(&mut self, vault_equity: u64, now: i64) -> Result<()> {
+        validate!(
+            self.in_drawdown_breach(),
+            ErrorCode::InvalidVaultUpdate,
+            "max drawdown not breached"
+        )?;
+        validate!(
+            now >= self.drawdown_breach_ts.safe_add(DRAWDOWN_COOLDOWN)?,
+            ErrorCode::InvalidVaultUpdate,
+            "drawdown breach can't be reset until {}",
+            self.drawdown_breach_ts.safe_add(DRAWDOWN_COOLDOWN)?
+        )?;
+
+        let share_price = self.get_share_price(vault_equity)?;
+        self.drawdown_breach_ts = 0;
+        self.drawdown_reduce_only_pending = false;
+
+        emit!(VaultDrawdownRecord {
+            ts: now,
+            vault: self.pubkey,
+            max_drawdown: self.max_drawdown,
+            high_water_mark_share_price: self.high_water_mark_share_price,
+            share_price,
+            breached: false,
+        });
+
+        self.high_water_mark_share_price = share_price;
+
+        Ok(())
+    }
+
     pub fn in_liquidation(&self) -> bool {
         self.liquidation_delegate != Pubkey::default()
     }
 
+    pub fn get_liquidation_window(&self) -> i64 {
+        if self.liquidation_window == 0 {
+            TIME_FOR_LIQUIDATION
+        } else {
+            self.liquidation_window
+        }
+    }
+
+    /// The liquidator used when the depositor doesn't pick one: the first allowed liquidator, or the
+    /// [`permissioned_liquidator`] if none are set.
+    pub fn get_default_liquidator(&self) -> Pubkey {
+        self.liquidators
+            .iter()
+            .find(|liquidator| **liquidator != Pubkey::default())
+            .copied()
+            .unwrap_or_else(permissioned_liquidator::id)
+    }
+
+    pub fn validate_liquidator(&self, liquidator: &Pubkey) -> VaultResult {
+        validate!(
+            *liquidator != Pubkey::default(),
+            ErrorCode::DelegateNotAvailableForLiquidation,
+            "liquidator can't be the default pubkey"
+        )?;
+
+        if self.permissionless_liquidation {
+            return Ok(());
+        }
+
+        let allowed = if self.liquidators.iter().all(|l| *l == Pubkey::default()) {
+            *liquidator == permissioned_liquidator::id()
+        } else {
+            self.liquidators.contains(liquidator)
+        };
+        validate!(
+            allowed,
+            ErrorCode::DelegateNotAvailableForLiquidation,
+            "{} is not an allowed liquidator for the vault",
+            liquidator
+        )?;
+
+        Ok(())
+    }
+
     pub fn check_can_exit_liquidation(&self, now: i64) -> VaultResult {
         validate!(
-            now.saturating_sub(self.liquidation_start_ts) > TIME_FOR_LIQUIDATION,
+            now.saturating_sub(self.liquidation_start_ts) > self.get_liquidation_window(),
             ErrorCode::VaultInLiquidation,
             "vault is in liquidation"
         )?;
@@ -762,6 +1835,90 @@ impl Vault {
         self.liquidation_start_ts = 0;
     }
 
+    /// Anyone can settle the withdraw queue once its oldest request has waited the redeem period, so
+    /// depositors aren't trapped by a manager who never settles.
+    pub fn is_withdraw_queue_overdue(&self, now: i64) -> VaultResult<bool> {
+        Ok(self.withdraw_queue_shares > 0
+            && now.safe_sub(self.withdraw_queue_start_ts)? >= self.redeem_period)
+    }
+
+    /// Settles the current withdraw epoch, filling every queued request pro-rata with up to `available_amount`
+    /// of freed collateral at the current share price. Filled shares are burned and unfilled shares roll over
+    /// into the next epoch. Returns the token amount to withdraw from drift for depositors to claim.
+    pub fn settle_withdraw_epoch(
+        &mut self,
+        withdraw_epoch: &mut WithdrawEpoch,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        available_amount: u64,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<u64> {
+        validate!(
+            self.withdraw_queue,
+            ErrorCode::InvalidVaultWithdraw,
+            "withdraw queue is not enabled for vault"
+        )?;
+
+        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_fee(vault_protocol, vault_equity, now)?;
+
+        let requested_shares = self.withdraw_queue_shares;
+        validate!(
+            requested_shares > 0,
+            ErrorCode::InvalidVaultWithdrawSize,
+            "no shares queued for withdraw"
+        )?;
+        validate!(
+            available_amount > 0,
+            ErrorCode::InvalidVaultWithdrawSize,
+            "settle amount must be > 0"
+        )?;
+        let requested_amount =
+            depositor_shares_to_vault_amount(requested_shares, self.total_shares, vault_equity)?;
+
+        let filled_shares = if available_amount >= requested_amount {
+            requested_shares
+        } else {
+            requested_shares
+                .safe_mul(available_amount.cast()?)?
+                .safe_div(requested_amount.cast()?)?
+        };
+        let filled_amount =
+            depositor_shares_to_vault_amount(filled_shares, self.total_shares, vault_equity)?;
+
+        // exit fees and the cap at each request's value are applied when depositors claim, what is
+        // not paid out then is deposited back into the vault
+        self.total_shares = self.total_shares.safe_sub(filled_shares)?;
+        self.user_shares = self.user_shares.safe_sub(filled_shares)?;
+        self.withdraw_queue_shares = requested_shares.safe_sub(filled_shares)?;
+        // unfilled shares keep their place, the oldest request is still in the queue
+        if self.withdraw_queue_shares == 0 {
+            self.withdraw_queue_start_ts = 0;
+        }
+
+        withdraw_epoch.vault = self.pubkey;
+        withdraw_epoch.epoch = self.withdraw_epoch;
+        withdraw_epoch.requested_shares = requested_shares;
+        withdraw_epoch.filled_shares = filled_shares;
+        withdraw_epoch.filled_amount = filled_amount;
+        withdraw_epoch.settled_ts = now;
+        withdraw_epoch.shares_base = self.shares_base;
+
+        self.withdraw_epoch = self.withdraw_epoch.safe_add(1)?;
+
+        emit!(WithdrawEpochRecord {
+            ts: now,
+            vault: self.pubkey,
+            epoch: withdraw_epoch.epoch,
+            vault_equity_before: vault_equity,
+            requested_shares,
+            filled_shares,
+            filled_amount,
+        });
+
+        Ok(filled_amount)
+    }
+
     pub fn protocol_request_withdraw(
         &mut self,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
@@ -1054,6 +2211,56 @@ impl Vault {
         Ok(n_tokens)
     }
 
+    /// The protocol gives up its role: accrued protocol fees are settled, a withdraw request is made for all
+    /// remaining protocol shares, and all protocol fees are set to zero.
+    /// Once the shares are claimed with `protocol_withdraw`, the [`VaultProtocol`] can be removed from the vault.
+    pub fn protocol_renounce(
+        &mut self,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<()> {
+        if vault_protocol.is_none() {
+            validate!(
+                false,
+                ErrorCode::VaultProtocolMissing,
+                "Protocol cannot renounce a non-protocol vault"
+            )?;
+        }
+
+        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_fee(vault_protocol, vault_equity, now)?;
+
+        if let Some(vp) = vault_protocol {
+            validate!(
+                !vp.last_protocol_withdraw_request.pending(),
+                ErrorCode::VaultWithdrawRequestInProgress,
+                "protocol withdraw request must be finished or canceled before renouncing"
+            )?;
+        }
+
+        if self.get_protocol_shares(vault_protocol) > 0 {
+            self.protocol_request_withdraw(
+                vault_protocol,
+                PERCENTAGE_PRECISION_U64,
+                WithdrawUnit::SharesPercent,
+                vault_equity,
+                now,
+            )?;
+        }
+
+        if let Some(vp) = vault_protocol {
+            vp.protocol_fee = 0;
+            vp.protocol_profit_share = 0;
+            vp.protocol_entry_fee = 0;
+            vp.protocol_exit_fee = 0;
+            vp.pending_protocol = Pubkey::default();
+            vp.reset_pending_params();
+        }
+
+        Ok(())
+    }
+
     pub fn validate_vault_protocol(&self, vp: &Option<AccountLoader<VaultProtocol>>) -> Result<()> {
         match vp {
             None => {
@@ -1070,6 +2277,14 @@ impl Vault {
             }
             Some(vp) => {
                 if self.vault_protocol {
+                    // VaultProtocol created before the account grew must be migrated before use.
+                    if vp.to_account_info().data_len() < VaultProtocol::SIZE {
+                        let ec = ErrorCode::VaultProtocolNotMigrated;
+                        msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+                        msg!("VaultProtocol must be migrated with migrate_vault_protocol");
+                        return Err(anchor_lang::error::Error::from(ec));
+                    }
+
                     // Vault has VaultProtocol and rem accts provided one.
                     // check if PDA matches rem acct given.
                     let (expected, _) = Pubkey::find_program_address(
@@ -1121,6 +2336,7 @@ impl Vault {
                     profit_share: params.manager_profit_share,
                     management_fee: params.management_fee,
                     management_fee_shares: params.management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(protocol_params) => {
@@ -1147,6 +2363,8 @@ impl Vault {
                     protocol_fee_shares: protocol_params.protocol_fee_shares,
                     protocol_shares_before: protocol_params.protocol_shares_before,
                     protocol_shares_after: protocol_params.protocol_shares_after,
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         };
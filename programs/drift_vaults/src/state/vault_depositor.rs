@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 use drift::controller::spot_balance::update_spot_balances;
 use drift::error::ErrorCode as DriftErrorCode;
 use drift::math::casting::Cast;
-use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
@@ -19,12 +19,16 @@ use drift::state::user::User;
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
+use crate::constants::MAX_WITHDRAW_SEARCH_ITERATIONS;
 use crate::error::ErrorCode;
 use crate::events::VaultDepositorAction;
 use crate::state::events::{VaultDepositorRecord, VaultDepositorV1Record};
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::state::withdraw_unit::WithdrawUnit;
-use crate::state::{Vault, VaultDepositorBase, VaultFee, VaultProtocol};
+use crate::state::{
+    Vault, VaultDepositorBase, VaultEntryExitFee, VaultFee, VaultProtocol, WithdrawDestination,
+    WithdrawEpoch, WithdrawRequestSlots,
+};
 use crate::validate;
 use crate::Size;
 
@@ -57,11 +61,34 @@ pub struct VaultDepositor {
     /// the exponent for vault_shares decimal places
     pub vault_shares_base: u32,
     pub padding1: u32,
-    pub padding: [u64; 8],
+    /// the last ts profit share was crystallized on the whole position, the hurdle accrues from this ts
+    pub last_profit_share_ts: i64,
+    /// the withdraw epoch the pending withdraw request is queued in, if the vault has a withdraw queue
+    pub withdraw_request_epoch: u64,
+    /// the number of pending requests in the depositor's [`WithdrawRequestSlots`]
+    pub withdraw_request_slot_count: u8,
+    /// set by the manager to block deposits, withdraw requests and tokenizing. The manager can still force
+    /// the depositor out.
+    pub frozen: bool,
+    pub padding2: [u8; 6],
+    /// the shares reserved by pending requests in the depositor's [`WithdrawRequestSlots`]
+    pub withdraw_request_slots_shares: u128,
+    /// the token account withdrawals are paid to, if the authority registered one
+    pub withdraw_destination: WithdrawDestination,
+    /// overrides the vault's max_depositor_equity for this depositor if non-zero
+    pub max_equity_override: u64,
+    /// overrides the vault's min_depositor_balance for this depositor if non-zero
+    pub min_balance_override: u64,
+    /// the cap committed to in the depositor's allowlist leaf, used instead of the vault's
+    /// max_depositor_equity if non-zero and the manager set no max_equity_override
+    pub allowlist_max_equity: u64,
+    /// the last ts the vault's profit share crystallization schedule was applied to the depositor
+    pub last_crystallization_ts: i64,
+    pub padding: [u64; 1],
 }
 
 impl Size for VaultDepositor {
-    const SIZE: usize = 264 + 8;
+    const SIZE: usize = 352 + 8;
 }
 
 const_assert_eq!(
@@ -111,6 +138,20 @@ impl VaultDepositorBase for VaultDepositor {
     fn set_profit_share_fee_paid(&mut self, amount: u64) {
         self.profit_share_fee_paid = amount;
     }
+
+    fn get_last_profit_share_ts(&self) -> i64 {
+        self.last_profit_share_ts
+    }
+    fn set_last_profit_share_ts(&mut self, ts: i64) {
+        self.last_profit_share_ts = ts;
+    }
+
+    fn get_last_crystallization_ts(&self) -> i64 {
+        self.last_crystallization_ts
+    }
+    fn set_last_crystallization_ts(&mut self, ts: i64) {
+        self.last_crystallization_ts = ts;
+    }
 }
 
 impl VaultDepositor {
@@ -129,7 +170,36 @@ impl VaultDepositor {
             cumulative_profit_share_amount: 0,
             padding1: 0,
             profit_share_fee_paid: 0,
-            padding: [0u64; 8],
+            last_profit_share_ts: now,
+            withdraw_request_epoch: 0,
+            withdraw_request_slot_count: 0,
+            frozen: false,
+            padding2: [0u8; 6],
+            withdraw_request_slots_shares: 0,
+            withdraw_destination: WithdrawDestination::default(),
+            max_equity_override: 0,
+            min_balance_override: 0,
+            allowlist_max_equity: 0,
+            last_crystallization_ts: now,
+            padding: [0u64; 1],
+        }
+    }
+
+    pub fn get_max_equity(&self, vault: &Vault) -> u64 {
+        if self.max_equity_override != 0 {
+            self.max_equity_override
+        } else if self.allowlist_max_equity != 0 {
+            self.allowlist_max_equity
+        } else {
+            vault.max_depositor_equity
+        }
+    }
+
+    pub fn get_min_balance(&self, vault: &Vault) -> u64 {
+        if self.min_balance_override != 0 {
+            self.min_balance_override
+        } else {
+            vault.min_depositor_balance
         }
     }
 
@@ -145,6 +215,38 @@ impl VaultDepositor {
         Ok(())
     }
 
+    /// A frozen depositor can't be closed, and limit overrides granted by the manager can only be given
+    /// up by the manager closing the depositor, not by the depositor closing and re-initializing. The
+    /// allowlist cap doesn't block closing, re-initializing with a proof restores it from the leaf.
+    pub fn validate_can_close(&self, by_manager: bool) -> Result<()> {
+        validate!(
+            !self.frozen,
+            ErrorCode::InvalidVaultDepositorClose,
+            "vault depositor is frozen"
+        )?;
+
+        validate!(
+            by_manager || (self.max_equity_override == 0 && self.min_balance_override == 0),
+            ErrorCode::InvalidVaultDepositorClose,
+            "vault depositor has limit overrides set by the manager"
+        )?;
+
+        validate!(
+            self.vault_shares == 0,
+            ErrorCode::InvalidVaultDepositorClose,
+            "vault depositor still has {} shares",
+            self.vault_shares
+        )?;
+
+        validate!(
+            !self.last_withdraw_request.pending() && self.withdraw_request_slot_count == 0,
+            ErrorCode::InvalidVaultDepositorClose,
+            "vault depositor has a pending withdraw request"
+        )?;
+
+        Ok(())
+    }
+
     pub fn checked_vault_shares(&self, vault: &Vault) -> Result<u128> {
         self.validate_base(vault)?;
         Ok(self.vault_shares)
@@ -183,6 +285,9 @@ impl VaultDepositor {
             VaultDepositorBase::apply_rebase(self, vault, vault_protocol, vault_equity)?
         {
             self.last_withdraw_request.rebase(rebase_divisor)?;
+            self.withdraw_request_slots_shares = self
+                .withdraw_request_slots_shares
+                .safe_div(rebase_divisor)?;
             Ok(Some(rebase_divisor))
         } else {
             Ok(None)
@@ -194,37 +299,18 @@ impl VaultDepositor {
         total_amount: u64,
         vault: &Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
     ) -> Result<(u128, u128)> {
-        let profit = total_amount.cast::<i64>()?.safe_sub(
-            self.net_deposits
-                .safe_add(self.cumulative_profit_share_amount)?,
-        )?;
-        if profit > 0 {
-            let profit_u128 = profit.cast::<u128>()?;
-
-            let manager_profit_share_amount = profit_u128
-                .safe_mul(vault.profit_share.cast()?)?
-                .safe_div(PERCENTAGE_PRECISION)?;
-            let protocol_profit_share_amount = match vault_protocol {
-                None => 0,
-                Some(vp) => profit_u128
-                    .safe_mul(vp.protocol_profit_share.cast()?)?
-                    .safe_div(PERCENTAGE_PRECISION)?,
-            };
-            let profit_share_amount =
-                manager_profit_share_amount.safe_add(protocol_profit_share_amount)?;
-            self.cumulative_profit_share_amount = self
-                .cumulative_profit_share_amount
-                .safe_add(profit_u128.cast()?)?;
-            self.profit_share_fee_paid = self
-                .profit_share_fee_paid
-                .safe_add(profit_share_amount.cast()?)?;
-            return Ok((manager_profit_share_amount, protocol_profit_share_amount));
-        }
-
-        Ok((0, 0))
+        VaultDepositorBase::calculate_profit_share_and_update(
+            self,
+            total_amount,
+            vault,
+            vault_protocol,
+            now,
+        )
     }
 
+    /// Returns the vault shares minted to the depositor, net of entry fee shares
     pub fn deposit(
         &mut self,
         amount: u64,
@@ -232,7 +318,7 @@ impl VaultDepositor {
         vault: &mut Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
-    ) -> Result<()> {
+    ) -> Result<u128> {
         validate!(
             vault.max_tokens == 0 || vault.max_tokens > vault_equity.safe_add(amount)?,
             ErrorCode::VaultIsAtCapacity,
@@ -255,6 +341,25 @@ impl VaultDepositor {
             "Vault balance should be non-zero for new depositors to enter"
         )?;
 
+        validate!(
+            !self.frozen,
+            ErrorCode::VaultDepositorFrozen,
+            "vault depositor is frozen"
+        )?;
+
+        validate!(
+            !vault.wind_down,
+            ErrorCode::VaultWindingDown,
+            "vault is winding down"
+        )?;
+
+        validate!(
+            !vault.is_paused(now),
+            ErrorCode::VaultPaused,
+            "vault is paused until {}",
+            vault.paused_until_ts
+        )?;
+
         validate!(
             !self.last_withdraw_request.pending(),
             ErrorCode::WithdrawInProgress,
@@ -268,6 +373,21 @@ impl VaultDepositor {
         let user_vault_shares_before = vault.user_shares;
         let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
 
+        let max_equity = self.get_max_equity(vault);
+        let depositor_equity = depositor_shares_to_vault_amount(
+            vault_shares_before,
+            vault.total_shares,
+            vault_equity,
+        )?
+        .safe_add(amount)?;
+        validate!(
+            max_equity == 0 || depositor_equity <= max_equity,
+            ErrorCode::VaultIsAtCapacity,
+            "after deposit depositor equity is {} > {}",
+            depositor_equity,
+            max_equity
+        )?;
+
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
@@ -275,20 +395,32 @@ impl VaultDepositor {
             protocol_fee_shares,
         } = vault.apply_fee(vault_protocol, vault_equity, now)?;
         let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_protocol, now)?;
 
         let n_shares = vault_amount_to_depositor_shares(amount, vault.total_shares, vault_equity)?;
 
+        let VaultEntryExitFee {
+            manager_fee: manager_entry_fee,
+            protocol_fee: protocol_entry_fee,
+            fee_shares: entry_fee_shares,
+        } = vault.apply_entry_fee(vault_protocol, amount, vault_equity)?;
+        let depositor_amount = amount
+            .safe_sub(manager_entry_fee)?
+            .safe_sub(protocol_entry_fee)?;
+        let depositor_shares = n_shares.safe_sub(entry_fee_shares)?;
+
+        self.update_last_profit_share_ts_for_deposit(depositor_amount, vault, now)?;
+
         self.total_deposits = self.total_deposits.saturating_add(amount);
-        self.net_deposits = self.net_deposits.safe_add(amount.cast()?)?;
+        self.net_deposits = self.net_deposits.safe_add(depositor_amount.cast()?)?;
 
         vault.total_deposits = vault.total_deposits.saturating_add(amount);
         vault.net_deposits = vault.net_deposits.safe_add(amount.cast()?)?;
 
-        self.increase_vault_shares(n_shares, vault)?;
+        self.increase_vault_shares(depositor_shares, vault)?;
 
         vault.total_shares = vault.total_shares.safe_add(n_shares)?;
-        vault.user_shares = vault.user_shares.safe_add(n_shares)?;
+        vault.user_shares = vault.user_shares.safe_add(depositor_shares)?;
 
         let vault_shares_after = self.checked_vault_shares(vault)?;
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
@@ -312,6 +444,7 @@ impl VaultDepositor {
                     profit_share: manager_profit_share,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: manager_entry_fee,
                 });
             }
             Some(_) => {
@@ -337,11 +470,13 @@ impl VaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
+                    protocol_entry_exit_fee: protocol_entry_fee,
+                    manager_entry_exit_fee: manager_entry_fee,
                 });
             }
         }
 
-        Ok(())
+        Ok(depositor_shares)
     }
 
     pub fn request_withdraw(
@@ -353,23 +488,106 @@ impl VaultDepositor {
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
     ) -> Result<()> {
+        validate!(
+            !self.frozen,
+            ErrorCode::VaultDepositorFrozen,
+            "vault depositor is frozen"
+        )?;
+
         let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
+
+        let mut request = self.last_withdraw_request;
+        self.request_withdraw_from_available_shares(
+            &mut request,
+            self.withdraw_request_slots_shares,
+            rebase_divisor,
+            withdraw_amount,
+            withdraw_unit,
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+        )?;
+        self.last_withdraw_request = request;
+
+        Ok(())
+    }
+
+    /// Requests a withdraw into `request` from the depositor's shares not already reserved by other requests
+    #[allow(clippy::too_many_arguments)]
+    fn request_withdraw_from_available_shares(
+        &mut self,
+        request: &mut WithdrawRequest,
+        reserved_shares: u128,
+        rebase_divisor: Option<u128>,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<()> {
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
         } = vault.apply_fee(vault_protocol, vault_equity, now)?;
-        let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
 
-        let (withdraw_value, n_shares) = withdraw_unit.get_withdraw_value_and_shares(
+        let available_shares = self.get_vault_shares().safe_sub(reserved_shares)?;
+        let (_, requested_shares) = withdraw_unit.get_withdraw_value_and_shares(
             withdraw_amount,
             vault_equity,
-            self.get_vault_shares(),
+            available_shares,
             vault.total_shares,
             rebase_divisor,
         )?;
+        let full_exit = requested_shares >= available_shares;
+
+        // a full exit crystallizes profit share regardless of the vault's crystallization schedule. a partial
+        // withdraw before the schedule is due, or while other withdraw requests are pending, crystallizes the
+        // fraction being withdrawn
+        let other_requests_pending = reserved_shares > 0 || self.withdraw_request_slot_count > 0;
+        let (manager_profit_share, protocol_profit_share) = if other_requests_pending {
+            self.crystallize_partial_profit_share(
+                requested_shares,
+                vault_equity,
+                vault,
+                vault_protocol,
+                now,
+            )?
+        } else if full_exit {
+            self.crystallize_profit_share(vault_equity, vault, vault_protocol, now)?
+        } else if vault.is_profit_share_crystallization_due(self.last_crystallization_ts, now)? {
+            self.apply_profit_share(vault_equity, vault, vault_protocol, now)?
+        } else {
+            self.crystallize_partial_profit_share(
+                requested_shares,
+                vault_equity,
+                vault,
+                vault_protocol,
+                now,
+            )?
+        };
+
+        let available_shares = self.get_vault_shares().safe_sub(reserved_shares)?;
+        let (withdraw_value, n_shares) = if full_exit {
+            WithdrawUnit::SharesPercent.get_withdraw_value_and_shares(
+                PERCENTAGE_PRECISION_U64,
+                vault_equity,
+                available_shares,
+                vault.total_shares,
+                rebase_divisor,
+            )?
+        } else {
+            withdraw_unit.get_withdraw_value_and_shares(
+                withdraw_amount,
+                vault_equity,
+                available_shares,
+                vault.total_shares,
+                rebase_divisor,
+            )?
+        };
 
         validate!(
             n_shares > 0,
@@ -377,19 +595,43 @@ impl VaultDepositor {
             "Requested n_shares = 0"
         )?;
 
+        let remaining_shares = available_shares.safe_sub(n_shares)?;
+        if remaining_shares > 0 {
+            let remaining_equity = depositor_shares_to_vault_amount(
+                remaining_shares,
+                vault.total_shares,
+                vault_equity,
+            )?;
+            let min_balance = self.get_min_balance(vault);
+            validate!(
+                remaining_equity >= min_balance,
+                ErrorCode::InvalidVaultWithdrawSize,
+                "withdraw leaves {} < min balance {}, withdraw everything instead",
+                remaining_equity,
+                min_balance
+            )?;
+        }
+
         let vault_shares_before: u128 = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
         let user_vault_shares_before = vault.user_shares;
         let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
 
-        self.last_withdraw_request.set(
-            vault_shares_before,
+        request.set(
+            vault_shares_before.safe_sub(reserved_shares)?,
             n_shares,
             withdraw_value,
             vault_equity,
             now,
         )?;
         vault.total_withdraw_requested = vault.total_withdraw_requested.safe_add(withdraw_value)?;
+        if vault.withdraw_queue {
+            self.withdraw_request_epoch = vault.withdraw_epoch;
+            if vault.withdraw_queue_shares == 0 {
+                vault.withdraw_queue_start_ts = now;
+            }
+            vault.withdraw_queue_shares = vault.withdraw_queue_shares.safe_add(n_shares)?;
+        }
 
         let vault_shares_after = self.checked_vault_shares(vault)?;
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
@@ -401,7 +643,7 @@ impl VaultDepositor {
                     vault: vault.pubkey,
                     depositor_authority: self.authority,
                     action: VaultDepositorAction::WithdrawRequest,
-                    amount: self.last_withdraw_request.value,
+                    amount: request.value,
                     spot_market_index: vault.spot_market_index,
                     vault_equity_before: vault_equity,
                     vault_shares_before,
@@ -413,6 +655,7 @@ impl VaultDepositor {
                     profit_share: manager_profit_share,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(_) => {
@@ -421,7 +664,7 @@ impl VaultDepositor {
                     vault: vault.pubkey,
                     depositor_authority: self.authority,
                     action: VaultDepositorAction::WithdrawRequest,
-                    amount: self.last_withdraw_request.value,
+                    amount: request.value,
                     spot_market_index: vault.spot_market_index,
                     vault_equity_before: vault_equity,
                     vault_shares_before,
@@ -438,6 +681,8 @@ impl VaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         }
@@ -445,6 +690,123 @@ impl VaultDepositor {
         Ok(())
     }
 
+    /// Requests a withdraw into an empty slot of the depositor's [`WithdrawRequestSlots`], from shares not
+    /// reserved by other requests. Returns the slot used.
+    #[allow(clippy::too_many_arguments)]
+    pub fn request_withdraw_slot(
+        &mut self,
+        withdraw_request_slots: &mut WithdrawRequestSlots,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<u8> {
+        validate!(
+            !self.frozen,
+            ErrorCode::VaultDepositorFrozen,
+            "vault depositor is frozen"
+        )?;
+
+        validate!(
+            !vault.withdraw_queue,
+            ErrorCode::InvalidVaultWithdraw,
+            "withdraw request slots are not available for vaults with a withdraw queue"
+        )?;
+
+        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        withdraw_request_slots.rebase(vault)?;
+
+        let slot = withdraw_request_slots.find_empty_slot()?;
+        let reserved_shares = self
+            .withdraw_request_slots_shares
+            .safe_add(self.last_withdraw_request.shares)?;
+
+        let request = withdraw_request_slots.get_request_mut(slot)?;
+        self.request_withdraw_from_available_shares(
+            request,
+            reserved_shares,
+            rebase_divisor,
+            withdraw_amount,
+            withdraw_unit,
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+        )?;
+
+        self.withdraw_request_slot_count = self.withdraw_request_slot_count.safe_add(1)?;
+        self.withdraw_request_slots_shares = self
+            .withdraw_request_slots_shares
+            .safe_add(request.shares)?;
+
+        Ok(slot)
+    }
+
+    pub fn cancel_withdraw_request_slot(
+        &mut self,
+        withdraw_request_slots: &mut WithdrawRequestSlots,
+        slot: u8,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<()> {
+        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        withdraw_request_slots.rebase(vault)?;
+
+        let request = withdraw_request_slots.get_request_mut(slot)?;
+        validate!(
+            request.pending(),
+            ErrorCode::InvalidVaultWithdraw,
+            "no withdraw request in slot {}",
+            slot
+        )?;
+
+        let request_shares = request.shares;
+        self.cancel_request(request, vault_equity, vault, vault_protocol, now)?;
+        self.release_withdraw_request_slot(request_shares)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_slot(
+        &mut self,
+        withdraw_request_slots: &mut WithdrawRequestSlots,
+        slot: u8,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        withdraw_request_slots.rebase(vault)?;
+
+        let request = withdraw_request_slots.get_request_mut(slot)?;
+        request.check_redeem_period_finished(vault, now)?;
+
+        let request_shares = request.shares;
+        let result =
+            self.withdraw_request(request, vault_equity, vault, vault_protocol, 0, None, now)?;
+        self.release_withdraw_request_slot(request_shares)?;
+
+        Ok(result)
+    }
+
+    fn release_withdraw_request_slot(&mut self, request_shares: u128) -> Result<()> {
+        self.withdraw_request_slot_count = self.withdraw_request_slot_count.safe_sub(1)?;
+        // slots are rebased lazily so the reserved total can round above the sum of the slots
+        self.withdraw_request_slots_shares = if self.withdraw_request_slot_count == 0 {
+            0
+        } else {
+            self.withdraw_request_slots_shares
+                .saturating_sub(request_shares)
+        };
+
+        Ok(())
+    }
+
     pub fn cancel_withdraw_request(
         &mut self,
         vault_equity: u64,
@@ -454,6 +816,44 @@ impl VaultDepositor {
     ) -> Result<()> {
         self.apply_rebase(vault, vault_protocol, vault_equity)?;
 
+        if vault.withdraw_queue {
+            self.dequeue_withdraw_request(vault)?;
+        }
+
+        let mut request = self.last_withdraw_request;
+        self.cancel_request(&mut request, vault_equity, vault, vault_protocol, now)?;
+        self.last_withdraw_request = request;
+
+        Ok(())
+    }
+
+    /// Takes the pending withdraw request out of the vault's withdraw queue. A request partially filled in a
+    /// settled epoch must be claimed first.
+    fn dequeue_withdraw_request(&self, vault: &mut Vault) -> Result<()> {
+        validate!(
+            self.withdraw_request_epoch == vault.withdraw_epoch,
+            ErrorCode::InvalidVaultWithdraw,
+            "withdraw request was partially filled in epoch {}, must claim it first",
+            self.withdraw_request_epoch
+        )?;
+        vault.withdraw_queue_shares = vault
+            .withdraw_queue_shares
+            .safe_sub(self.last_withdraw_request.shares)?;
+        if vault.withdraw_queue_shares == 0 {
+            vault.withdraw_queue_start_ts = 0;
+        }
+
+        Ok(())
+    }
+
+    fn cancel_request(
+        &mut self,
+        request: &mut WithdrawRequest,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<()> {
         let vd_vault_shares_before: u128 = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
         let user_vault_shares_before = vault.user_shares;
@@ -466,9 +866,7 @@ impl VaultDepositor {
             protocol_fee_shares,
         } = vault.apply_fee(vault_protocol, vault_equity, now)?;
 
-        let vault_shares_lost = self
-            .last_withdraw_request
-            .calculate_shares_lost(vault, vault_equity)?;
+        let vault_shares_lost = request.calculate_shares_lost(vault, vault_equity)?;
 
         // only deduct lost shares if user doesn't own 100% of the vault
         let user_owns_entire_vault = total_vault_shares_before == vd_vault_shares_before;
@@ -502,6 +900,7 @@ impl VaultDepositor {
                     profit_share: 0,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(_) => {
@@ -527,15 +926,15 @@ impl VaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         }
 
-        vault.total_withdraw_requested = vault
-            .total_withdraw_requested
-            .safe_sub(self.last_withdraw_request.value)?;
+        vault.total_withdraw_requested = vault.total_withdraw_requested.safe_sub(request.value)?;
 
-        self.last_withdraw_request.reset(now)?;
+        request.reset(now)?;
 
         Ok(())
     }
@@ -550,74 +949,447 @@ impl VaultDepositor {
         self.last_withdraw_request
             .check_redeem_period_finished(vault, now)?;
 
-        self.apply_rebase(vault, vault_protocol, vault_equity)?;
-
-        let vault_shares_before: u128 = self.checked_vault_shares(vault)?;
-        let total_vault_shares_before = vault.total_shares;
-        let user_vault_shares_before = vault.user_shares;
-        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+        self.withdraw_with_penalty(vault_equity, vault, vault_protocol, 0, None, now)
+    }
 
-        let n_shares = self.last_withdraw_request.shares;
+    /// Withdraws the pending withdraw request for the manager. A frozen depositor can't request a withdraw,
+    /// and a winding down vault is exiting everyone, so they are exited in full without waiting for the
+    /// redeem period. A winding down vault with a withdraw queue takes the request out of the queue and
+    /// pays it out directly.
+    pub fn force_withdraw(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        if !self.frozen && !vault.wind_down {
+            return self.withdraw(vault_equity, vault, vault_protocol, now);
+        }
 
         validate!(
-            n_shares > 0,
+            !vault.withdraw_queue || vault.wind_down,
             ErrorCode::InvalidVaultWithdraw,
-            "No last_withdraw_request.shares found, must call request_withdraw first",
+            "vault has a withdraw queue, withdraws are claimed from settled epochs"
         )?;
 
-        validate!(
-            vault_shares_before >= n_shares,
-            ErrorCode::InsufficientVaultShares
-        )?;
+        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
 
-        let VaultFee {
-            management_fee_payment,
-            management_fee_shares,
-            protocol_fee_payment,
-            protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
-        msg!("after management_fee vault_shares={}", self.vault_shares);
+        if !self.last_withdraw_request.pending() {
+            let mut request = self.last_withdraw_request;
+            self.request_withdraw_from_available_shares(
+                &mut request,
+                self.withdraw_request_slots_shares,
+                rebase_divisor,
+                PERCENTAGE_PRECISION_U64,
+                WithdrawUnit::SharesPercent,
+                vault_equity,
+                vault,
+                vault_protocol,
+                now,
+            )?;
+            self.last_withdraw_request = request;
+        }
 
-        let amount: u64 =
-            depositor_shares_to_vault_amount(n_shares, vault.total_shares, vault_equity)?;
+        if vault.withdraw_queue {
+            self.dequeue_withdraw_request(vault)?;
+        }
 
-        let withdraw_amount = amount.min(self.last_withdraw_request.value);
-        msg!(
-            "amount={}, last_withdraw_request_value={}",
-            amount,
-            self.last_withdraw_request.value
-        );
-        msg!(
-            "vault_shares={}, last_withdraw_request_shares={}",
-            self.get_vault_shares(),
-            self.last_withdraw_request.shares
-        );
+        self.withdraw_pending_request(vault_equity, vault, vault_protocol, 0, None, now)
+    }
 
-        self.decrease_vault_shares(n_shares, vault)?;
+    /// Withdraws up to `max_withdraw_amount` of the pending withdraw request after the redeem period.
+    /// The remainder stays pending with the original request ts.
+    pub fn partial_withdraw(
+        &mut self,
+        max_withdraw_amount: u64,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        self.last_withdraw_request
+            .check_redeem_period_finished(vault, now)?;
 
-        self.total_withdraws = self.total_withdraws.saturating_add(withdraw_amount);
-        self.net_deposits = self.net_deposits.safe_sub(withdraw_amount.cast()?)?;
+        validate!(
+            max_withdraw_amount > 0,
+            ErrorCode::InvalidVaultWithdrawSize,
+            "no free collateral to withdraw"
+        )?;
 
-        vault.total_withdraws = vault.total_withdraws.saturating_add(withdraw_amount);
-        vault.net_deposits = vault.net_deposits.safe_sub(withdraw_amount.cast()?)?;
-        vault.total_shares = vault.total_shares.safe_sub(n_shares)?;
-        vault.user_shares = vault.user_shares.safe_sub(n_shares)?;
-        vault.total_withdraw_requested = vault
-            .total_withdraw_requested
-            .safe_sub(self.last_withdraw_request.value)?;
+        self.withdraw_with_penalty(
+            vault_equity,
+            vault,
+            vault_protocol,
+            0,
+            Some(max_withdraw_amount),
+            now,
+        )
+    }
 
-        self.last_withdraw_request.reset(now)?;
+    /// Withdraws the pending withdraw request before the redeem period ends by paying the vault's
+    /// early withdraw penalty, which decays linearly to zero over the redeem period.
+    pub fn instant_withdraw(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        validate!(
+            vault.early_withdraw_penalty > 0,
+            ErrorCode::InvalidVaultWithdraw,
+            "instant withdraw is not enabled for vault"
+        )?;
 
-        let vault_shares_after = self.checked_vault_shares(vault)?;
-        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+        let early_withdraw_penalty = self
+            .last_withdraw_request
+            .calculate_early_withdraw_penalty(vault, now)?;
 
-        match vault_protocol {
+        self.withdraw_with_penalty(
+            vault_equity,
+            vault,
+            vault_protocol,
+            early_withdraw_penalty,
+            None,
+            now,
+        )
+    }
+
+    /// Claims the depositor's pro-rata fill of the settled withdraw epoch its request is queued in.
+    /// The fill is capped at the request's value and charged the exit fee. The unfilled part of the
+    /// request rolls over into the next epoch.
+    /// Returns the payout and the amount to deposit back into the vault.
+    pub fn claim_withdraw_epoch(
+        &mut self,
+        withdraw_epoch: &mut WithdrawEpoch,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+    ) -> Result<(u64, u64)> {
+        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+
+        validate!(
+            vault.withdraw_queue,
+            ErrorCode::InvalidVaultWithdraw,
+            "withdraw queue is not enabled for vault"
+        )?;
+        validate!(
+            self.last_withdraw_request.pending(),
+            ErrorCode::InvalidVaultWithdraw,
+            "No last_withdraw_request found, must call request_withdraw first",
+        )?;
+        validate!(
+            withdraw_epoch.vault == vault.pubkey
+                && withdraw_epoch.epoch == self.withdraw_request_epoch
+                && withdraw_epoch.epoch < vault.withdraw_epoch,
+            ErrorCode::InvalidVaultWithdraw,
+            "withdraw request is queued in epoch {}, not settled epoch {}",
+            self.withdraw_request_epoch,
+            withdraw_epoch.epoch
+        )?;
+
+        let vault_shares_before: u128 = self.checked_vault_shares(vault)?;
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+
+        let request_shares = self.last_withdraw_request.shares;
+        let (filled_shares, amount) =
+            withdraw_epoch.calculate_fill(request_shares, vault.shares_base)?;
+        let filled_value = if filled_shares == request_shares {
+            self.last_withdraw_request.value
+        } else {
+            self.last_withdraw_request
+                .value
+                .cast::<u128>()?
+                .safe_mul(filled_shares)?
+                .safe_div(request_shares)?
+                .cast::<u64>()?
+        };
+        let withdraw_amount = amount.min(filled_value);
+        msg!(
+            "epoch={}, filled_shares={}, amount={}, filled_value={}",
+            withdraw_epoch.epoch,
+            filled_shares,
+            amount,
+            filled_value
+        );
+
+        let VaultEntryExitFee {
+            manager_fee: manager_exit_fee,
+            protocol_fee: protocol_exit_fee,
+            fee_shares: exit_fee_shares,
+        } = vault.apply_exit_fee(vault_protocol, withdraw_amount, vault_equity)?;
+        let payout_amount = withdraw_amount
+            .safe_sub(manager_exit_fee)?
+            .safe_sub(protocol_exit_fee)?;
+        // the exit fees and the fill above the request's value are deposited back into the vault
+        let deposit_amount = amount.safe_sub(payout_amount)?;
+
+        // the filled shares were already burned from the vault when the epoch was settled
+        self.decrease_vault_shares(filled_shares, vault)?;
+
+        self.total_withdraws = self.total_withdraws.saturating_add(withdraw_amount);
+        self.net_deposits = self.net_deposits.safe_sub(withdraw_amount.cast()?)?;
+
+        vault.total_withdraws = vault.total_withdraws.saturating_add(payout_amount);
+        vault.net_deposits = vault.net_deposits.safe_sub(payout_amount.cast()?)?;
+        // exit fee shares are kept by the manager and protocol
+        vault.total_shares = vault.total_shares.safe_add(exit_fee_shares)?;
+
+        vault.total_withdraw_requested = vault.total_withdraw_requested.safe_sub(filled_value)?;
+        withdraw_epoch.claimed_amount = withdraw_epoch.claimed_amount.safe_add(amount)?;
+
+        if filled_shares == request_shares {
+            self.last_withdraw_request.reset(now)?;
+        } else {
+            self.last_withdraw_request.shares = request_shares.safe_sub(filled_shares)?;
+            self.last_withdraw_request.value =
+                self.last_withdraw_request.value.safe_sub(filled_value)?;
+            self.withdraw_request_epoch = self.withdraw_request_epoch.safe_add(1)?;
+        }
+
+        let vault_shares_after = self.checked_vault_shares(vault)?;
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        match vault_protocol {
+            None => {
+                emit!(VaultDepositorRecord {
+                    ts: now,
+                    vault: vault.pubkey,
+                    depositor_authority: self.authority,
+                    action: VaultDepositorAction::EpochWithdraw,
+                    amount: withdraw_amount,
+                    spot_market_index: vault.spot_market_index,
+                    vault_equity_before: vault_equity,
+                    vault_shares_before,
+                    user_vault_shares_before,
+                    total_vault_shares_before,
+                    vault_shares_after,
+                    total_vault_shares_after: vault.total_shares,
+                    user_vault_shares_after: vault.user_shares,
+                    profit_share: 0,
+                    management_fee: 0,
+                    management_fee_shares: 0,
+                    entry_exit_fee: manager_exit_fee,
+                });
+            }
+            Some(_) => {
+                emit!(VaultDepositorV1Record {
+                    ts: now,
+                    vault: vault.pubkey,
+                    depositor_authority: self.authority,
+                    action: VaultDepositorAction::EpochWithdraw,
+                    amount: withdraw_amount,
+                    spot_market_index: vault.spot_market_index,
+                    vault_equity_before: vault_equity,
+                    vault_shares_before,
+                    user_vault_shares_before,
+                    total_vault_shares_before,
+                    vault_shares_after,
+                    total_vault_shares_after: vault.total_shares,
+                    user_vault_shares_after: vault.user_shares,
+                    protocol_profit_share: 0,
+                    protocol_fee: 0,
+                    protocol_fee_shares: 0,
+                    manager_profit_share: 0,
+                    management_fee: 0,
+                    management_fee_shares: 0,
+                    protocol_shares_before,
+                    protocol_shares_after,
+                    protocol_entry_exit_fee: protocol_exit_fee,
+                    manager_entry_exit_fee: manager_exit_fee,
+                });
+            }
+        }
+
+        Ok((payout_amount, deposit_amount))
+    }
+
+    fn withdraw_with_penalty(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        early_withdraw_penalty: u32,
+        max_withdraw_amount: Option<u64>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        validate!(
+            !vault.withdraw_queue,
+            ErrorCode::InvalidVaultWithdraw,
+            "vault has a withdraw queue, withdraws are claimed from settled epochs"
+        )?;
+
+        self.withdraw_pending_request(
+            vault_equity,
+            vault,
+            vault_protocol,
+            early_withdraw_penalty,
+            max_withdraw_amount,
+            now,
+        )
+    }
+
+    fn withdraw_pending_request(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        early_withdraw_penalty: u32,
+        max_withdraw_amount: Option<u64>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+
+        let mut request = self.last_withdraw_request;
+        let result = self.withdraw_request(
+            &mut request,
+            vault_equity,
+            vault,
+            vault_protocol,
+            early_withdraw_penalty,
+            max_withdraw_amount,
+            now,
+        )?;
+        self.last_withdraw_request = request;
+
+        Ok(result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn withdraw_request(
+        &mut self,
+        request: &mut WithdrawRequest,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        early_withdraw_penalty: u32,
+        max_withdraw_amount: Option<u64>,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        let vault_shares_before: u128 = self.checked_vault_shares(vault)?;
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+
+        let request_shares = request.shares;
+
+        validate!(
+            request_shares > 0,
+            ErrorCode::InvalidVaultWithdraw,
+            "No last_withdraw_request.shares found, must call request_withdraw first",
+        )?;
+
+        validate!(
+            vault_shares_before >= request_shares,
+            ErrorCode::InsufficientVaultShares
+        )?;
+
+        let VaultFee {
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        msg!("after management_fee vault_shares={}", self.vault_shares);
+
+        let amount: u64 =
+            depositor_shares_to_vault_amount(request_shares, vault.total_shares, vault_equity)?;
+
+        let request_amount = amount.min(request.value);
+        let withdraw_amount = request_amount.min(max_withdraw_amount.unwrap_or(u64::MAX));
+        // a partial withdraw burns the same fraction of the requested shares and value
+        let (n_shares, withdraw_request_value) = if withdraw_amount == request_amount {
+            (request_shares, request.value)
+        } else {
+            let n_shares = request_shares
+                .safe_mul(withdraw_amount.cast()?)?
+                .safe_add(request_amount.safe_sub(1)?.cast()?)?
+                .safe_div(request_amount.cast()?)?
+                .min(request_shares);
+            let withdraw_request_value = request
+                .value
+                .cast::<u128>()?
+                .safe_mul(n_shares)?
+                .safe_div(request_shares)?
+                .cast::<u64>()?;
+            (n_shares, withdraw_request_value)
+        };
+        let full_withdraw = n_shares == request_shares;
+        msg!(
+            "amount={}, last_withdraw_request_value={}",
+            amount,
+            request.value
+        );
+        msg!(
+            "vault_shares={}, last_withdraw_request_shares={}",
+            self.get_vault_shares(),
+            request.shares
+        );
+
+        let VaultEntryExitFee {
+            manager_fee: manager_exit_fee,
+            protocol_fee: protocol_exit_fee,
+            fee_shares: exit_fee_shares,
+        } = vault.apply_exit_fee(vault_protocol, withdraw_amount, vault_equity)?;
+        let (early_withdraw_penalty_amount, early_withdraw_penalty_shares) = vault
+            .apply_early_withdraw_penalty(withdraw_amount, vault_equity, early_withdraw_penalty)?;
+        msg!(
+            "early_withdraw_penalty={}, early_withdraw_penalty_shares={}",
+            early_withdraw_penalty_amount,
+            early_withdraw_penalty_shares
+        );
+        let exit_fee_shares = exit_fee_shares
+            .safe_add(early_withdraw_penalty_shares)?
+            .min(n_shares);
+        let payout_amount = withdraw_amount
+            .safe_sub(manager_exit_fee)?
+            .safe_sub(protocol_exit_fee)?
+            .safe_sub(early_withdraw_penalty_amount)?;
+
+        self.decrease_vault_shares(n_shares, vault)?;
+
+        self.total_withdraws = self.total_withdraws.saturating_add(withdraw_amount);
+        self.net_deposits = self.net_deposits.safe_sub(withdraw_amount.cast()?)?;
+
+        vault.total_withdraws = vault.total_withdraws.saturating_add(payout_amount);
+        vault.net_deposits = vault.net_deposits.safe_sub(payout_amount.cast()?)?;
+        // exit fee and manager penalty shares are not burned, they are kept by the manager and protocol
+        vault.total_shares = vault
+            .total_shares
+            .safe_sub(n_shares.safe_sub(exit_fee_shares)?)?;
+        vault.user_shares = vault.user_shares.safe_sub(n_shares)?;
+        vault.total_withdraw_requested = vault
+            .total_withdraw_requested
+            .safe_sub(withdraw_request_value)?;
+
+        if full_withdraw {
+            request.reset(now)?;
+        } else {
+            // the remainder stays pending with the original request ts
+            request.shares = request_shares.safe_sub(n_shares)?;
+            request.value = request.value.safe_sub(withdraw_request_value)?;
+        }
+
+        let vault_shares_after = self.checked_vault_shares(vault)?;
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        let action = if early_withdraw_penalty > 0 {
+            VaultDepositorAction::InstantWithdraw
+        } else {
+            VaultDepositorAction::Withdraw
+        };
+
+        match vault_protocol {
             None => {
                 emit!(VaultDepositorRecord {
                     ts: now,
                     vault: vault.pubkey,
                     depositor_authority: self.authority,
-                    action: VaultDepositorAction::Withdraw,
+                    action,
                     amount: withdraw_amount,
                     spot_market_index: vault.spot_market_index,
                     vault_equity_before: vault_equity,
@@ -630,6 +1402,7 @@ impl VaultDepositor {
                     profit_share: 0,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: manager_exit_fee,
                 });
             }
             Some(_) => {
@@ -637,7 +1410,7 @@ impl VaultDepositor {
                     ts: now,
                     vault: vault.pubkey,
                     depositor_authority: self.authority,
-                    action: VaultDepositorAction::Withdraw,
+                    action,
                     amount: withdraw_amount,
                     spot_market_index: vault.spot_market_index,
                     vault_equity_before: vault_equity,
@@ -655,13 +1428,15 @@ impl VaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
+                    protocol_entry_exit_fee: protocol_exit_fee,
+                    manager_entry_exit_fee: manager_exit_fee,
                 });
             }
         }
 
-        let finishing_liquidation = vault.liquidation_delegate == self.authority;
+        let finishing_liquidation = full_withdraw && vault.liquidation_delegate == self.authority;
 
-        Ok((withdraw_amount, finishing_liquidation))
+        Ok((payout_amount, finishing_liquidation))
     }
 
     pub fn apply_profit_share(
@@ -669,13 +1444,42 @@ impl VaultDepositor {
         vault_equity: u64,
         vault: &mut Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
     ) -> Result<(u64, u64)> {
         validate!(
             !self.last_withdraw_request.pending(),
             ErrorCode::InvalidVaultDeposit,
             "Cannot apply profit share to depositor with pending withdraw request"
         )?;
-        VaultDepositorBase::apply_profit_share(self, vault_equity, vault, vault_protocol)
+
+        if self.withdraw_request_slot_count == 0 {
+            return VaultDepositorBase::apply_profit_share(
+                self,
+                vault_equity,
+                vault,
+                vault_protocol,
+                now,
+            );
+        }
+
+        // shares in withdraw request slots were charged when requested, the rest is crystallized on schedule
+        if !vault.is_profit_share_crystallization_due(self.last_crystallization_ts, now)? {
+            return Ok((0, 0));
+        }
+
+        let unreserved_shares = self
+            .get_vault_shares()
+            .saturating_sub(self.withdraw_request_slots_shares);
+        let profit_share = self.crystallize_partial_profit_share(
+            unreserved_shares,
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+        )?;
+        self.last_crystallization_ts = now;
+
+        Ok(profit_share)
     }
 
     pub fn realize_profits(
@@ -698,7 +1502,7 @@ impl VaultDepositor {
         let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
 
         let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_protocol, now)?;
         let profit_share = manager_profit_share.saturating_add(protocol_profit_share);
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
 
@@ -721,6 +1525,7 @@ impl VaultDepositor {
                     profit_share: manager_profit_share,
                     management_fee: management_fee_payment,
                     management_fee_shares,
+                    entry_exit_fee: 0,
                 });
             }
             Some(_) => {
@@ -746,6 +1551,8 @@ impl VaultDepositor {
                     management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
+                    protocol_entry_exit_fee: 0,
+                    manager_entry_exit_fee: 0,
                 });
             }
         }
@@ -753,22 +1560,27 @@ impl VaultDepositor {
         Ok(profit_share)
     }
 
-    pub fn check_cant_withdraw(
-        &self,
-        vault: &Vault,
-        vault_equity: u64,
-        drift_user: &mut User,
-        perp_market_map: &PerpMarketMap,
-        spot_market_map: &SpotMarketMap,
-        oracle_map: &mut OracleMap,
-    ) -> Result<()> {
+    /// The token amount the pending withdraw request is for: its requested value, capped at the current
+    /// value of its shares.
+    fn calculate_withdraw_request_amount(&self, vault: &Vault, vault_equity: u64) -> Result<u64> {
         let shares_value = depositor_shares_to_vault_amount(
             self.last_withdraw_request.shares,
             vault.total_shares,
             vault_equity,
         )?;
-        let withdraw_amount = self.last_withdraw_request.value.min(shares_value);
+        Ok(self.last_withdraw_request.value.min(shares_value))
+    }
 
+    /// Simulates withdrawing `amount` from the drift user and checks it still meets initial margin and
+    /// the spot margin trading rules. The drift accounts are restored afterward.
+    fn can_withdraw_amount(
+        vault: &Vault,
+        amount: u64,
+        drift_user: &mut User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<(bool, bool)> {
         let mut spot_market = spot_market_map.get_ref_mut(&vault.spot_market_index)?;
 
         // Save relevant data before updating balances
@@ -777,7 +1589,7 @@ impl VaultDepositor {
         let user_spot_position_before = drift_user.spot_positions;
 
         update_spot_balances(
-            withdraw_amount.cast()?,
+            amount.cast()?,
             &SpotBalanceType::Borrow,
             &mut spot_market,
             drift_user.force_get_spot_position_mut(vault.spot_market_index)?,
@@ -807,6 +1619,36 @@ impl VaultDepositor {
             }
         };
 
+        // Must reset drift accounts afterward else ix will fail
+        let mut spot_market = spot_market_map.get_ref_mut(&vault.spot_market_index)?;
+        spot_market.deposit_balance = spot_market_deposit_balance_before;
+        spot_market.borrow_balance = spot_market_borrow_balance_before;
+
+        drift_user.spot_positions = user_spot_position_before;
+
+        Ok((sufficient_collateral, margin_trading_ok))
+    }
+
+    pub fn check_cant_withdraw(
+        &self,
+        vault: &Vault,
+        vault_equity: u64,
+        drift_user: &mut User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<()> {
+        let withdraw_amount = self.calculate_withdraw_request_amount(vault, vault_equity)?;
+
+        let (sufficient_collateral, margin_trading_ok) = Self::can_withdraw_amount(
+            vault,
+            withdraw_amount,
+            drift_user,
+            perp_market_map,
+            spot_market_map,
+            oracle_map,
+        )?;
+
         if sufficient_collateral && margin_trading_ok {
             msg!(
                 "depositor is able to withdraw. sufficient collateral = {} margin trading ok = {}",
@@ -816,27 +1658,85 @@ impl VaultDepositor {
             return Err(ErrorCode::DriftError.into());
         }
 
-        // Must reset drift accounts afterward else ix will fail
-        let mut spot_market = spot_market_map.get_ref_mut(&vault.spot_market_index)?;
-        spot_market.deposit_balance = spot_market_deposit_balance_before;
-        spot_market.borrow_balance = spot_market_borrow_balance_before;
-
-        drift_user.spot_positions = user_spot_position_before;
-
         Ok(())
     }
-}
-
-#[cfg(test)]
-mod vault_v1_tests {
-    use std::cell::RefCell;
 
-    use anchor_lang::prelude::Pubkey;
+    /// The largest part of the pending withdraw request that can be withdrawn from the drift user while it
+    /// still meets initial margin, found by a bounded binary search over the withdraw simulation.
+    pub fn calculate_max_withdraw_amount(
+        &self,
+        vault: &Vault,
+        vault_equity: u64,
+        drift_user: &mut User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<u64> {
+        let withdraw_amount = self.calculate_withdraw_request_amount(vault, vault_equity)?;
+
+        Self::search_max_withdraw_amount(withdraw_amount, |amount| {
+            let (sufficient_collateral, margin_trading_ok) = Self::can_withdraw_amount(
+                vault,
+                amount,
+                drift_user,
+                perp_market_map,
+                spot_market_map,
+                oracle_map,
+            )?;
+            Ok(sufficient_collateral && margin_trading_ok)
+        })
+    }
+
+    /// Binary searches for the largest amount up to `withdraw_amount` that `can_withdraw` allows, running at
+    /// most [`MAX_WITHDRAW_SEARCH_ITERATIONS`] simulations after the one for the full amount. The search may
+    /// stop short of the exact max, always on an amount that can be withdrawn.
+    pub fn search_max_withdraw_amount(
+        withdraw_amount: u64,
+        mut can_withdraw: impl FnMut(u64) -> Result<bool>,
+    ) -> Result<u64> {
+        if withdraw_amount == 0 || can_withdraw(withdraw_amount)? {
+            return Ok(withdraw_amount);
+        }
+
+        let mut min_amount = 0_u64;
+        let mut max_amount = withdraw_amount.safe_sub(1)?;
+        for _ in 0..MAX_WITHDRAW_SEARCH_ITERATIONS {
+            if min_amount >= max_amount {
+                break;
+            }
+
+            let amount =
+                min_amount.safe_add(max_amount.safe_sub(min_amount)?.safe_add(1)?.safe_div(2)?)?;
+            if can_withdraw(amount)? {
+                min_amount = amount;
+            } else {
+                max_amount = amount.safe_sub(1)?;
+            }
+        }
+
+        Ok(min_amount)
+    }
+}
+
+#[cfg(test)]
+mod vault_v1_tests {
+    use std::cell::RefCell;
+
+    use anchor_lang::prelude::Pubkey;
     use drift::math::casting::Cast;
-    use drift::math::constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION_U64};
+    use drift::math::constants::{
+        ONE_YEAR, PERCENTAGE_PRECISION_U64, QUOTE_PRECISION_I64, QUOTE_PRECISION_U64,
+    };
     use drift::math::insurance::if_shares_to_vault_amount;
 
-    use crate::{Vault, VaultDepositor, VaultProtocol, WithdrawUnit};
+    use crate::constants::{
+        DRAWDOWN_COOLDOWN, MAX_PAUSE_DURATION, MAX_WITHDRAW_SEARCH_ITERATIONS, MIN_PARAMS_TIMELOCK,
+        ONE_DAY, PAUSE_COOLDOWN, WITHDRAW_DESTINATION_TIMELOCK,
+    };
+    use crate::{
+        TokenizedVaultDepositor, Vault, VaultDepositor, VaultDepositorBase, VaultProtocol,
+        WithdrawEpoch, WithdrawRequestSlots, WithdrawUnit,
+    };
 
     #[test]
     fn base_init() {
@@ -1563,4 +2463,1908 @@ mod vault_v1_tests {
             vd.cumulative_profit_share_amount
         );
     }
+
+    #[test]
+    fn test_profit_share_hurdle_rate() {
+        let now = 1000;
+        let one_year: i64 = ONE_YEAR.cast().unwrap();
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.hurdle_rate = 100_000; // 10% annual hurdle
+        vault.profit_share = 200_000; // 20% profit share
+        vp.borrow_mut().protocol_profit_share = 50_000; // 5% profit share
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 100_000_000);
+        assert_eq!(vault.total_shares, 100_000_000);
+        assert_eq!(vd.last_profit_share_ts, now);
+
+        // up 3% after half a year, below the $5 hurdle accrued so far
+        vault_equity = 103 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + one_year / 2,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 0);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 100_000_000);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+        assert_eq!(vd.last_profit_share_ts, now);
+
+        // up 20% after a year, $10 above the $10 hurdle
+        vault_equity = 120 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + one_year,
+            )
+            .unwrap();
+        // 20% manager + 5% protocol profit share on $10
+        assert_eq!(profit_share, 2_500_000);
+        assert_eq!(vault.manager_total_profit_share, 2_000_000);
+        assert_eq!(vp.borrow().protocol_total_profit_share, 500_000);
+        assert_eq!(vd.profit_share_fee_paid, 2_500_000);
+        // high-water mark moves to the full profit, not just the amount above the hurdle
+        assert_eq!(vd.cumulative_profit_share_amount, 20_000_000);
+        assert_eq!(vd.last_profit_share_ts, now + one_year);
+
+        let user_owned_amount =
+            if_shares_to_vault_amount(vault.user_shares, vault.total_shares, vault_equity).unwrap();
+        assert_eq!(user_owned_amount, 117_500_000);
+    }
+
+    #[test]
+    fn test_profit_share_hurdle_rate_time_weighted_deposit() {
+        let now = 1000;
+        let one_year: i64 = ONE_YEAR.cast().unwrap();
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.hurdle_rate = 100_000; // 10% annual hurdle
+        vault.profit_share = 200_000; // 20% profit share
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // flat after half a year, deposit the same amount again
+        vault_equity = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + one_year / 2,
+        )
+        .unwrap();
+        // half the capital has accrued hurdle for half a year, same as all of it for a quarter year
+        assert_eq!(vd.last_profit_share_ts, now + one_year / 4);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+
+        // up $20 at the end of the year, hurdle is 10% on $200 for three quarters of a year = $15
+        // ($10 on the first deposit for a year, $5 on the second for half a year)
+        vault_equity = 220 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + one_year,
+            )
+            .unwrap();
+        // 20% profit share on $20 - $15 hurdle
+        assert_eq!(profit_share, 1_000_000);
+        assert_eq!(vd.cumulative_profit_share_amount, 20_000_000);
+        assert_eq!(vd.last_profit_share_ts, now + one_year);
+    }
+
+    #[test]
+    fn test_profit_share_crystallization_period() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.init_ts = now;
+        vault.profit_share = 100_000; // 10% profit share
+        vault.profit_share_crystallization_period = 30 * ONE_DAY;
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // mid period, nothing is crystallized
+        vault_equity = 110 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 10 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 0);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+        assert_eq!(vd.last_profit_share_ts, now);
+        assert_eq!(vd.last_crystallization_ts, now);
+
+        // first period boundary passed
+        vault_equity = 120 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 31 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 2_000_000);
+        assert_eq!(vd.cumulative_profit_share_amount, 20_000_000);
+        assert_eq!(vd.last_profit_share_ts, now + 31 * ONE_DAY);
+        assert_eq!(vd.last_crystallization_ts, now + 31 * ONE_DAY);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 98_333_334);
+
+        // mid second period, nothing is crystallized
+        vault_equity = 130 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 40 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 0);
+        assert_eq!(vd.cumulative_profit_share_amount, 20_000_000);
+
+        // full exit mid period crystallizes on the $7.83 above the high-water mark
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + 45 * ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vault.manager_total_profit_share, 2_783_333);
+        assert_eq!(vd.cumulative_profit_share_amount, 27_833_334);
+        assert_eq!(
+            vd.last_withdraw_request.shares,
+            vd.checked_vault_shares(&vault).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_profit_share_crystallization_period_hurdle() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.init_ts = now;
+        vault.hurdle_rate = 100_000; // 10% annual hurdle
+        vault.profit_share = 200_000; // 20% profit share
+        vault.profit_share_crystallization_period = 30 * ONE_DAY;
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // first period boundary passed, the $0.50 gain is below the $0.85 hurdle
+        vault_equity = 100_500_000;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 31 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 0);
+        assert_eq!(vd.last_crystallization_ts, now + 31 * ONE_DAY);
+        // nothing was charged, the hurdle keeps accruing from the deposit
+        assert_eq!(vd.last_profit_share_ts, now);
+
+        // second period boundary passed, the $1.50 gain is below the $1.67 hurdle accrued since the deposit
+        vault_equity = 101_500_000;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 61 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 0);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+        assert_eq!(vd.last_crystallization_ts, now + 61 * ONE_DAY);
+        assert_eq!(vd.last_profit_share_ts, now);
+
+        // a deposit time-weights the hurdle accrual, the crystallization schedule is left alone
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + 70 * ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vd.last_profit_share_ts, now + 35 * ONE_DAY);
+        assert_eq!(vd.last_crystallization_ts, now + 61 * ONE_DAY);
+
+        // third period boundary passed
+        vault_equity = 300 * QUOTE_PRECISION_U64;
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 91 * ONE_DAY,
+            )
+            .unwrap();
+        assert!(profit_share > 0);
+        assert_eq!(vd.last_profit_share_ts, now + 91 * ONE_DAY);
+        assert_eq!(vd.last_crystallization_ts, now + 91 * ONE_DAY);
+    }
+
+    #[test]
+    fn test_partial_withdraw_crystallizes_profit_share() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.init_ts = now;
+        vault.profit_share = 200_000; // 20% profit share
+        vault.profit_share_crystallization_period = 30 * ONE_DAY;
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // withdrawing half mid period crystallizes profit share on half of the $100 gain
+        vault_equity = 200 * QUOTE_PRECISION_U64;
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64 / 2,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + 10 * ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vault.manager_total_profit_share, 10 * QUOTE_PRECISION_U64);
+        assert_eq!(vd.cumulative_profit_share_amount, 40 * QUOTE_PRECISION_I64);
+        assert_eq!(vd.last_profit_share_ts, now);
+        assert_eq!(vd.last_withdraw_request.shares, 47_500_000);
+
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 10 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 95 * QUOTE_PRECISION_U64);
+        vault_equity -= withdraw_amount;
+
+        // the remaining half of the gain is charged at the period boundary
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 31 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 10 * QUOTE_PRECISION_U64);
+        assert_eq!(vault.manager_total_profit_share, 20 * QUOTE_PRECISION_U64);
+    }
+
+    #[test]
+    fn test_transfer_shares_crystallizes_profit_share() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vp.borrow_mut().protocol_profit_share = 50_000; // 5% profit share
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let tvd = &mut TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+
+        vault.init_ts = now;
+        vault.profit_share = 100_000; // 10% profit share
+        vault.profit_share_crystallization_period = 30 * ONE_DAY;
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        assert_eq!(
+            vault
+                .get_manager_shares(&mut Some(vp.borrow_mut()))
+                .unwrap(),
+            0
+        );
+        assert_eq!(vault.get_protocol_shares(&mut Some(vp.borrow_mut())), 0);
+
+        // transferring half mid period crystallizes profit share on half of the $100 gain
+        let vault_equity = 200 * QUOTE_PRECISION_U64;
+        let (n_shares, _) = vd
+            .transfer_shares(
+                tvd,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                PERCENTAGE_PRECISION_U64 / 2,
+                WithdrawUnit::SharesPercent,
+                vault_equity,
+                now + 10 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(vault.manager_total_profit_share, 5 * QUOTE_PRECISION_U64);
+        assert_eq!(vp.borrow().protocol_total_profit_share, 2_500_000);
+        assert_eq!(
+            vault
+                .get_manager_shares(&mut Some(vp.borrow_mut()))
+                .unwrap(),
+            2_500_000
+        );
+        assert_eq!(
+            vault.get_protocol_shares(&mut Some(vp.borrow_mut())),
+            1_250_000
+        );
+        assert_eq!(n_shares, 48_125_000);
+        assert_eq!(tvd.checked_vault_shares(&vault).unwrap(), n_shares);
+        assert_eq!(vd.last_profit_share_ts, now);
+
+        // the transferred shares are not charged again at the period boundary
+        let (manager_profit_share, protocol_profit_share) = tvd
+            .apply_profit_share(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 31 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(manager_profit_share + protocol_profit_share, 0);
+    }
+
+    #[test]
+    fn test_profit_share_capped_at_depositor_value() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.init_ts = now;
+        vault.profit_share = 600_000; // 60% profit share
+        vault.profit_share_crystallization_period = 30 * ONE_DAY;
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // withdrawing $150 of $200 pays the $45 profit share on that fraction out of the rest
+        vault_equity = 200 * QUOTE_PRECISION_U64;
+        vd.request_withdraw(
+            150 * QUOTE_PRECISION_U64,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + 10 * ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vault.manager_total_profit_share, 45 * QUOTE_PRECISION_U64);
+
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 10 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 150 * QUOTE_PRECISION_U64);
+        vault_equity -= withdraw_amount;
+
+        // the $15 still owed is capped at the depositor's remaining $5
+        let profit_share = vd
+            .realize_profits(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 31 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(profit_share, 5 * QUOTE_PRECISION_U64);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_entry_exit_fee() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.entry_fee = 10_000; // 1% entry fee
+        vault.exit_fee = 20_000; // 2% exit fee
+        vp.borrow_mut().protocol_entry_fee = 5_000; // 0.5% entry fee
+        vp.borrow_mut().protocol_exit_fee = 5_000; // 0.5% exit fee
+
+        let vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        let shares_out = vd
+            .deposit(
+                amount,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(shares_out, 98_500_000);
+        // $1.50 of the $100 deposit is kept by the manager and protocol
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 98_500_000);
+        assert_eq!(vd.net_deposits, 98_500_000);
+        assert_eq!(vd.total_deposits, 100_000_000);
+        assert_eq!(vault.user_shares, 98_500_000);
+        assert_eq!(vault.total_shares, 100_000_000);
+        assert_eq!(
+            vault
+                .get_manager_shares(&mut Some(vp.borrow_mut()))
+                .unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            vault.get_protocol_shares(&mut Some(vp.borrow_mut())),
+            500_000
+        );
+        assert_eq!(vault.manager_total_fee, 1_000_000);
+        assert_eq!(vp.borrow().protocol_total_fee, 500_000);
+
+        let vault_equity: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vd.last_withdraw_request.value, 98_500_000);
+
+        let (withdraw_amount, _) = vd
+            .withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        // 2.5% exit fee on $98.50
+        assert_eq!(withdraw_amount, 96_037_500);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vd.net_deposits, 0);
+        assert_eq!(vault.user_shares, 0);
+        assert_eq!(vault.total_shares, 3_962_500);
+        assert_eq!(
+            vault
+                .get_manager_shares(&mut Some(vp.borrow_mut()))
+                .unwrap(),
+            2_970_000
+        );
+        assert_eq!(
+            vault.get_protocol_shares(&mut Some(vp.borrow_mut())),
+            992_500
+        );
+    }
+
+    #[test]
+    fn test_instant_withdraw() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        vault.redeem_period = 10 * ONE_DAY;
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity = 100 * QUOTE_PRECISION_U64;
+
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64 / 2,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // instant withdraw disabled
+        assert!(vd
+            .instant_withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 5 * ONE_DAY,
+            )
+            .is_err());
+
+        vault.early_withdraw_penalty = 100_000; // 10% penalty
+
+        // halfway through the redeem period the penalty is 5%, left in the vault
+        let (withdraw_amount, _) = vd
+            .instant_withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 5 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 47_500_000);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 50_000_000);
+        assert_eq!(vault.user_shares, 50_000_000);
+        assert_eq!(vault.total_shares, 50_000_000);
+        vault_equity -= withdraw_amount;
+        assert_eq!(vault_equity, 52_500_000);
+
+        // full penalty right after requesting, paid to the manager
+        vault.early_withdraw_penalty_to_manager = true;
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + 5 * ONE_DAY,
+        )
+        .unwrap();
+        let (withdraw_amount, _) = vd
+            .instant_withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 5 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 47_250_000);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vault.user_shares, 0);
+        assert_eq!(vault.total_shares, 5_000_000);
+        assert_eq!(
+            vault
+                .get_manager_shares(&mut Some(vp.borrow_mut()))
+                .unwrap(),
+            5_000_000
+        );
+        assert_eq!(vault.manager_total_fee, 5_250_000);
+    }
+
+    #[test]
+    fn test_partial_withdraw() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // still bound by the redeem period
+        assert!(vd
+            .partial_withdraw(
+                40 * QUOTE_PRECISION_U64,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        let (withdraw_amount, _) = vd
+            .partial_withdraw(
+                40 * QUOTE_PRECISION_U64,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 40_000_000);
+        vault_equity -= withdraw_amount;
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 60_000_000);
+        assert_eq!(vd.last_withdraw_request.shares, 60_000_000);
+        assert_eq!(vd.last_withdraw_request.value, 60_000_000);
+        assert_eq!(vd.last_withdraw_request.ts, now);
+        assert_eq!(vault.total_withdraw_requested, 60_000_000);
+
+        let (withdraw_amount, _) = vd
+            .partial_withdraw(
+                1_000 * QUOTE_PRECISION_U64,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 60_000_000);
+        assert!(!vd.last_withdraw_request.pending());
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vault.total_shares, 0);
+        assert_eq!(vault.total_withdraw_requested, 0);
+    }
+
+    #[test]
+    fn test_withdraw_request_slot_profit_share() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        vault.init_ts = now;
+        vault.profit_share = 200_000; // 20% profit share
+        vault.profit_share_crystallization_period = 30 * ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let slots = &mut WithdrawRequestSlots::default();
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+
+        // a slot request is charged profit share on the $0.50 gain of the $1 it withdraws
+        vault_equity = 200 * QUOTE_PRECISION_U64;
+        vd.request_withdraw_slot(
+            slots,
+            QUOTE_PRECISION_U64,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vault.manager_total_profit_share, 100_000);
+        assert_eq!(vd.withdraw_request_slots_shares, 500_000);
+
+        // the open slot doesn't hold off the rest of the position's profit share
+        let (manager_profit_share, _) = vd
+            .apply_profit_share(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + 31 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(manager_profit_share, 19_800_450);
+        assert_eq!(vd.last_profit_share_ts, now + 31 * ONE_DAY);
+        assert!(vd.checked_vault_shares(&vault).unwrap() >= vd.withdraw_request_slots_shares);
+    }
+
+    #[test]
+    fn test_withdraw_request_slots() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let slots = &mut WithdrawRequestSlots::default();
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        let slot = vd
+            .request_withdraw_slot(
+                slots,
+                30 * QUOTE_PRECISION_U64,
+                WithdrawUnit::Token,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(slot, 0);
+
+        let slot = vd
+            .request_withdraw_slot(
+                slots,
+                20 * QUOTE_PRECISION_U64,
+                WithdrawUnit::Token,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY / 2,
+            )
+            .unwrap();
+        assert_eq!(slot, 1);
+        assert_eq!(vd.withdraw_request_slot_count, 2);
+        assert_eq!(vd.withdraw_request_slots_shares, 50_000_000);
+
+        // the main request can only take the shares not reserved by the slots
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vd.last_withdraw_request.shares, 50_000_000);
+        assert_eq!(vault.total_withdraw_requested, 100_000_000);
+
+        // every share is reserved
+        assert!(vd
+            .request_withdraw_slot(
+                slots,
+                10 * QUOTE_PRECISION_U64,
+                WithdrawUnit::Token,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        // each slot has its own redeem period
+        assert!(vd
+            .withdraw_slot(
+                slots,
+                1,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY,
+            )
+            .is_err());
+
+        let (withdraw_amount, _) = vd
+            .withdraw_slot(
+                slots,
+                0,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 30_000_000);
+        vault_equity -= withdraw_amount;
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 70_000_000);
+        assert_eq!(vd.withdraw_request_slot_count, 1);
+        assert_eq!(vd.withdraw_request_slots_shares, 20_000_000);
+        assert!(!slots.requests[0].pending());
+
+        vd.cancel_withdraw_request_slot(
+            slots,
+            1,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vd.withdraw_request_slot_count, 0);
+        assert_eq!(vd.withdraw_request_slots_shares, 0);
+        assert!(!slots.requests[1].pending());
+
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 50_000_000);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 20_000_000);
+        assert_eq!(vault.total_withdraw_requested, 0);
+    }
+
+    #[test]
+    fn test_withdraw_destination_timelock() {
+        let now = 1000;
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let destination = Pubkey::new_unique();
+        let other_destination = Pubkey::new_unique();
+
+        assert_eq!(vd.withdraw_destination.get(now), None);
+        assert!(!vd.withdraw_destination.is_registered(now));
+
+        let effective_ts = vd.withdraw_destination.propose(destination, now).unwrap();
+        assert_eq!(effective_ts, now + WITHDRAW_DESTINATION_TIMELOCK);
+        assert_eq!(vd.withdraw_destination.get(effective_ts - 1), None);
+        assert_eq!(vd.withdraw_destination.get(effective_ts), Some(destination));
+        // a pending destination already blocks tokenizing
+        assert!(vd.withdraw_destination.is_registered(now));
+        assert!(vd.withdraw_destination.is_registered(effective_ts));
+
+        // a change restarts the timelock, the matured destination stays in effect until then
+        let now = effective_ts + 1;
+        let effective_ts = vd
+            .withdraw_destination
+            .propose(other_destination, now)
+            .unwrap();
+        assert_eq!(vd.withdraw_destination.destination, destination);
+        assert_eq!(
+            vd.withdraw_destination.get(effective_ts - 1),
+            Some(destination)
+        );
+        assert_eq!(
+            vd.withdraw_destination.get(effective_ts),
+            Some(other_destination)
+        );
+
+        // proposing the destination in effect cancels the pending change
+        let effective_ts = vd.withdraw_destination.propose(destination, now).unwrap();
+        assert_eq!(effective_ts, now);
+        assert_eq!(
+            vd.withdraw_destination
+                .get(now + WITHDRAW_DESTINATION_TIMELOCK),
+            Some(destination)
+        );
+
+        // removing the destination is timelocked like any change
+        let effective_ts = vd
+            .withdraw_destination
+            .propose(Pubkey::default(), now)
+            .unwrap();
+        assert!(vd.withdraw_destination.is_registered(effective_ts - 1));
+        assert!(!vd.withdraw_destination.is_registered(effective_ts));
+    }
+
+    #[test]
+    fn test_depositor_limits() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.max_depositor_equity = 100 * QUOTE_PRECISION_U64;
+        vault.min_depositor_balance = 10 * QUOTE_PRECISION_U64;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        assert!(vd
+            .deposit(
+                101 * QUOTE_PRECISION_U64,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        // the manager lifts the cap for this depositor
+        vd.max_equity_override = 200 * QUOTE_PRECISION_U64;
+        let amount = 150 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        // leaving $5 is below the min balance
+        assert!(vd
+            .request_withdraw(
+                145 * QUOTE_PRECISION_U64,
+                WithdrawUnit::Token,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        vd.request_withdraw(
+            140 * QUOTE_PRECISION_U64,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vd.cancel_withdraw_request(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+
+        // a full exit is always allowed
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vd.last_withdraw_request.value, amount);
+    }
+
+    #[test]
+    fn test_frozen_vault_depositor() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vd.frozen = true;
+        assert!(vd
+            .deposit(
+                amount,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+        assert!(vd
+            .request_withdraw(
+                PERCENTAGE_PRECISION_U64,
+                WithdrawUnit::SharesPercent,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        // the manager forces the frozen depositor out without a request or redeem period
+        let (withdraw_amount, _) = vd
+            .force_withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        assert_eq!(withdraw_amount, amount);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+        assert!(!vd.last_withdraw_request.pending());
+        assert_eq!(vault.total_withdraw_requested, 0);
+    }
+
+    #[test]
+    fn test_validate_can_close() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd.validate_can_close(false).unwrap();
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+        assert!(vd.validate_can_close(false).is_err());
+
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert!(vd.validate_can_close(false).is_err());
+
+        vd.withdraw(
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + ONE_DAY,
+        )
+        .unwrap();
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+        vd.validate_can_close(false).unwrap();
+
+        // a frozen depositor stays open
+        vd.frozen = true;
+        assert!(vd.validate_can_close(false).is_err());
+        assert!(vd.validate_can_close(true).is_err());
+        vd.frozen = false;
+
+        // overrides can't be shed by the depositor closing and re-initializing
+        vd.max_equity_override = amount;
+        assert!(vd.validate_can_close(false).is_err());
+        vd.validate_can_close(true).unwrap();
+        vd.max_equity_override = 0;
+        vd.min_balance_override = amount;
+        assert!(vd.validate_can_close(false).is_err());
+        vd.validate_can_close(true).unwrap();
+        vd.min_balance_override = 0;
+
+        // the allowlist cap comes back from the leaf, and a manager override still takes precedence
+        vd.allowlist_max_equity = amount;
+        vd.validate_can_close(false).unwrap();
+        assert_eq!(vd.get_max_equity(&vault), amount);
+        vd.max_equity_override = 2 * amount;
+        assert_eq!(vd.get_max_equity(&vault), 2 * amount);
+    }
+
+    #[test]
+    fn test_wind_down() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+        vault.exit_fee = PERCENTAGE_PRECISION_U64 as u32 / 100; // 1%
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vault.management_fee = PERCENTAGE_PRECISION_U64 as i64 / 10; // 10%
+        vault
+            .start_wind_down(&mut Some(vp.borrow_mut()), vault_equity, now)
+            .unwrap();
+        assert!(vault.wind_down);
+        assert_eq!(vault.redeem_period, 0);
+        assert!(vault
+            .start_wind_down(&mut Some(vp.borrow_mut()), vault_equity, now)
+            .is_err());
+        assert!(vault.validate_can_close().is_err());
+
+        assert!(vd
+            .deposit(
+                amount,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        // no management fee accrues and no exit fee is charged while winding down
+        let total_shares_before = vault.total_shares;
+        let fee = vault
+            .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now + ONE_DAY * 30)
+            .unwrap();
+        assert_eq!(fee.management_fee_payment, 0);
+        assert_eq!(vault.total_shares, total_shares_before);
+
+        let (withdraw_amount, _) = vd
+            .force_withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now + ONE_DAY * 30,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, amount);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 0);
+
+        vault.validate_can_close().unwrap();
+    }
+
+    #[test]
+    fn test_wind_down_withdraw_queue() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+        vault.withdraw_queue = true;
+
+        let vd1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let vd2 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd1.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+        vd2.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vd1.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vault.withdraw_queue_shares, 100_000_000);
+
+        // a frozen depositor can't jump the queue of a vault that isn't winding down
+        vd2.frozen = true;
+        assert!(vd2
+            .force_withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .is_err());
+        vd2.frozen = false;
+
+        // half of vd1's request is filled before the vault winds down
+        let mut epoch_0 = WithdrawEpoch::default();
+        let settle_amount = vault
+            .settle_withdraw_epoch(
+                &mut epoch_0,
+                &mut Some(vp.borrow_mut()),
+                50 * QUOTE_PRECISION_U64,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        vault_equity -= settle_amount;
+        assert_eq!(vault.withdraw_queue_shares, 50_000_000);
+
+        vault
+            .start_wind_down(&mut Some(vp.borrow_mut()), vault_equity, now)
+            .unwrap();
+        assert!(vault.withdraw_queue);
+
+        // the fill in the settled epoch is claimed before the rest of the request is exited
+        assert!(vd1
+            .force_withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .is_err());
+        let (claim_amount, _) = vd1
+            .claim_withdraw_epoch(
+                &mut epoch_0,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(claim_amount, 50_000_000);
+
+        let (withdraw_amount, _) = vd1
+            .force_withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        assert_eq!(withdraw_amount, 50_000_000);
+        vault_equity -= withdraw_amount;
+        assert_eq!(vd1.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vault.withdraw_queue_shares, 0);
+        assert_eq!(vault.withdraw_queue_start_ts, 0);
+
+        // a depositor without a request is queued and paid out in the same call
+        let (withdraw_amount, _) = vd2
+            .force_withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        assert_eq!(withdraw_amount, amount);
+        assert_eq!(vd2.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vault.withdraw_queue_shares, 0);
+        assert_eq!(vault.total_withdraw_requested, 0);
+
+        vault.validate_can_close().unwrap();
+    }
+
+    #[test]
+    fn test_pause() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        let manager = Pubkey::new_unique();
+        let protocol = Pubkey::new_unique();
+        vault.manager = manager;
+        vault.delegate = Pubkey::new_unique();
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        // nothing to unpause
+        assert!(vault.unpause(manager, now).is_err());
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        assert!(vault.pause(manager, MAX_PAUSE_DURATION + 1, now).is_err());
+        let paused_until_ts = vault.pause(manager, ONE_DAY, now).unwrap();
+        assert_eq!(paused_until_ts, now + ONE_DAY);
+        assert_eq!(vault.get_drift_delegate(now), Pubkey::default());
+        // an active pause can't be extended
+        assert!(vault.pause(manager, ONE_DAY, now + 1).is_err());
+
+        assert!(vd
+            .deposit(
+                amount,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        // withdrawals still work while paused
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64 / 2,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        let (withdraw_amount, _) = vd
+            .withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        assert_eq!(withdraw_amount, amount / 2);
+        vault_equity -= withdraw_amount;
+
+        // only the pauser can lift the pause early, the manager can too once it expires
+        assert!(vault.unpause(protocol, now + 1).is_err());
+        assert_eq!(vault.get_drift_delegate(now + ONE_DAY), vault.delegate);
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now + ONE_DAY,
+        )
+        .unwrap();
+        assert!(vault.unpause(protocol, now + ONE_DAY).is_err());
+        vault.unpause(manager, now + ONE_DAY).unwrap();
+        assert_eq!(vault.paused_until_ts, 0);
+        assert_eq!(vault.pause_end_ts, now + ONE_DAY);
+        assert!(vault.unpause(manager, now + ONE_DAY).is_err());
+
+        // pauses can't be chained back to back
+        let now = now + ONE_DAY;
+        assert!(vault.pause(manager, ONE_DAY, now).is_err());
+        assert!(vault
+            .pause(manager, ONE_DAY, now + PAUSE_COOLDOWN - 1)
+            .is_err());
+        let now = now + PAUSE_COOLDOWN;
+        vault.pause(manager, ONE_DAY, now).unwrap();
+
+        // lifting a pause early starts the cooldown then
+        vault.unpause(manager, now + 1).unwrap();
+        assert_eq!(vault.pause_end_ts, now + 1);
+        assert!(vault
+            .pause(protocol, ONE_DAY, now + PAUSE_COOLDOWN)
+            .is_err());
+        vault
+            .pause(protocol, ONE_DAY, now + 1 + PAUSE_COOLDOWN)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_max_drawdown() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.max_drawdown = PERCENTAGE_PRECISION_U64 as u32 / 5; // 20%
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+
+        assert!(!vault.check_drawdown(amount, now).unwrap());
+        assert_eq!(vault.high_water_mark_share_price, 1_000_000);
+        assert!(!vault
+            .check_drawdown(110 * QUOTE_PRECISION_U64, now)
+            .unwrap());
+        assert_eq!(vault.high_water_mark_share_price, 1_100_000);
+
+        // 18% below the high water mark
+        assert!(!vault.check_drawdown(90 * QUOTE_PRECISION_U64, now).unwrap());
+        // 22.7% below the high water mark
+        assert!(vault.check_drawdown(85 * QUOTE_PRECISION_U64, now).unwrap());
+        assert!(vault.in_drawdown_breach());
+        assert!(!vault.check_drawdown(80 * QUOTE_PRECISION_U64, now).unwrap());
+        // the breach above didn't set the drift users reduce-only, the next instruction that can does so once
+        assert!(vault
+            .enforce_drawdown(80 * QUOTE_PRECISION_U64, now)
+            .unwrap());
+        assert!(!vault
+            .enforce_drawdown(80 * QUOTE_PRECISION_U64, now)
+            .unwrap());
+
+        assert!(vault
+            .reset_drawdown_breach(80 * QUOTE_PRECISION_U64, now + DRAWDOWN_COOLDOWN - 1)
+            .is_err());
+        vault
+            .reset_drawdown_breach(80 * QUOTE_PRECISION_U64, now + DRAWDOWN_COOLDOWN)
+            .unwrap();
+        assert!(!vault.in_drawdown_breach());
+        assert_eq!(vault.high_water_mark_share_price, 800_000);
+    }
+
+    #[test]
+    fn test_update_max_drawdown() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+
+        assert!(vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32, now)
+            .is_err());
+
+        // enabling applies immediately
+        vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32 / 5, now)
+            .unwrap();
+        assert_eq!(vault.max_drawdown, 200_000);
+        assert!(!vault
+            .check_drawdown(110 * QUOTE_PRECISION_U64, now)
+            .unwrap());
+        assert_eq!(vault.high_water_mark_share_price, 1_100_000);
+
+        // tightening or re-setting applies immediately and keeps the high water mark
+        vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32 / 10, now)
+            .unwrap();
+        vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32 / 10, now)
+            .unwrap();
+        assert_eq!(vault.max_drawdown, 100_000);
+        assert_eq!(vault.pending_max_drawdown_ts, 0);
+        assert_eq!(vault.high_water_mark_share_price, 1_100_000);
+
+        // disabling waits out the notice
+        vault.update_max_drawdown(0, now).unwrap();
+        assert_eq!(vault.max_drawdown, 100_000);
+        assert_eq!(vault.pending_max_drawdown_ts, now + MIN_PARAMS_TIMELOCK);
+        // 13.6% below the high water mark
+        let notice_ts = now + MIN_PARAMS_TIMELOCK;
+        assert!(vault
+            .check_drawdown(95 * QUOTE_PRECISION_U64, notice_ts - 1)
+            .unwrap());
+        vault
+            .reset_drawdown_breach(95 * QUOTE_PRECISION_U64, notice_ts - 1 + DRAWDOWN_COOLDOWN)
+            .unwrap();
+        assert_eq!(vault.high_water_mark_share_price, 950_000);
+
+        assert!(!vault
+            .check_drawdown(50 * QUOTE_PRECISION_U64, notice_ts + DRAWDOWN_COOLDOWN)
+            .unwrap());
+        assert_eq!(vault.max_drawdown, 0);
+        assert_eq!(vault.pending_max_drawdown_ts, 0);
+
+        // loosening waits out the notice too, and a tightening replaces it
+        vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32 / 10, now)
+            .unwrap();
+        vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32 / 2, now)
+            .unwrap();
+        assert_eq!(vault.max_drawdown, 100_000);
+        assert_eq!(vault.pending_max_drawdown, 500_000);
+        vault
+            .update_max_drawdown(PERCENTAGE_PRECISION_U64 as u32 / 20, now)
+            .unwrap();
+        assert_eq!(vault.max_drawdown, 50_000);
+        assert_eq!(vault.pending_max_drawdown_ts, 0);
+    }
+
+    #[test]
+    fn test_withdraw_queue_pro_rata() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.withdraw_queue = true;
+
+        let vd1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let vd2 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd1.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+        vd2.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vd1.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vd2.request_withdraw(
+            PERCENTAGE_PRECISION_U64 / 2,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vault.withdraw_queue_shares, 150_000_000);
+
+        // requests are not withdrawn directly from drift
+        assert!(vd1
+            .withdraw(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .is_err());
+
+        // the epoch can't be claimed before it is settled
+        let mut epoch_0 = WithdrawEpoch::default();
+        assert!(vd1
+            .claim_withdraw_epoch(
+                &mut epoch_0,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .is_err());
+
+        // half of the queued requests can be filled
+        let settle_amount = vault
+            .settle_withdraw_epoch(
+                &mut epoch_0,
+                &mut Some(vp.borrow_mut()),
+                75 * QUOTE_PRECISION_U64,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        assert_eq!(settle_amount, 75_000_000);
+        assert_eq!(vault.withdraw_epoch, 1);
+        assert_eq!(vault.withdraw_queue_shares, 75_000_000);
+        assert_eq!(vault.total_shares, 125_000_000);
+        vault_equity -= settle_amount;
+
+        let (vd1_amount, vd1_deposit_amount) = vd1
+            .claim_withdraw_epoch(
+                &mut epoch_0,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        let (vd2_amount, vd2_deposit_amount) = vd2
+            .claim_withdraw_epoch(
+                &mut epoch_0,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(vd1_amount, 50_000_000);
+        assert_eq!(vd2_amount, 25_000_000);
+        assert_eq!(vd1_deposit_amount + vd2_deposit_amount, 0);
+        assert_eq!(epoch_0.claimed_amount, epoch_0.filled_amount);
+        assert_eq!(vd1.checked_vault_shares(&vault).unwrap(), 50_000_000);
+        assert_eq!(vd1.last_withdraw_request.shares, 50_000_000);
+        assert_eq!(vd1.withdraw_request_epoch, 1);
+        assert_eq!(vd2.checked_vault_shares(&vault).unwrap(), 75_000_000);
+        assert_eq!(vd2.last_withdraw_request.shares, 25_000_000);
+
+        // the unfilled remainder rolled over and is filled in full
+        let mut epoch_1 = WithdrawEpoch::default();
+        let settle_amount = vault
+            .settle_withdraw_epoch(
+                &mut epoch_1,
+                &mut Some(vp.borrow_mut()),
+                1_000 * QUOTE_PRECISION_U64,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        assert_eq!(settle_amount, 75_000_000);
+        vault_equity -= settle_amount;
+
+        let (vd1_amount, vd1_deposit_amount) = vd1
+            .claim_withdraw_epoch(
+                &mut epoch_1,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        let (vd2_amount, vd2_deposit_amount) = vd2
+            .claim_withdraw_epoch(
+                &mut epoch_1,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(vd1_amount, 50_000_000);
+        assert_eq!(vd2_amount, 25_000_000);
+        assert_eq!(vd1_deposit_amount + vd2_deposit_amount, 0);
+        assert!(!vd1.last_withdraw_request.pending());
+        assert!(!vd2.last_withdraw_request.pending());
+        assert_eq!(vd1.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vd2.checked_vault_shares(&vault).unwrap(), 50_000_000);
+        assert_eq!(vault.user_shares, 50_000_000);
+        assert_eq!(vault.total_withdraw_requested, 0);
+        assert_eq!(vault_equity, 50_000_000);
+    }
+
+    #[test]
+    fn test_withdraw_queue_overdue() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.withdraw_queue = true;
+        vault.redeem_period = ONE_DAY;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut Some(vp.borrow_mut()), now)
+            .unwrap();
+        let vault_equity = amount;
+        assert!(!vault.is_withdraw_queue_overdue(now).unwrap());
+
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vault.withdraw_queue_start_ts, now);
+        assert!(!vault
+            .is_withdraw_queue_overdue(now + vault.redeem_period - 1)
+            .unwrap());
+        let now = now + vault.redeem_period;
+        assert!(vault.is_withdraw_queue_overdue(now).unwrap());
+
+        // empty settles don't advance the epoch
+        let mut epoch_0 = WithdrawEpoch::default();
+        assert!(vault
+            .settle_withdraw_epoch(
+                &mut epoch_0,
+                &mut Some(vp.borrow_mut()),
+                0,
+                vault_equity,
+                now,
+            )
+            .is_err());
+        assert_eq!(vault.withdraw_epoch, 0);
+
+        let settle_amount = vault
+            .settle_withdraw_epoch(
+                &mut epoch_0,
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        assert_eq!(settle_amount, amount);
+        assert_eq!(vault.withdraw_queue_shares, 0);
+        assert_eq!(vault.withdraw_queue_start_ts, 0);
+        assert!(!vault.is_withdraw_queue_overdue(now).unwrap());
+    }
+
+    #[test]
+    fn test_withdraw_queue_capped_at_request_value() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.withdraw_queue = true;
+
+        let vd1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let vd2 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd1.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+        vd2.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vd1.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(vd1.last_withdraw_request.value, amount);
+
+        // equity rises 50% before the epoch is settled, the fill is withdrawn at the settle price
+        vault_equity = 300 * QUOTE_PRECISION_U64;
+        let mut epoch_0 = WithdrawEpoch::default();
+        let settle_amount = vault
+            .settle_withdraw_epoch(
+                &mut epoch_0,
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        assert_eq!(settle_amount, 150_000_000);
+        assert_eq!(vault.total_shares, 100_000_000);
+        vault_equity -= settle_amount;
+
+        // the depositor is paid the requested value, the rise is deposited back into the vault
+        let (vd1_amount, vd1_deposit_amount) = vd1
+            .claim_withdraw_epoch(
+                &mut epoch_0,
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                now,
+            )
+            .unwrap();
+        assert_eq!(vd1_amount, amount);
+        assert_eq!(vd1_deposit_amount, 50_000_000);
+        assert_eq!(epoch_0.claimed_amount, epoch_0.filled_amount);
+        assert!(!vd1.last_withdraw_request.pending());
+        assert_eq!(vd1.checked_vault_shares(&vault).unwrap(), 0);
+        assert_eq!(vault.total_withdraw_requested, 0);
+        assert_eq!(vault.total_withdraws, amount);
+        vault_equity += vd1_deposit_amount;
+
+        // the remaining depositor keeps the whole rise
+        assert_eq!(vault.total_shares, 100_000_000);
+        assert_eq!(
+            if_shares_to_vault_amount(
+                vd2.checked_vault_shares(&vault).unwrap(),
+                vault.total_shares,
+                vault_equity
+            )
+            .unwrap(),
+            200 * QUOTE_PRECISION_U64
+        );
+    }
+
+    #[test]
+    fn test_search_max_withdraw_amount() {
+        let withdraw_amount = 1_000 * QUOTE_PRECISION_U64;
+
+        for max_amount in [
+            0,
+            1,
+            withdraw_amount / 3,
+            withdraw_amount - 1,
+            withdraw_amount,
+        ] {
+            let mut simulations = 0;
+            let amount = VaultDepositor::search_max_withdraw_amount(withdraw_amount, |amount| {
+                simulations += 1;
+                Ok(amount <= max_amount)
+            })
+            .unwrap();
+
+            // never more than the margin allows, and at most 1/256th of the request short of it
+            assert!(amount <= max_amount);
+            assert!(max_amount - amount <= withdraw_amount >> MAX_WITHDRAW_SEARCH_ITERATIONS);
+            assert!(simulations <= MAX_WITHDRAW_SEARCH_ITERATIONS + 1);
+        }
+
+        // small requests are searched exactly
+        let amount =
+            VaultDepositor::search_max_withdraw_amount(100, |amount| Ok(amount <= 37)).unwrap();
+        assert_eq!(amount, 37);
+        assert_eq!(
+            VaultDepositor::search_max_withdraw_amount(0, |_| Ok(false)).unwrap(),
+            0
+        );
+    }
 }
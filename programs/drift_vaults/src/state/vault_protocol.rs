@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_lang::prelude::{AccountLoader, Context};
+use drift::math::safe_math::SafeMath;
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
+use crate::constants::MIN_PARAMS_TIMELOCK;
+use crate::error::{ErrorCode, VaultResult};
+use crate::events::{VaultParamsUpdateAction, VaultProtocolParamsUpdateRecord};
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::state::Size;
+use crate::{validate, Vault};
 
+#[derive(Default)]
 pub struct VaultFee {
     pub management_fee_payment: i64,
     pub management_fee_shares: i64,
@@ -13,6 +19,31 @@ pub struct VaultFee {
     pub protocol_fee_shares: i64,
 }
 
+impl VaultFee {
+    pub fn add(&mut self, other: &VaultFee) -> VaultResult {
+        self.management_fee_payment = self
+            .management_fee_payment
+            .safe_add(other.management_fee_payment)?;
+        self.management_fee_shares = self
+            .management_fee_shares
+            .safe_add(other.management_fee_shares)?;
+        self.protocol_fee_payment = self
+            .protocol_fee_payment
+            .safe_add(other.protocol_fee_payment)?;
+        self.protocol_fee_shares = self
+            .protocol_fee_shares
+            .safe_add(other.protocol_fee_shares)?;
+        Ok(())
+    }
+}
+
+pub struct VaultEntryExitFee {
+    pub manager_fee: u64,
+    pub protocol_fee: u64,
+    /// The shares kept by the manager and protocol, instead of minted to or burned from the depositor
+    pub fee_shares: u128,
+}
+
 #[assert_no_slop]
 #[account(zero_copy(unsafe))]
 #[derive(Default, Eq, PartialEq, Debug)]
@@ -39,10 +70,28 @@ pub struct VaultProtocol {
     pub bump: u8,
     pub version: u8,
     pub padding: [u8; 2],
+    /// Percentage the protocol charges on deposits: PERCENTAGE_PRECISION
+    pub protocol_entry_fee: u32,
+    /// Percentage the protocol charges on withdrawals: PERCENTAGE_PRECISION
+    pub protocol_exit_fee: u32,
+    /// The protocol proposed by the current protocol. Becomes the protocol once they accept the handover.
+    pub pending_protocol: Pubkey,
+    /// The protocol fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_protocol_fee: u64,
+    /// The protocol profit share that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_protocol_profit_share: u32,
+    /// The protocol entry fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_protocol_entry_fee: u32,
+    /// The protocol exit fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.
+    pub pending_protocol_exit_fee: u32,
+    pub padding2: [u8; 4],
+    /// When the pending protocol fee increases take effect. 0 means there are no pending changes.
+    pub pending_params_effective_ts: i64,
+    pub padding1: [u64; 7],
 }
 
 impl Size for VaultProtocol {
-    const SIZE: usize = 120 + 8;
+    const SIZE: usize = 248 + 8;
 }
 const_assert_eq!(
     VaultProtocol::SIZE,
@@ -53,6 +102,196 @@ impl VaultProtocol {
     pub fn get_vault_protocol_seeds<'a>(vault: &'a [u8], bump: &'a u8) -> [&'a [u8]; 3] {
         [b"vault_protocol".as_ref(), vault, bytemuck::bytes_of(bump)]
     }
+
+    pub fn propose_protocol(&mut self, new_protocol: Pubkey) -> VaultResult {
+        validate!(
+            new_protocol != self.protocol,
+            ErrorCode::InvalidVaultUpdate,
+            "new protocol is already the protocol"
+        )?;
+
+        // proposing the default pubkey cancels the pending handover
+        self.pending_protocol = new_protocol;
+
+        Ok(())
+    }
+
+    /// Hands the protocol role to the pending protocol and returns the previous protocol.
+    /// Protocol shares and the last protocol withdraw request move to the new protocol as is.
+    pub fn accept_protocol(&mut self, new_protocol: Pubkey) -> VaultResult<Pubkey> {
+        validate!(
+            self.pending_protocol != Pubkey::default() && self.pending_protocol == new_protocol,
+            ErrorCode::InvalidVaultUpdate,
+            "signer is not the pending protocol"
+        )?;
+
+        let old_protocol = self.protocol;
+        self.protocol = new_protocol;
+        self.pending_protocol = Pubkey::default();
+
+        Ok(old_protocol)
+    }
+
+    /// Announces protocol fee increases that take effect at `effective_ts`, which must be at least a
+    /// redeem period and [`MIN_PARAMS_TIMELOCK`] away so depositors can exit before. A value of 0 leaves the fee unchanged,
+    /// and proposing no changes cancels the pending ones.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_params(
+        &mut self,
+        vault: &Vault,
+        protocol_fee: u64,
+        protocol_profit_share: u32,
+        protocol_entry_fee: u32,
+        protocol_exit_fee: u32,
+        effective_ts: i64,
+        now: i64,
+    ) -> VaultResult {
+        self.reset_pending_params();
+
+        if protocol_fee == 0
+            && protocol_profit_share == 0
+            && protocol_entry_fee == 0
+            && protocol_exit_fee == 0
+        {
+            return Ok(());
+        }
+
+        // the redeem period can be shortened right before proposing, so a minimum notice always applies
+        validate!(
+            effective_ts >= now.safe_add(vault.redeem_period.max(MIN_PARAMS_TIMELOCK))?,
+            ErrorCode::InvalidVaultUpdate,
+            "pending params must take effect at least a redeem period and {}s from now",
+            MIN_PARAMS_TIMELOCK
+        )?;
+
+        validate!(
+            protocol_fee == 0 || protocol_fee > self.protocol_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "pending protocol fee must be greater than existing protocol fee"
+        )?;
+        validate!(
+            protocol_profit_share == 0 || protocol_profit_share > self.protocol_profit_share,
+            ErrorCode::InvalidVaultUpdate,
+            "pending protocol profit share must be greater than existing protocol profit share"
+        )?;
+        validate!(
+            protocol_entry_fee == 0 || protocol_entry_fee > self.protocol_entry_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "pending protocol entry fee must be greater than existing protocol entry fee"
+        )?;
+        validate!(
+            protocol_exit_fee == 0 || protocol_exit_fee > self.protocol_exit_fee,
+            ErrorCode::InvalidVaultUpdate,
+            "pending protocol exit fee must be greater than existing protocol exit fee"
+        )?;
+
+        self.pending_protocol_fee = protocol_fee;
+        self.pending_protocol_profit_share = protocol_profit_share;
+        self.pending_protocol_entry_fee = protocol_entry_fee;
+        self.pending_protocol_exit_fee = protocol_exit_fee;
+        self.pending_params_effective_ts = effective_ts;
+
+        self.emit_pending_params_record(vault.pubkey, VaultParamsUpdateAction::Propose, now);
+
+        Ok(())
+    }
+
+    /// Fees of a protocol attached to an existing vault are announced like any other increase, so the
+    /// vault's depositors can exit before they apply. Returns the ts the fees take effect at.
+    pub fn propose_initial_params(
+        &mut self,
+        vault: &Vault,
+        protocol_fee: u64,
+        protocol_profit_share: u32,
+        protocol_entry_fee: u32,
+        protocol_exit_fee: u32,
+        now: i64,
+    ) -> VaultResult<i64> {
+        let effective_ts = now.safe_add(vault.redeem_period.max(MIN_PARAMS_TIMELOCK))?;
+
+        self.propose_params(
+            vault,
+            protocol_fee,
+            protocol_profit_share,
+            protocol_entry_fee,
+            protocol_exit_fee,
+            effective_ts,
+            now,
+        )?;
+
+        Ok(effective_ts)
+    }
+
+    pub fn apply_pending_params(&mut self, vault: Pubkey, now: i64) {
+        if self.pending_protocol_fee != 0 {
+            self.protocol_fee = self.pending_protocol_fee;
+        }
+        if self.pending_protocol_profit_share != 0 {
+            self.protocol_profit_share = self.pending_protocol_profit_share;
+        }
+        if self.pending_protocol_entry_fee != 0 {
+            self.protocol_entry_fee = self.pending_protocol_entry_fee;
+        }
+        if self.pending_protocol_exit_fee != 0 {
+            self.protocol_exit_fee = self.pending_protocol_exit_fee;
+        }
+
+        self.emit_pending_params_record(vault, VaultParamsUpdateAction::Apply, now);
+        self.reset_pending_params();
+    }
+
+    pub fn reset_pending_params(&mut self) {
+        self.pending_protocol_fee = 0;
+        self.pending_protocol_profit_share = 0;
+        self.pending_protocol_entry_fee = 0;
+        self.pending_protocol_exit_fee = 0;
+        self.pending_params_effective_ts = 0;
+    }
+
+    fn emit_pending_params_record(&self, vault: Pubkey, action: VaultParamsUpdateAction, ts: i64) {
+        emit!(VaultProtocolParamsUpdateRecord {
+            ts,
+            vault,
+            action,
+            effective_ts: self.pending_params_effective_ts,
+            protocol_fee: self.pending_protocol_fee,
+            protocol_profit_share: self.pending_protocol_profit_share,
+            protocol_entry_fee: self.pending_protocol_entry_fee,
+            protocol_exit_fee: self.pending_protocol_exit_fee,
+        });
+    }
+
+    /// A renounced protocol with no shares or withdraw request left can be removed from the vault
+    pub fn validate_can_remove(&self) -> VaultResult {
+        validate!(
+            self.protocol_fee == 0
+                && self.protocol_profit_share == 0
+                && self.protocol_entry_fee == 0
+                && self.protocol_exit_fee == 0,
+            ErrorCode::InvalidVaultUpdate,
+            "protocol fees must be renounced before removing the protocol"
+        )?;
+
+        validate!(
+            self.protocol_profit_and_fee_shares == 0
+                && !self.last_protocol_withdraw_request.pending(),
+            ErrorCode::InvalidVaultUpdate,
+            "protocol shares must be withdrawn before removing the protocol"
+        )?;
+
+        Ok(())
+    }
+
+    pub fn validate_can_close(&self) -> VaultResult {
+        validate!(
+            self.protocol_profit_and_fee_shares == 0
+                && !self.last_protocol_withdraw_request.pending(),
+            ErrorCode::InvalidVaultClose,
+            "protocol shares must be withdrawn before closing the vault protocol"
+        )?;
+
+        Ok(())
+    }
 }
 
 pub trait VaultProtocolProvider<'a> {
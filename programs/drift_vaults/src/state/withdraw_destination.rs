@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+
+use crate::constants::WITHDRAW_DESTINATION_TIMELOCK;
+use crate::error::VaultResult;
+
+/// The token account withdrawals are paid to instead of the owner's own token account. Changes only
+/// take effect after [`WITHDRAW_DESTINATION_TIMELOCK`].
+#[assert_no_slop]
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct WithdrawDestination {
+    /// the token account withdrawals are paid to, default means any token account of the owner
+    pub destination: Pubkey,
+    /// the destination that replaces `destination` at `pending_effective_ts`
+    pub pending_destination: Pubkey,
+    /// when `pending_destination` takes effect, 0 if there is no pending change
+    pub pending_effective_ts: i64,
+}
+
+impl WithdrawDestination {
+    /// The destination in effect at `now`, None if withdrawals go to the owner's token account
+    pub fn get(&self, now: i64) -> Option<Pubkey> {
+        let destination = if self.pending_effective_ts != 0 && now >= self.pending_effective_ts {
+            self.pending_destination
+        } else {
+            self.destination
+        };
+
+        (destination != Pubkey::default()).then_some(destination)
+    }
+
+    /// Whether withdrawals are restricted to a registered destination at `now` or will be once a pending
+    /// change takes effect
+    pub fn is_registered(&self, now: i64) -> bool {
+        self.get(now).is_some()
+            || (self.pending_effective_ts != 0 && now < self.pending_effective_ts)
+    }
+
+    /// Schedules a change to `destination`, returning the ts it takes effect. Proposing the destination
+    /// already in effect cancels any pending change.
+    pub fn propose(&mut self, destination: Pubkey, now: i64) -> VaultResult<i64> {
+        self.destination = self.get(now).unwrap_or_default();
+        self.pending_destination = Pubkey::default();
+        self.pending_effective_ts = 0;
+
+        if destination == self.destination {
+            return Ok(now);
+        }
+
+        self.pending_destination = destination;
+        self.pending_effective_ts = now.safe_add(WITHDRAW_DESTINATION_TIMELOCK)?;
+
+        Ok(self.pending_effective_ts)
+    }
+}
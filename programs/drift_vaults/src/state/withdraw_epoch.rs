@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::{ErrorCode, VaultResult};
+use crate::{validate, Size};
+
+/// A settled withdraw epoch. Every request queued in the epoch is filled by the same fraction,
+/// `filled_shares / requested_shares`, at the share price the epoch was settled at, capped at the
+/// request's value when claimed.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct WithdrawEpoch {
+    /// The vault the epoch belongs to
+    pub vault: Pubkey,
+    /// The withdraw epoch account's pubkey. It is a pda of vault and epoch
+    pub pubkey: Pubkey,
+    /// The shares queued for withdraw when the epoch was settled
+    pub requested_shares: u128,
+    /// The queued shares burned to fill the epoch, the rest rolled over into the next epoch
+    pub filled_shares: u128,
+    /// The token amount withdrawn from drift to pay for the filled shares, before exit fees
+    pub filled_amount: u64,
+    /// The token amount claimed by depositors so far, including exit fees and the value above each
+    /// request's cap deposited back into the vault
+    pub claimed_amount: u64,
+    pub epoch: u64,
+    pub settled_ts: i64,
+    /// The vault's shares_base when the epoch was settled
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding1: [u8; 3],
+    pub padding: [u64; 9],
+}
+
+impl Size for WithdrawEpoch {
+    const SIZE: usize = 208 + 8;
+}
+
+const_assert_eq!(
+    WithdrawEpoch::SIZE,
+    std::mem::size_of::<WithdrawEpoch>() + 8
+);
+
+impl WithdrawEpoch {
+    /// The shares filled and token amount paid for a depositor's `request_shares` queued in this epoch.
+    /// `shares_base` is the vault's current shares_base, used to bring the request back to the epoch's
+    /// share precision if the vault rebased since settling.
+    pub fn calculate_fill(
+        &self,
+        request_shares: u128,
+        shares_base: u32,
+    ) -> VaultResult<(u128, u64)> {
+        validate!(
+            shares_base >= self.shares_base,
+            ErrorCode::InvalidVaultRebase,
+            "vault shares_base {} below epoch shares_base {}",
+            shares_base,
+            self.shares_base
+        )?;
+
+        if self.requested_shares == 0 || self.filled_shares == 0 {
+            return Ok((0, 0));
+        }
+
+        // round filled shares up so depositors never hold more shares than the vault accounts for
+        let filled_shares = request_shares
+            .safe_mul(self.filled_shares)?
+            .safe_add(self.requested_shares.safe_sub(1)?)?
+            .safe_div(self.requested_shares)?
+            .min(request_shares);
+
+        let rebase_multiplier = 10_u128.pow(shares_base.safe_sub(self.shares_base)?);
+        let amount = request_shares
+            .safe_mul(rebase_multiplier)?
+            .safe_mul(self.filled_amount.cast()?)?
+            .safe_div(self.requested_shares)?
+            .cast::<u64>()?
+            .min(self.filled_amount.safe_sub(self.claimed_amount)?);
+
+        Ok((filled_shares, amount))
+    }
+}
@@ -4,6 +4,7 @@ use crate::{validate, Vault};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use bytemuck::Zeroable;
+use drift::math::casting::Cast;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
@@ -122,4 +123,27 @@ impl WithdrawRequest {
 
         Ok(())
     }
+
+    /// The early withdraw penalty (PERCENTAGE_PRECISION) for withdrawing now, decaying linearly from
+    /// `vault.early_withdraw_penalty` at request time to 0 at the end of the redeem period
+    pub fn calculate_early_withdraw_penalty(&self, vault: &Vault, now: i64) -> VaultResult<u32> {
+        if vault.redeem_period == 0 {
+            return Ok(0);
+        }
+
+        let time_since_withdraw_request = now.safe_sub(self.ts)?.max(0);
+        let time_remaining = vault
+            .redeem_period
+            .safe_sub(time_since_withdraw_request)?
+            .max(0);
+
+        let penalty = vault
+            .early_withdraw_penalty
+            .cast::<i64>()?
+            .safe_mul(time_remaining)?
+            .safe_div(vault.redeem_period)?
+            .cast::<u32>()?;
+
+        Ok(penalty)
+    }
 }
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::MAX_WITHDRAW_REQUEST_SLOTS;
+use crate::error::{ErrorCode, VaultResult};
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::{validate, Size, Vault};
+
+/// Additional withdraw requests for a vault depositor, each with its own redeem period clock, so
+/// withdrawals can be laddered alongside the depositor's `last_withdraw_request`.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct WithdrawRequestSlots {
+    /// The vault depositor the requests belong to
+    pub vault_depositor: Pubkey,
+    /// The vault the vault depositor deposited into
+    pub vault: Pubkey,
+    pub requests: [WithdrawRequest; MAX_WITHDRAW_REQUEST_SLOTS],
+    /// the exponent for the request shares decimal places, requests are rebased lazily when loaded
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding1: [u8; 3],
+    pub padding: [u64; 7],
+}
+
+impl Size for WithdrawRequestSlots {
+    const SIZE: usize = 384 + 8;
+}
+
+const_assert_eq!(
+    WithdrawRequestSlots::SIZE,
+    std::mem::size_of::<WithdrawRequestSlots>() + 8
+);
+
+impl WithdrawRequestSlots {
+    /// Brings the requests to the vault's current shares_base
+    pub fn rebase(&mut self, vault: &Vault) -> VaultResult {
+        if self.shares_base == vault.shares_base {
+            return Ok(());
+        }
+
+        validate!(
+            vault.shares_base > self.shares_base,
+            ErrorCode::InvalidVaultRebase,
+            "withdraw request slots base {} above vault base {}",
+            self.shares_base,
+            vault.shares_base
+        )?;
+
+        let rebase_divisor = 10_u128.pow(vault.shares_base.safe_sub(self.shares_base)?);
+        for request in self.requests.iter_mut() {
+            request.rebase(rebase_divisor)?;
+        }
+        self.shares_base = vault.shares_base;
+
+        Ok(())
+    }
+
+    pub fn get_request_mut(&mut self, slot: u8) -> VaultResult<&mut WithdrawRequest> {
+        validate!(
+            (slot as usize) < MAX_WITHDRAW_REQUEST_SLOTS,
+            ErrorCode::InvalidVaultWithdraw,
+            "invalid withdraw request slot {}",
+            slot
+        )?;
+
+        Ok(&mut self.requests[slot as usize])
+    }
+
+    pub fn find_empty_slot(&self) -> VaultResult<u8> {
+        match self.requests.iter().position(|request| !request.pending()) {
+            Some(slot) => Ok(slot as u8),
+            None => {
+                let ec = ErrorCode::VaultWithdrawRequestInProgress;
+                msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+                msg!("all withdraw request slots are in use");
+                Err(ec)
+            }
+        }
+    }
+}
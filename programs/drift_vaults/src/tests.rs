@@ -338,7 +338,7 @@ mod vault_fcn {
 
         now += 60 * 60 * 24; // 1 day later
 
-        vd.apply_profit_share(vault_equity, &mut vault, &mut vp)
+        vd.apply_profit_share(vault_equity, &mut vault, &mut vp, now)
             .unwrap();
         vault.apply_fee(&mut vp, vault_equity, now).unwrap();
 
@@ -402,7 +402,7 @@ mod vault_fcn {
         while (vault.total_shares == 2000000000 + 100000000) && cnt < 400 {
             now += 60 * 60 * 24; // 1 day later
 
-            vd.apply_profit_share(vault_equity, &mut vault, &mut vp)
+            vd.apply_profit_share(vault_equity, &mut vault, &mut vp, now)
                 .unwrap();
             vault.apply_fee(&mut vp, vault_equity, now).unwrap();
             // crate::msg!("vault last ts: {} vs {}", vault.last_fee_update_ts, now);
@@ -488,7 +488,7 @@ mod vault_fcn {
         while (vault.total_shares == 2000000000 + 100000000) && cnt < 400 {
             now += 60 * 60 * 24; // 1 day later
 
-            vd.apply_profit_share(vault_equity, &mut vault, &mut vp)
+            vd.apply_profit_share(vault_equity, &mut vault, &mut vp, now)
                 .unwrap();
             vault.apply_fee(&mut vp, vault_equity, now).unwrap();
             // crate::msg!("vault last ts: {} vs {}", vault.last_fee_update_ts, now);
@@ -747,6 +747,551 @@ mod vault_fcn {
         );
         assert!(!finishing_liquidation);
     }
+
+    #[test]
+    fn test_manager_transfer() {
+        let now = 0;
+        let mut vault = Vault::default();
+        let mut vp = None;
+        vault.redeem_period = 60;
+
+        let old_manager = Pubkey::new_unique();
+        let new_manager = Pubkey::new_unique();
+        vault.manager = old_manager;
+        vault.delegate = old_manager;
+
+        let amount = 100_000_000; // $100
+        vault.manager_deposit(&mut vp, amount, 0, now).unwrap();
+        vault
+            .manager_request_withdraw(&mut vp, amount, WithdrawUnit::Token, amount, now)
+            .unwrap();
+
+        assert!(vault.propose_manager(old_manager).is_err());
+        assert!(vault.accept_manager(new_manager).is_err());
+
+        vault.propose_manager(new_manager).unwrap();
+        assert_eq!(vault.pending_manager, new_manager);
+        assert!(vault.accept_manager(Pubkey::new_unique()).is_err());
+
+        assert_eq!(vault.accept_manager(new_manager).unwrap(), old_manager);
+        assert_eq!(vault.manager, new_manager);
+        assert_eq!(vault.delegate, new_manager);
+        assert_eq!(vault.pending_manager, Pubkey::default());
+
+        // manager shares and pending withdraw request carry over to the new manager
+        assert_eq!(vault.get_manager_shares(&mut vp).unwrap(), amount as u128);
+        assert_eq!(vault.last_manager_withdraw_request.value, amount);
+        let manager_withdraw_amount = vault.manager_withdraw(&mut vp, amount, now + 60).unwrap();
+        assert_eq!(manager_withdraw_amount, amount);
+        assert_eq!(vault.total_shares, 0);
+    }
+
+    #[test]
+    fn test_pending_params_increase() {
+        use crate::constants::MIN_PARAMS_TIMELOCK;
+        use crate::state::LiquidationSettings;
+
+        let now = 0;
+        let mut vault = Vault::default();
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vault.management_fee = 10_000; // 1%
+        vault.redeem_period = 60;
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut None, now).unwrap();
+        let vault_equity = amount;
+
+        let half_year = ONE_YEAR as i64 / 2;
+
+        // must be announced at least a redeem period ahead
+        assert!(vault
+            .propose_params(
+                20_000,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                LiquidationSettings::default(),
+                now + 59,
+                now
+            )
+            .is_err());
+        // and no sooner than the minimum notice, however short the redeem period
+        assert!(vault
+            .propose_params(
+                20_000,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                LiquidationSettings::default(),
+                now + MIN_PARAMS_TIMELOCK - 1,
+                now
+            )
+            .is_err());
+        // only increases can be announced
+        assert!(vault
+            .propose_params(
+                5_000,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                LiquidationSettings::default(),
+                half_year,
+                now
+            )
+            .is_err());
+
+        vault
+            .propose_params(
+                20_000,
+                120,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                LiquidationSettings::default(),
+                half_year,
+                now,
+            )
+            .unwrap();
+        assert_eq!(vault.pending_params_effective_ts, half_year);
+
+        // not effective yet
+        vault.apply_fee(&mut None, vault_equity, now + 100).unwrap();
+        assert_eq!(vault.management_fee, 10_000);
+        assert_eq!(vault.redeem_period, 60);
+
+        // fees before the effective ts are charged at the old rate, after at the new rate
+        vault
+            .apply_fee(&mut None, vault_equity, ONE_YEAR as i64)
+            .unwrap();
+        assert_eq!(vault.management_fee, 20_000);
+        assert_eq!(vault.redeem_period, 120);
+        assert_eq!(vault.pending_params_effective_ts, 0);
+        assert_eq!(vault.pending_management_fee, 0);
+        assert_eq!(vault.last_fee_update_ts, ONE_YEAR as i64);
+        assert_eq!(vault.manager_total_fee, 1_495_003);
+    }
+
+    #[test]
+    fn test_pending_profit_share_crystallization_period() {
+        use crate::constants::{MIN_PARAMS_TIMELOCK, ONE_DAY};
+        use crate::state::LiquidationSettings;
+
+        let now = 0;
+        let mut vault = Vault::default();
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vault.profit_share_crystallization_period = ONE_DAY * 30;
+        let effective_ts = now + MIN_PARAMS_TIMELOCK;
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut None, now).unwrap();
+
+        let propose = |vault: &mut Vault, period: i64| {
+            vault.propose_params(
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                period,
+                LiquidationSettings::default(),
+                effective_ts,
+                now,
+            )
+        };
+
+        // only a longer period, up to a year, can be announced
+        assert!(propose(&mut vault, ONE_DAY * 7).is_err());
+        assert!(propose(&mut vault, ONE_DAY * 366).is_err());
+        propose(&mut vault, ONE_DAY * 90).unwrap();
+        assert_eq!(
+            vault.pending_profit_share_crystallization_period,
+            ONE_DAY * 90
+        );
+        assert_eq!(vault.pending_params_effective_ts, effective_ts);
+
+        // the current period applies until the effective ts
+        vault
+            .apply_fee(&mut None, amount, effective_ts - 1)
+            .unwrap();
+        assert_eq!(vault.profit_share_crystallization_period, ONE_DAY * 30);
+        vault.apply_fee(&mut None, amount, effective_ts).unwrap();
+        assert_eq!(vault.profit_share_crystallization_period, ONE_DAY * 90);
+        assert_eq!(vault.pending_profit_share_crystallization_period, 0);
+        assert_eq!(vault.pending_params_effective_ts, 0);
+    }
+
+    #[test]
+    fn test_allowlist_proof() {
+        use anchor_lang::solana_program::keccak::hashv;
+
+        let hash_pair = |a: [u8; 32], b: [u8; 32]| {
+            if a <= b {
+                hashv(&[&a, &b]).to_bytes()
+            } else {
+                hashv(&[&b, &a]).to_bytes()
+            }
+        };
+
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let carol = Pubkey::new_unique();
+        let alice_leaf = hashv(&[alice.as_ref(), &0_u64.to_le_bytes()]).to_bytes();
+        let bob_leaf = hashv(&[bob.as_ref(), &QUOTE_PRECISION_U64.to_le_bytes()]).to_bytes();
+        let carol_leaf = hashv(&[carol.as_ref(), &0_u64.to_le_bytes()]).to_bytes();
+        let alice_bob = hash_pair(alice_leaf, bob_leaf);
+
+        let mut vault = Vault::default();
+        assert!(!vault.verify_allowlist_proof(&alice, 0, &[bob_leaf, carol_leaf]));
+
+        vault.allowlist_merkle_root = hash_pair(alice_bob, carol_leaf);
+        assert!(vault.verify_allowlist_proof(&alice, 0, &[bob_leaf, carol_leaf]));
+        assert!(vault.verify_allowlist_proof(&bob, QUOTE_PRECISION_U64, &[alice_leaf, carol_leaf]));
+        assert!(vault.verify_allowlist_proof(&carol, 0, &[alice_bob]));
+
+        // the leaf commits to the cap
+        assert!(!vault.verify_allowlist_proof(&bob, 0, &[alice_leaf, carol_leaf]));
+        assert!(!vault.verify_allowlist_proof(&Pubkey::new_unique(), 0, &[alice_bob]));
+    }
+
+    #[test]
+    fn test_validate_access() {
+        use anchor_lang::prelude::{Account, AccountInfo};
+        use anchor_lang::solana_program::program_option::COption;
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::metadata::mpl_token_metadata;
+        use anchor_spl::metadata::mpl_token_metadata::types::Key;
+        use anchor_spl::token::spl_token;
+        use anchor_spl::token::spl_token::state::AccountState;
+
+        use crate::state::VaultAccessAccounts;
+
+        /// Runs `validate_access` with token accounts of (owner, mint, amount) and NFT metadata of
+        /// (mint, (verified, collection))
+        fn validate_access(
+            vault: &Vault,
+            authority: &Pubkey,
+            token_accounts: &[(Pubkey, Pubkey, u64)],
+            metadata_accounts: &[(Pubkey, Option<(bool, Pubkey)>)],
+        ) -> anchor_lang::Result<()> {
+            let mut token_account_data: Vec<Vec<u8>> = token_accounts
+                .iter()
+                .map(|(owner, mint, amount)| {
+                    let mut data = vec![0_u8; spl_token::state::Account::LEN];
+                    spl_token::state::Account {
+                        mint: *mint,
+                        owner: *owner,
+                        amount: *amount,
+                        delegate: COption::None,
+                        state: AccountState::Initialized,
+                        is_native: COption::None,
+                        delegated_amount: 0,
+                        close_authority: COption::None,
+                    }
+                    .pack_into_slice(&mut data);
+                    data
+                })
+                .collect();
+            // borsh layout of the metadata account, with no creators, uses or other optional fields
+            let mut metadata_data: Vec<Vec<u8>> = metadata_accounts
+                .iter()
+                .map(|(mint, collection)| {
+                    let mut data = vec![Key::MetadataV1 as u8];
+                    data.extend_from_slice(Pubkey::new_unique().as_ref()); // update authority
+                    data.extend_from_slice(mint.as_ref());
+                    data.extend_from_slice(&[0_u8; 12]); // empty name, symbol and uri
+                    data.extend_from_slice(&[0_u8; 2]); // seller fee basis points
+                    data.extend_from_slice(&[0_u8; 5]); // creators, sale, mutable, nonce, standard
+                    match collection {
+                        Some((verified, key)) => {
+                            data.extend_from_slice(&[1, *verified as u8]);
+                            data.extend_from_slice(key.as_ref());
+                        }
+                        None => data.push(0),
+                    }
+                    data.extend_from_slice(&[0_u8; 3]); // uses, collection details, config
+                    data
+                })
+                .collect();
+
+            let token_keys: Vec<Pubkey> = token_accounts
+                .iter()
+                .map(|_| Pubkey::new_unique())
+                .collect();
+            let metadata_keys: Vec<Pubkey> = metadata_accounts
+                .iter()
+                .map(|_| Pubkey::new_unique())
+                .collect();
+            let mut token_lamports = vec![0_u64; token_accounts.len()];
+            let mut metadata_lamports = vec![0_u64; metadata_accounts.len()];
+            let token_account_infos: Vec<AccountInfo> = token_keys
+                .iter()
+                .zip(token_lamports.iter_mut())
+                .zip(token_account_data.iter_mut())
+                .map(|((key, lamports), data)| {
+                    AccountInfo::new(key, false, false, lamports, data, &spl_token::ID, false, 0)
+                })
+                .collect();
+            let metadata_account_infos: Vec<AccountInfo> = metadata_keys
+                .iter()
+                .zip(metadata_lamports.iter_mut())
+                .zip(metadata_data.iter_mut())
+                .map(|((key, lamports), data)| {
+                    AccountInfo::new(
+                        key,
+                        false,
+                        false,
+                        lamports,
+                        data,
+                        &mpl_token_metadata::ID,
+                        false,
+                        0,
+                    )
+                })
+                .collect();
+
+            vault.validate_access(
+                authority,
+                &VaultAccessAccounts {
+                    token_accounts: token_account_infos
+                        .iter()
+                        .map(|account_info| Account::try_from(account_info).unwrap())
+                        .collect(),
+                    metadata_accounts: metadata_account_infos
+                        .iter()
+                        .map(|account_info| Account::try_from(account_info).unwrap())
+                        .collect(),
+                },
+            )
+        }
+
+        let authority = Pubkey::new_unique();
+        let access_mint = Pubkey::new_unique();
+        let mut vault = Vault::default();
+
+        // vaults that aren't token gated don't check
+        validate_access(&vault, &authority, &[], &[]).unwrap();
+
+        // any balance of the access mint, but not an empty token account
+        vault.access_mint = access_mint;
+        validate_access(&vault, &authority, &[(authority, access_mint, 1)], &[]).unwrap();
+        assert!(validate_access(&vault, &authority, &[(authority, access_mint, 0)], &[]).is_err());
+        assert!(validate_access(&vault, &authority, &[], &[]).is_err());
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(authority, Pubkey::new_unique(), 1)],
+            &[]
+        )
+        .is_err());
+
+        // the min balance is checked when set
+        vault.access_min_balance = 10;
+        assert!(validate_access(&vault, &authority, &[(authority, access_mint, 9)], &[]).is_err());
+        validate_access(&vault, &authority, &[(authority, access_mint, 10)], &[]).unwrap();
+
+        // the token account must belong to the authority
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(Pubkey::new_unique(), access_mint, 10)],
+            &[]
+        )
+        .is_err());
+
+        let access_collection = Pubkey::new_unique();
+        let nft_mint = Pubkey::new_unique();
+        let verified = Some((true, access_collection));
+        let mut vault = Vault {
+            access_collection,
+            ..Vault::default()
+        };
+
+        // an NFT of the verified collection
+        validate_access(
+            &vault,
+            &authority,
+            &[(authority, nft_mint, 1)],
+            &[(nft_mint, verified)],
+        )
+        .unwrap();
+        // anyone can claim an unverified collection
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(authority, nft_mint, 1)],
+            &[(nft_mint, Some((false, access_collection)))],
+        )
+        .is_err());
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(authority, nft_mint, 1)],
+            &[(nft_mint, Some((true, Pubkey::new_unique())))],
+        )
+        .is_err());
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(authority, nft_mint, 1)],
+            &[(nft_mint, None)]
+        )
+        .is_err());
+        // the NFT must be held by the authority
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(Pubkey::new_unique(), nft_mint, 1)],
+            &[(nft_mint, verified)],
+        )
+        .is_err());
+        assert!(validate_access(
+            &vault,
+            &authority,
+            &[(authority, nft_mint, 0)],
+            &[(nft_mint, verified)],
+        )
+        .is_err());
+
+        // either the access mint or the collection grants access
+        vault.access_mint = access_mint;
+        validate_access(&vault, &authority, &[(authority, access_mint, 1)], &[]).unwrap();
+        validate_access(
+            &vault,
+            &authority,
+            &[(authority, nft_mint, 1)],
+            &[(nft_mint, verified)],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_liquidation_settings() {
+        use crate::constants::{
+            permissioned_liquidator, MAX_LIQUIDATORS, MAX_TIME_FOR_LIQUIDATION,
+            MIN_PARAMS_TIMELOCK, ONE_DAY, TIME_FOR_LIQUIDATION,
+        };
+        use crate::state::LiquidationSettings;
+
+        let now = 1000;
+        let effective_ts = now + MIN_PARAMS_TIMELOCK;
+        let mut vault = Vault::default();
+        let liquidator = Pubkey::new_unique();
+
+        // defaults to the permissioned liquidator and a one hour window
+        assert_eq!(
+            vault.get_default_liquidator(),
+            permissioned_liquidator::id()
+        );
+        vault
+            .validate_liquidator(&permissioned_liquidator::id())
+            .unwrap();
+        assert!(vault.validate_liquidator(&liquidator).is_err());
+        assert_eq!(vault.get_liquidation_window(), TIME_FOR_LIQUIDATION);
+
+        let propose = |vault: &mut Vault, settings: LiquidationSettings, effective_ts: i64| {
+            vault.propose_params(0, 0, 0, 0, 0, 0, 0, 0, settings, effective_ts, now)
+        };
+
+        assert!(propose(
+            &mut vault,
+            LiquidationSettings {
+                liquidators: Some(vec![Pubkey::new_unique(); MAX_LIQUIDATORS + 1]),
+                ..LiquidationSettings::default()
+            },
+            effective_ts
+        )
+        .is_err());
+        for liquidation_window in [TIME_FOR_LIQUIDATION - 1, MAX_TIME_FOR_LIQUIDATION + 1] {
+            assert!(propose(
+                &mut vault,
+                LiquidationSettings {
+                    liquidation_window: Some(liquidation_window),
+                    ..LiquidationSettings::default()
+                },
+                effective_ts
+            )
+            .is_err());
+        }
+
+        let settings = LiquidationSettings {
+            liquidators: Some(vec![liquidator]),
+            liquidation_window: Some(ONE_DAY),
+            permissionless_liquidation: None,
+        };
+        // liquidation settings are timelocked like the fees
+        assert!(propose(&mut vault, settings.clone(), effective_ts - 1).is_err());
+        propose(&mut vault, settings.clone(), effective_ts).unwrap();
+        assert_eq!(vault.pending_management_fee, 0);
+        assert_eq!(vault.pending_params_effective_ts, 0);
+
+        vault.apply_pending_liquidation_settings(effective_ts - 1);
+        assert_eq!(
+            vault.get_default_liquidator(),
+            permissioned_liquidator::id()
+        );
+        assert_eq!(vault.get_liquidation_window(), TIME_FOR_LIQUIDATION);
+
+        // an allowed liquidator list replaces the permissioned liquidator
+        vault.apply_pending_liquidation_settings(effective_ts);
+        assert_eq!(vault.pending_liquidation_settings_ts, 0);
+        assert_eq!(vault.get_default_liquidator(), liquidator);
+        assert_eq!(vault.get_liquidation_window(), ONE_DAY);
+        vault.validate_liquidator(&liquidator).unwrap();
+        assert!(vault
+            .validate_liquidator(&permissioned_liquidator::id())
+            .is_err());
+
+        // settings left out keep their current value
+        propose(
+            &mut vault,
+            LiquidationSettings {
+                permissionless_liquidation: Some(true),
+                ..LiquidationSettings::default()
+            },
+            effective_ts,
+        )
+        .unwrap();
+
+        // the window can't change under an ongoing liquidation
+        vault.set_liquidation_delegate(liquidator, effective_ts);
+        vault.apply_pending_liquidation_settings(effective_ts);
+        assert!(!vault.permissionless_liquidation);
+        assert!(vault
+            .check_can_exit_liquidation(effective_ts + ONE_DAY)
+            .is_err());
+        vault
+            .check_can_exit_liquidation(effective_ts + ONE_DAY + 1)
+            .unwrap();
+        vault.reset_liquidation_delegate();
+        vault
+            .check_available_for_liquidation(effective_ts + ONE_DAY + 1)
+            .unwrap();
+
+        vault.apply_pending_liquidation_settings(effective_ts + ONE_DAY + 1);
+        assert_eq!(vault.get_default_liquidator(), liquidator);
+        assert_eq!(vault.get_liquidation_window(), ONE_DAY);
+        vault.validate_liquidator(&Pubkey::new_unique()).unwrap();
+        assert!(vault.validate_liquidator(&Pubkey::default()).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -1318,7 +1863,7 @@ mod vault_v1_fcn {
 
         now += 60 * 60 * 24; // 1 day later
 
-        vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()))
+        vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
             .unwrap();
         vault
             .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
@@ -1398,7 +1943,7 @@ mod vault_v1_fcn {
         while (vault.total_shares == 2000000000 + 100000000) && cnt < 400 {
             now += 60 * 60 * 24; // 1 day later
 
-            vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()))
+            vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
                 .unwrap();
             vault
                 .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
@@ -1500,7 +2045,7 @@ mod vault_v1_fcn {
         while (vault.total_shares == 2_000_000_000 + 100_000_000) && cnt < 400 {
             now += 60 * 60 * 24; // 1 day later
 
-            vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()))
+            vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
                 .unwrap();
             vault
                 .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
@@ -1608,7 +2153,7 @@ mod vault_v1_fcn {
         while (vault.total_shares == 2_000_000_000 + 100_000_000) && cnt < 400 {
             now += 60 * 60 * 24; // 1 day later
 
-            vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()))
+            vd.apply_profit_share(vault_equity, &mut vault, &mut Some(vp.borrow_mut()), now)
                 .unwrap();
             vault
                 .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
@@ -1682,6 +2227,158 @@ mod vault_v1_fcn {
             vault_equity - 1
         );
     }
+
+    #[test]
+    fn test_protocol_transfer_and_renounce() {
+        let now = 0;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = 60;
+        vp.borrow_mut().protocol_fee = 500; // 5 bps
+        vp.borrow_mut().protocol_profit_share = 100_000; // 10%
+
+        let old_protocol = Pubkey::new_unique();
+        let new_protocol = Pubkey::new_unique();
+        vp.borrow_mut().protocol = old_protocol;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let mut vault_equity: u64 = 0;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        vault
+            .apply_fee(
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
+            .unwrap();
+        let protocol_shares = vault.get_protocol_shares(&mut Some(vp.borrow_mut()));
+        assert!(protocol_shares > 0);
+
+        // two-step handover
+        assert!(vp.borrow_mut().propose_protocol(old_protocol).is_err());
+        vp.borrow_mut().propose_protocol(new_protocol).unwrap();
+        assert!(vp
+            .borrow_mut()
+            .accept_protocol(Pubkey::new_unique())
+            .is_err());
+        assert_eq!(
+            vp.borrow_mut().accept_protocol(new_protocol).unwrap(),
+            old_protocol
+        );
+        assert_eq!(vp.borrow().protocol, new_protocol);
+        assert_eq!(vp.borrow().pending_protocol, Pubkey::default());
+        assert_eq!(
+            vault.get_protocol_shares(&mut Some(vp.borrow_mut())),
+            protocol_shares
+        );
+
+        // renounce zeroes fees and requests a withdraw of all protocol shares
+        assert!(vp.borrow().validate_can_remove().is_err());
+        vault
+            .protocol_renounce(
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
+            .unwrap();
+        assert_eq!(vp.borrow().protocol_fee, 0);
+        assert_eq!(vp.borrow().protocol_profit_share, 0);
+        assert_eq!(
+            vp.borrow().last_protocol_withdraw_request.shares,
+            protocol_shares
+        );
+        assert!(vp.borrow().validate_can_remove().is_err());
+
+        let withdraw_amount = vault
+            .protocol_withdraw(
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + ONE_YEAR as i64 + 60,
+            )
+            .unwrap();
+        assert!(withdraw_amount > 0);
+        assert_eq!(vault.get_protocol_shares(&mut Some(vp.borrow_mut())), 0);
+        vp.borrow().validate_can_remove().unwrap();
+    }
+
+    #[test]
+    fn test_protocol_attached_to_existing_vault() {
+        use crate::constants::MIN_PARAMS_TIMELOCK;
+
+        let now = 0;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.redeem_period = 60;
+
+        // a legacy depositor joined without protocol fees
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(amount, 0, &mut vault, &mut None, now).unwrap();
+        let vault_equity = amount;
+
+        let effective_ts = vp
+            .borrow_mut()
+            .propose_initial_params(&vault, 500, 100_000, 0, 0, now)
+            .unwrap();
+        assert_eq!(effective_ts, now + MIN_PARAMS_TIMELOCK);
+        assert_eq!(vp.borrow().protocol_fee, 0);
+        assert_eq!(vp.borrow().protocol_profit_share, 0);
+        assert_eq!(vp.borrow().pending_protocol_fee, 500);
+        assert_eq!(vp.borrow().pending_protocol_profit_share, 100_000);
+
+        // the depositor can exit before the protocol fees apply
+        vd.request_withdraw(
+            amount,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &mut Some(vp.borrow_mut()),
+            now,
+        )
+        .unwrap();
+        vault
+            .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, effective_ts - 1)
+            .unwrap();
+        assert_eq!(vp.borrow().protocol_fee, 0);
+        assert_eq!(vault.get_protocol_shares(&mut Some(vp.borrow_mut())), 0);
+        let withdraw_amount = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &mut Some(vp.borrow_mut()),
+                effective_ts - 1,
+            )
+            .unwrap()
+            .0;
+        assert_eq!(withdraw_amount, amount);
+
+        // a longer redeem period pushes the fees further out
+        vault.redeem_period = MIN_PARAMS_TIMELOCK * 2;
+        let vp = RefCell::new(VaultProtocol::default());
+        let effective_ts = vp
+            .borrow_mut()
+            .propose_initial_params(&vault, 500, 0, 0, 0, now)
+            .unwrap();
+        assert_eq!(effective_ts, now + MIN_PARAMS_TIMELOCK * 2);
+
+        vault
+            .apply_fee(&mut Some(vp.borrow_mut()), 0, effective_ts)
+            .unwrap();
+        assert_eq!(vp.borrow().protocol_fee, 500);
+        assert_eq!(vp.borrow().pending_params_effective_ts, 0);
+    }
 }
 
 #[cfg(test)]
@@ -3120,3 +3817,201 @@ mod full_vault_withdraw_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod sub_account_tests {
+    use anchor_lang::prelude::{AccountInfo, AccountLoader, Pubkey};
+    use anchor_lang::Discriminator;
+    use drift::math::constants::{
+        PRICE_PRECISION_I64, QUOTE_PRECISION_U64, SPOT_BALANCE_PRECISION_U64,
+        SPOT_CUMULATIVE_INTEREST_PRECISION,
+    };
+    use drift::state::oracle::{HistoricalOracleData, OracleSource};
+    use drift::state::oracle_map::OracleMap;
+    use drift::state::perp_market_map::PerpMarketMap;
+    use drift::state::spot_market::SpotMarket;
+    use drift::state::spot_market_map::SpotMarketMap;
+    use drift::state::user::User;
+
+    use crate::state::{Vault, VaultEquityAccounts};
+
+    fn account_bytes<T: bytemuck::Pod + Discriminator>(account: &T) -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(account));
+        data
+    }
+
+    fn sub_account(authority: Pubkey, sub_account_id: u16, deposit: u64) -> User {
+        let mut user = User {
+            authority,
+            sub_account_id,
+            ..User::default()
+        };
+        user.spot_positions[0].scaled_balance =
+            deposit * SPOT_BALANCE_PRECISION_U64 / QUOTE_PRECISION_U64;
+        user
+    }
+
+    /// Runs `f` with `users` loaded as drift user accounts
+    fn with_sub_accounts<R>(users: &[User], f: impl FnOnce(&[AccountLoader<User>]) -> R) -> R {
+        let keys: Vec<Pubkey> = users.iter().map(|_| Pubkey::new_unique()).collect();
+        let mut lamports = vec![0_u64; users.len()];
+        let mut data: Vec<Vec<u8>> = users.iter().map(account_bytes).collect();
+        let account_infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, false, lamports, data, &drift::ID, false, 0)
+            })
+            .collect();
+        let sub_accounts: Vec<AccountLoader<User>> = account_infos
+            .iter()
+            .map(|account_info| AccountLoader::try_from(account_info).unwrap())
+            .collect();
+
+        f(&sub_accounts)
+    }
+
+    #[test]
+    fn test_validate_sub_accounts() {
+        let vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            sub_account_count: 3,
+            ..Vault::default()
+        };
+        let validate = |users: &[User]| {
+            with_sub_accounts(users, |sub_accounts| {
+                vault.validate_sub_accounts(sub_accounts)
+            })
+        };
+
+        validate(&[
+            sub_account(vault.pubkey, 1, 0),
+            sub_account(vault.pubkey, 2, 0),
+        ])
+        .unwrap();
+        validate(&[
+            sub_account(vault.pubkey, 2, 0),
+            sub_account(vault.pubkey, 1, 0),
+        ])
+        .unwrap();
+
+        // every sub-account must be passed, and only those
+        assert!(validate(&[sub_account(vault.pubkey, 1, 0)]).is_err());
+        assert!(validate(&[
+            sub_account(vault.pubkey, 1, 0),
+            sub_account(vault.pubkey, 2, 0),
+            sub_account(vault.pubkey, 3, 0),
+        ])
+        .is_err());
+        // the same sub-account can't be counted twice
+        assert!(validate(&[
+            sub_account(vault.pubkey, 1, 0),
+            sub_account(vault.pubkey, 1, 0)
+        ])
+        .is_err());
+        // the primary user is counted separately
+        assert!(validate(&[
+            sub_account(vault.pubkey, 0, 0),
+            sub_account(vault.pubkey, 1, 0)
+        ])
+        .is_err());
+        assert!(validate(&[
+            sub_account(vault.pubkey, 1, 0),
+            sub_account(vault.pubkey, 3, 0)
+        ])
+        .is_err());
+        assert!(validate(&[
+            sub_account(vault.pubkey, 1, 0),
+            sub_account(Pubkey::new_unique(), 2, 0),
+        ])
+        .is_err());
+
+        // a vault without sub-accounts takes none
+        let vault = Vault::default();
+        vault.validate_sub_accounts(&[]).unwrap();
+        assert!(
+            with_sub_accounts(&[sub_account(vault.pubkey, 1, 0)], |sub_accounts| {
+                vault.validate_sub_accounts(sub_accounts)
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_calculate_equity_sums_sub_accounts() {
+        let vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            sub_account_count: 3,
+            ..Vault::default()
+        };
+
+        let spot_market = SpotMarket {
+            market_index: 0,
+            oracle_source: OracleSource::QuoteAsset,
+            decimals: 6,
+            cumulative_deposit_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            cumulative_borrow_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            historical_oracle_data: HistoricalOracleData {
+                last_oracle_price_twap: PRICE_PRECISION_I64,
+                last_oracle_price_twap_5min: PRICE_PRECISION_I64,
+                ..HistoricalOracleData::default()
+            },
+            ..SpotMarket::default()
+        };
+        let spot_market_key = Pubkey::new_unique();
+        let mut spot_market_lamports = 0;
+        let mut spot_market_data = account_bytes(&spot_market);
+        let spot_market_account_info = AccountInfo::new(
+            &spot_market_key,
+            false,
+            false,
+            &mut spot_market_lamports,
+            &mut spot_market_data,
+            &drift::ID,
+            false,
+            0,
+        );
+        let spot_market_map = SpotMarketMap::load_one(&spot_market_account_info, false).unwrap();
+        let perp_market_map = PerpMarketMap::empty();
+        let mut oracle_map = OracleMap::empty();
+
+        let user = sub_account(vault.pubkey, 0, 100 * QUOTE_PRECISION_U64);
+        let sub_accounts = [
+            sub_account(vault.pubkey, 1, 200 * QUOTE_PRECISION_U64),
+            sub_account(vault.pubkey, 2, 300 * QUOTE_PRECISION_U64),
+        ];
+        let vault_equity = with_sub_accounts(&sub_accounts, |sub_accounts| {
+            vault.calculate_equity(
+                &user,
+                &VaultEquityAccounts {
+                    sub_accounts: sub_accounts.to_vec(),
+                    insurance_fund_stake: None,
+                    token_accounts: vec![],
+                },
+                &perp_market_map,
+                &spot_market_map,
+                &mut oracle_map,
+            )
+        })
+        .unwrap();
+        assert_eq!(vault_equity, 600 * QUOTE_PRECISION_U64);
+
+        // equity can't be computed without every sub-account
+        let vault_equity = with_sub_accounts(&sub_accounts[..1], |sub_accounts| {
+            vault.calculate_equity(
+                &user,
+                &VaultEquityAccounts {
+                    sub_accounts: sub_accounts.to_vec(),
+                    insurance_fund_stake: None,
+                    token_accounts: vec![],
+                },
+                &perp_market_map,
+                &spot_market_map,
+                &mut oracle_map,
+            )
+        });
+        assert!(vault_equity.is_err());
+    }
+}
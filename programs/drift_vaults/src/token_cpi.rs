@@ -11,3 +11,7 @@ pub trait BurnTokensCPI {
 pub trait TokenTransferCPI {
     fn token_transfer(&self, amount: u64) -> Result<()>;
 }
+
+pub trait CloseTokenAccountCPI {
+    fn close_token_account(&self) -> Result<()>;
+}
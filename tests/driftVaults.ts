@@ -180,6 +180,7 @@ describe('driftVaults', () => {
 
 	let _delegate: Keypair;
 	let delegateClient: VaultClient;
+	let delegateUSDCAccount: PublicKey;
 	let _delegateUser: User;
 
 	const vaultName = 'crisp vault';
@@ -237,6 +238,7 @@ describe('driftVaults', () => {
 		});
 		_delegate = bootstrapDelegate.signer;
 		delegateClient = bootstrapDelegate.vaultClient;
+		delegateUSDCAccount = bootstrapDelegate.userUSDCAccount.publicKey;
 		_delegateUser = bootstrapDelegate.user;
 
 		// the VaultDepositor for the vault
@@ -335,19 +337,33 @@ describe('driftVaults', () => {
 			writableSpotMarketIndexes: [0],
 		});
 
+		const depositAccounts = {
+			userTokenAccount: vd2UserUSDCAccount,
+			vault,
+			vaultDepositor,
+			vaultTokenAccount: vaultAccount.tokenAccount,
+			driftUser: vaultAccount.user,
+			driftUserStats: vaultAccount.userStats,
+			driftState: await adminClient.getStatePublicKey(),
+			driftSpotMarketVault: adminClient.getSpotMarketAccount(0).vault,
+			driftProgram: adminClient.program.programId,
+		};
+
+		// the first deposit mints one share per token
+		try {
+			await vd2Client.program.methods
+				.deposit(usdcAmount, usdcAmount.addn(1))
+				.accounts(depositAccounts)
+				.remainingAccounts(remainingAccounts)
+				.rpc();
+			assert(false, 'deposit should have exceeded min_shares_out');
+		} catch (e) {
+			assert(e.toString().includes('SlippageExceeded'), e.toString());
+		}
+
 		await vd2Client.program.methods
-			.deposit(usdcAmount)
-			.accounts({
-				userTokenAccount: vd2UserUSDCAccount,
-				vault,
-				vaultDepositor,
-				vaultTokenAccount: vaultAccount.tokenAccount,
-				driftUser: vaultAccount.user,
-				driftUserStats: vaultAccount.userStats,
-				driftState: await adminClient.getStatePublicKey(),
-				driftSpotMarketVault: adminClient.getSpotMarketAccount(0).vault,
-				driftProgram: adminClient.program.programId,
-			})
+			.deposit(usdcAmount, usdcAmount)
+			.accounts(depositAccounts)
 			.remainingAccounts(remainingAccounts)
 			.rpc();
 
@@ -379,14 +395,34 @@ describe('driftVaults', () => {
 
 		// request withdraw
 		console.log('request withdraw');
+		const requestAccounts = {
+			vault,
+			vaultDepositor,
+			driftUser: vaultAccount.user,
+			driftUserStats: vaultAccount.userStats,
+		};
+		try {
+			await vd2Client.program.methods
+				.requestWithdraw(
+					usdcAmount,
+					WithdrawUnit.TOKEN,
+					vaultDepositorAccount.vaultShares.subn(1)
+				)
+				.accounts(requestAccounts)
+				.remainingAccounts(remainingAccounts)
+				.rpc();
+			assert(false, 'request withdraw should have exceeded max_shares_burned');
+		} catch (e) {
+			assert(e.toString().includes('SlippageExceeded'), e.toString());
+		}
+
 		const requestTxSig = await vd2Client.program.methods
-			.requestWithdraw(usdcAmount, WithdrawUnit.TOKEN)
-			.accounts({
-				vault,
-				vaultDepositor,
-				driftUser: vaultAccount.user,
-				driftUserStats: vaultAccount.userStats,
-			})
+			.requestWithdraw(
+				usdcAmount,
+				WithdrawUnit.TOKEN,
+				vaultDepositorAccount.vaultShares
+			)
+			.accounts(requestAccounts)
 			.remainingAccounts(remainingAccounts)
 			.rpc();
 
@@ -406,29 +442,140 @@ describe('driftVaults', () => {
 
 		// do withdraw
 		console.log('do withdraw');
+		const withdrawAccounts = {
+			userTokenAccount: vd2UserUSDCAccount,
+			vault,
+			vaultDepositor,
+			vaultTokenAccount: vaultAccount.tokenAccount,
+			driftUser: vaultAccount.user,
+			driftUserStats: vaultAccount.userStats,
+			driftState: await adminClient.getStatePublicKey(),
+			driftSpotMarketVault: adminClient.getSpotMarketAccount(0).vault,
+			driftSigner: adminClient.getStateAccount().signer,
+			driftProgram: adminClient.program.programId,
+		};
+		try {
+			await vd2Client.program.methods
+				.withdraw(vaultDepositorAccountAfter.lastWithdrawRequest.value.addn(1))
+				.accounts(withdrawAccounts)
+				.remainingAccounts(remainingAccounts)
+				.rpc();
+			assert(false, 'withdraw should have exceeded min_tokens_out');
+		} catch (e) {
+			assert(e.toString().includes('SlippageExceeded'), e.toString());
+		}
+
 		try {
 			const txSig = await vd2Client.program.methods
-				.withdraw()
+				.withdraw(vaultDepositorAccountAfter.lastWithdrawRequest.value)
+				.accounts(withdrawAccounts)
+				.remainingAccounts(remainingAccounts)
+				.rpc();
+
+			await printTxLogs(provider.connection, txSig);
+		} catch (e) {
+			console.error(e);
+			assert(false);
+		}
+	});
+
+	it('Deposit For', async () => {
+		const vaultAccount = await program.account.vault.fetch(vault);
+		const remainingAccounts = delegateClient.driftClient.getRemainingAccounts({
+			userAccounts: [],
+			writableSpotMarketIndexes: [0],
+		});
+		const driftState = await adminClient.getStatePublicKey();
+		const depositFor = (authority: PublicKey, amount: BN) =>
+			delegateClient.program.methods
+				.depositFor(amount, null)
 				.accounts({
-					userTokenAccount: vd2UserUSDCAccount,
 					vault,
-					vaultDepositor,
+					vaultDepositor: getVaultDepositorAddressSync(
+						program.programId,
+						vault,
+						authority
+					),
+					authority,
+					funder: delegateClient.driftClient.wallet.publicKey,
 					vaultTokenAccount: vaultAccount.tokenAccount,
-					driftUser: vaultAccount.user,
 					driftUserStats: vaultAccount.userStats,
-					driftState: await adminClient.getStatePublicKey(),
+					driftUser: vaultAccount.user,
+					driftState,
 					driftSpotMarketVault: adminClient.getSpotMarketAccount(0).vault,
-					driftSigner: adminClient.getStateAccount().signer,
+					funderTokenAccount: delegateUSDCAccount,
 					driftProgram: adminClient.program.programId,
 				})
 				.remainingAccounts(remainingAccounts)
 				.rpc();
+		const vaultDepositor = getVaultDepositorAddressSync(
+			program.programId,
+			vault,
+			vd2.publicKey
+		);
+		const amount = usdcAmount.divn(10);
 
-			await printTxLogs(provider.connection, txSig);
+		// the funder pays, the shares go to vd2
+		const vdBefore = await program.account.vaultDepositor.fetch(vaultDepositor);
+		const funderBalanceBefore =
+			await provider.connection.getTokenAccountBalance(delegateUSDCAccount);
+		await depositFor(vd2.publicKey, amount);
+		const vdAfter = await program.account.vaultDepositor.fetch(vaultDepositor);
+		const funderBalanceAfter =
+			await provider.connection.getTokenAccountBalance(delegateUSDCAccount);
+		assert(vdAfter.totalDeposits.sub(vdBefore.totalDeposits).eq(amount));
+		assert(vdAfter.vaultShares.gt(vdBefore.vaultShares));
+		assert(
+			new BN(funderBalanceBefore.value.amount)
+				.sub(new BN(funderBalanceAfter.value.amount))
+				.eq(amount)
+		);
+
+		// a frozen depositor can't be funded
+		await managerClient.program.methods
+			.updateVaultDepositorFrozen(true)
+			.accounts({ vault, vaultDepositor })
+			.rpc();
+		try {
+			await depositFor(vd2.publicKey, amount);
+			assert(false, 'deposit for a frozen depositor should have failed');
 		} catch (e) {
-			console.error(e);
-			assert(false);
+			assert(e.toString().includes('VaultDepositorFrozen'), e.toString());
 		}
+		await managerClient.program.methods
+			.updateVaultDepositorFrozen(false)
+			.accounts({ vault, vaultDepositor })
+			.rpc();
+
+		// funding needs an existing depositor, so a permissioned vault still controls who holds shares
+		await managerClient.managerUpdateVault(vault, { permissioned: true });
+		try {
+			await depositFor(Keypair.generate().publicKey, amount);
+			assert(
+				false,
+				'deposit for an authority without a depositor should have failed'
+			);
+		} catch (e) {
+			assert(e.toString().includes('AccountNotInitialized'), e.toString());
+		}
+		await managerClient.managerUpdateVault(vault, { permissioned: false });
+
+		// a token gated vault checks the depositor authority holds the access token, not the funder
+		await managerClient.managerUpdateVault(vault, {
+			accessMint: Keypair.generate().publicKey,
+		});
+		try {
+			await depositFor(vd2.publicKey, amount);
+			assert(
+				false,
+				'deposit for a depositor without the access token should have failed'
+			);
+		} catch (e) {
+			assert(e.toString().includes('PermissionedVault'), e.toString());
+		}
+		await managerClient.managerUpdateVault(vault, {
+			accessMint: PublicKey.default,
+		});
 	});
 
 	it('Update Delegate', async () => {
@@ -679,6 +826,8 @@ describe('TestProtocolVaults', () => {
 			protocolFee: new BN(0),
 			// 100_000 = 10%
 			protocolProfitShare: 100_000,
+			protocolEntryFee: 0,
+			protocolExitFee: 0,
 		};
 		await managerClient.initializeVault({
 			name: encodeName(protocolVaultName),
@@ -761,7 +910,7 @@ describe('TestProtocolVaults', () => {
 		}
 
 		await vdClient.program.methods
-			.deposit(usdcAmount)
+			.deposit(usdcAmount, null)
 			.accounts({
 				vault: protocolVault,
 				vaultDepositor,
@@ -1183,7 +1332,7 @@ describe('TestProtocolVaults', () => {
 
 		try {
 			await vdClient.program.methods
-				.requestWithdraw(withdrawAmount, WithdrawUnit.TOKEN)
+				.requestWithdraw(withdrawAmount, WithdrawUnit.TOKEN, null)
 				.accounts({
 					vault: protocolVault,
 					vaultDepositor,
@@ -1225,7 +1374,7 @@ describe('TestProtocolVaults', () => {
 			const vaultAccount = await program.account.vault.fetch(protocolVault);
 
 			await vdClient.program.methods
-				.withdraw()
+				.withdraw(null)
 				.accounts({
 					userTokenAccount: vdUserUSDCAccount,
 					vault: protocolVault,
@@ -3219,6 +3368,8 @@ describe('TestWithdrawFromVaults', () => {
 				protocolFee: new BN(0),
 				// 100_000 = 10%
 				protocolProfitShare: 100_000,
+				protocolEntryFee: 0,
+				protocolExitFee: 0,
 			};
 
 			await managerClient.initializeVault({
@@ -3378,7 +3529,7 @@ describe('TestWithdrawFromVaults', () => {
 				isWritable: true,
 			});
 			const txSig = await vd0Client.program.methods
-				.withdraw()
+				.withdraw(null)
 				.accounts({
 					userTokenAccount: vd0UsdcAccount,
 					vault: commonVaultKey,
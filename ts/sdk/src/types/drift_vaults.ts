@@ -164,7 +164,7 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'updateDelegate';
+			name: 'initializeVaultProtocol';
 			accounts: [
 				{
 					name: 'vault';
@@ -172,75 +172,43 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftUser';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'delegate';
-					type: 'publicKey';
-				}
-			];
-		},
-		{
-			name: 'updateMarginTradingEnabled';
-			accounts: [
-				{
-					name: 'vault';
-					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'manager';
+					name: 'protocol';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'enabled';
-					type: 'bool';
-				}
-			];
-		},
-		{
-			name: 'updateVaultProtocol';
-			accounts: [
+				},
 				{
-					name: 'vault';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'protocol';
+					name: 'rent';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
-					isMut: true;
+					name: 'systemProgram';
+					isMut: false;
 					isSigner: false;
 				}
 			];
@@ -248,13 +216,13 @@ export type DriftVaults = {
 				{
 					name: 'params';
 					type: {
-						defined: 'UpdateVaultProtocolParams';
+						defined: 'VaultProtocolParams';
 					};
 				}
 			];
 		},
 		{
-			name: 'updateVault';
+			name: 'initializeVaultSubAccount';
 			accounts: [
 				{
 					name: 'vault';
@@ -265,33 +233,20 @@ export type DriftVaults = {
 					name: 'manager';
 					isMut: false;
 					isSigner: true;
-				}
-			];
-			args: [
-				{
-					name: 'params';
-					type: {
-						defined: 'UpdateVaultParams';
-					};
-				}
-			];
-		},
-		{
-			name: 'initializeVaultDepositor';
-			accounts: [
+				},
 				{
-					name: 'vault';
-					isMut: false;
+					name: 'driftUserStats';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
+					name: 'driftState';
+					isMut: true;
 					isSigner: false;
 				},
 				{
@@ -308,12 +263,17 @@ export type DriftVaults = {
 					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'initializeTokenizedVaultDepositor';
+			name: 'transferSubAccountDeposit';
 			accounts: [
 				{
 					name: 'vault';
@@ -321,57 +281,54 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'mintAccount';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'metadataAccount';
+					name: 'fromUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'payer';
+					name: 'toUser';
 					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: 'tokenProgram';
-					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenMetadataProgram';
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'rent';
+					name: 'driftSpotMarketVault';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'systemProgram';
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'params';
-					type: {
-						defined: 'InitializeTokenizedVaultDepositorParams';
-					};
+					name: 'marketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'amount';
+					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'tokenizeShares';
+			name: 'updateDelegate';
 			accounts: [
 				{
 					name: 'vault';
@@ -379,56 +336,30 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'authority';
+					name: 'manager';
 					isMut: false;
 					isSigner: true;
 				},
-				{
-					name: 'tokenizedVaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'mint';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'userTokenAccount';
-					isMut: false;
-					isSigner: false;
-				},
 				{
 					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
-					type: 'u64';
-				},
-				{
-					name: 'unit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
+					name: 'delegate';
+					type: 'publicKey';
 				}
 			];
 		},
 		{
-			name: 'redeemTokens';
+			name: 'updateManagerWithdrawDestination';
 			accounts: [
 				{
 					name: 'vault';
@@ -436,34 +367,35 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'authority';
+					name: 'manager';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'tokenizedVaultDepositor';
-					isMut: true;
+					name: 'vaultTokenAccount';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'mint';
-					isMut: true;
+					name: 'withdrawDestination';
+					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateMarginTradingEnabled';
+			accounts: [
 				{
-					name: 'userTokenAccount';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
-					isMut: true;
-					isSigner: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
 					name: 'driftUser';
@@ -471,20 +403,20 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'tokensToBurn';
-					type: 'u64';
+					name: 'enabled';
+					type: 'bool';
 				}
 			];
 		},
 		{
-			name: 'deposit';
+			name: 'updateVaultProtocol';
 			accounts: [
 				{
 					name: 'vault';
@@ -492,69 +424,82 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'authority';
+					name: 'protocol';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
-				},
+				}
+			];
+			args: [
 				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
-				},
+					name: 'params';
+					type: {
+						defined: 'UpdateVaultProtocolParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'updateVault';
+			accounts: [
 				{
-					name: 'driftUser';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
-				},
+					isSigner: true;
+				}
+			];
+			args: [
 				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
+					name: 'params';
+					type: {
+						defined: 'UpdateVaultParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'updateVaultDepositorLimits';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'tokenProgram';
-					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
-					type: 'u64';
+					name: 'params';
+					type: {
+						defined: 'UpdateVaultDepositorLimitsParams';
+					};
 				}
 			];
 		},
 		{
-			name: 'requestWithdraw';
+			name: 'updateVaultDepositorFrozen';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -563,40 +508,24 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'manager';
 					isMut: false;
 					isSigner: true;
-				},
-				{
-					name: 'driftUserStats';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
-					isMut: false;
-					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'withdrawAmount';
-					type: 'u64';
-				},
-				{
-					name: 'withdrawUnit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
+					name: 'frozen';
+					type: 'bool';
 				}
 			];
 		},
 		{
-			name: 'cancelRequestWithdraw';
+			name: 'closeVaultDepositor';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -605,29 +534,25 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftUserStats';
-					isMut: false;
+					name: 'withdrawRequestSlots';
+					isMut: true;
 					isSigner: false;
+					isOptional: true;
 				},
 				{
-					name: 'driftUser';
-					isMut: false;
-					isSigner: false;
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'withdraw';
+			name: 'managerCloseVaultDepositor';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -636,42 +561,60 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'vaultTokenAccount';
+					name: 'depositorAuthority';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'withdrawRequestSlots';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
 				},
 				{
-					name: 'driftUser';
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'proposeVaultManager';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
-				},
+					isSigner: true;
+				}
+			];
+			args: [
 				{
-					name: 'driftSpotMarketVault';
+					name: 'newManager';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'acceptVaultManager';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
+					name: 'newManager';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
@@ -679,74 +622,59 @@ export type DriftVaults = {
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
-				},
-				{
-					name: 'tokenProgram';
-					isMut: false;
-					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'liquidate';
+			name: 'proposeVaultProtocol';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'protocol';
 					isMut: false;
 					isSigner: true;
-				},
-				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
-					isMut: true;
-					isSigner: false;
-				},
+				}
+			];
+			args: [
 				{
-					name: 'driftProgram';
-					isMut: false;
-					isSigner: false;
+					name: 'newProtocol';
+					type: 'publicKey';
 				}
 			];
-			args: [];
 		},
 		{
-			name: 'resetDelegate';
+			name: 'acceptVaultProtocol';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'newProtocol';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'managerDeposit';
+			name: 'renounceVaultProtocol';
 			accounts: [
 				{
 					name: 'vault';
@@ -754,97 +682,102 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'vaultTokenAccount';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'removeVaultProtocol';
+			accounts: [
 				{
-					name: 'driftSpotMarketVault';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
-					isMut: false;
+					name: 'protocol';
+					isMut: true;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'proposeVaultParams';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
-					type: 'u64';
+					name: 'params';
+					type: {
+						defined: 'ProposeVaultParamsParams';
+					};
 				}
 			];
 		},
 		{
-			name: 'managerRequestWithdraw';
+			name: 'proposeVaultProtocolParams';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'manager';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: false;
+					name: 'vaultProtocol';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'protocol';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				}
 			];
 			args: [
 				{
-					name: 'withdrawAmount';
-					type: 'u64';
-				},
-				{
-					name: 'withdrawUnit';
+					name: 'params';
 					type: {
-						defined: 'WithdrawUnit';
+						defined: 'ProposeVaultProtocolParamsParams';
 					};
 				}
 			];
 		},
 		{
-			name: 'mangerCancelWithdrawRequest';
+			name: 'migrateVault';
 			accounts: [
 				{
 					name: 'vault';
@@ -852,17 +785,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
+					name: 'payer';
+					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
@@ -870,60 +798,46 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'managerWithdraw';
+			name: 'migrateVaultProtocol';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'manager';
 					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'vaultTokenAccount';
-					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'vaultProtocol';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftState';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'migrateVaultDepositor';
+			accounts: [
 				{
-					name: 'driftSpotMarketVault';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'userTokenAccount';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftProgram';
-					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
@@ -931,11 +845,11 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'applyProfitShare';
+			name: 'initializeVaultDepositor';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -944,32 +858,22 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'authority';
 					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftUserStats';
-					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftState';
-					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftSigner';
+					name: 'rent';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
@@ -977,11 +881,11 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'applyRebase';
+			name: 'initializeVaultDepositorWithProof';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -990,46 +894,79 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
 					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'maxEquity';
+					type: 'u64';
+				},
+				{
+					name: 'proof';
+					type: {
+						vec: {
+							array: ['u8', 32];
+						};
+					};
+				}
+			];
 		},
 		{
-			name: 'applyRebaseTokenizedDepositor';
+			name: 'updateWithdrawDestination';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenizedVaultDepositor';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'withdrawDestination';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'forceWithdraw';
+			name: 'initializeTokenizedVaultDepositor';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'manager';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
 					name: 'vaultDepositor';
@@ -1037,55 +974,52 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'mintAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'metadataAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftState';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftSigner';
+					name: 'tokenMetadataProgram';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftProgram';
+					name: 'rent';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorParams';
+					};
+				}
+			];
 		},
 		{
-			name: 'initializeInsuranceFundStake';
+			name: 'tokenizeShares';
 			accounts: [
 				{
 					name: 'vault';
@@ -1093,75 +1027,112 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'payer';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'rent';
+					name: 'mint';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketMint';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'unit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
+		},
+		{
+			name: 'redeemTokens';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
-					isMut: false;
+					name: 'mint';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
-					isMut: false;
+					name: 'userTokenAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
-					isMut: false;
+					name: 'vaultTokenAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'systemProgram';
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'tokensToBurn';
+					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'addInsuranceFundStake';
+			name: 'deposit';
 			accounts: [
 				{
 					name: 'vault';
@@ -1169,53 +1140,43 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: 'driftSpotMarket';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
-					isSigner: false;
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'managerTokenAccount';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultIfTokenAccount';
-					isMut: true;
+					name: 'driftState';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'driftSpotMarketVault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'driftSigner';
-					isMut: false;
+					name: 'userTokenAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
@@ -1230,18 +1191,20 @@ export type DriftVaults = {
 				}
 			];
 			args: [
-				{
-					name: 'marketIndex';
-					type: 'u16';
-				},
 				{
 					name: 'amount';
 					type: 'u64';
+				},
+				{
+					name: 'minSharesOut';
+					type: {
+						option: 'u128';
+					};
 				}
 			];
 		},
 		{
-			name: 'requestRemoveInsuranceFundStake';
+			name: 'depositFor';
 			accounts: [
 				{
 					name: 'vault';
@@ -1249,22 +1212,22 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftSpotMarket';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
-					isMut: true;
+					name: 'authority';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'funder';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1273,25 +1236,52 @@ export type DriftVaults = {
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'funderTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [
-				{
-					name: 'marketIndex';
-					type: 'u16';
-				},
 				{
 					name: 'amount';
 					type: 'u64';
+				},
+				{
+					name: 'minSharesOut';
+					type: {
+						option: 'u128';
+					};
 				}
 			];
 		},
 		{
-			name: 'removeInsuranceFundStake';
+			name: 'requestWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1299,32 +1289,101 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				},
+				{
+					name: 'maxSharesBurned';
+					type: {
+						option: 'u128';
+					};
+				}
+			];
+		},
+		{
+			name: 'cancelRequestWithdraw';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'settleWithdrawEpoch';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'managerTokenAccount';
+					name: 'withdrawEpoch';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultIfTokenAccount';
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1333,11 +1392,21 @@ export type DriftVaults = {
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftSigner';
 					isMut: false;
@@ -1352,17 +1421,22 @@ export type DriftVaults = {
 					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'amount';
+					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'cancelRequestRemoveInsuranceFundStake';
+			name: 'claimWithdrawEpoch';
 			accounts: [
 				{
 					name: 'vault';
@@ -1370,27 +1444,47 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'withdrawEpoch';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1398,17 +1492,17 @@ export type DriftVaults = {
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
-				}
-			];
-			args: [
+				},
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
+			args: [];
 		},
 		{
-			name: 'protocolRequestWithdraw';
+			name: 'withdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1416,72 +1510,72 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'protocol';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUserStats';
-					isMut: false;
+					isMut: true;
 					isSigner: false;
 				},
 				{
 					name: 'driftUser';
-					isMut: false;
+					isMut: true;
 					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'withdrawAmount';
-					type: 'u64';
 				},
 				{
-					name: 'withdrawUnit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
-				}
-			];
-		},
-		{
-			name: 'protocolCancelWithdrawRequest';
-			accounts: [
-				{
-					name: 'vault';
-					isMut: true;
+					name: 'driftState';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
+					name: 'driftSpotMarketVault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'protocol';
+					name: 'driftSigner';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'minTokensOut';
+					type: {
+						option: 'u64';
+					};
+				}
+			];
 		},
 		{
-			name: 'protocolWithdraw';
+			name: 'partialWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1489,12 +1583,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'protocol';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
@@ -1544,2815 +1638,8637 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [];
-		}
-	];
-	accounts: [
+			args: [
+				{
+					name: 'minTokensOut';
+					type: {
+						option: 'u64';
+					};
+				}
+			];
+		},
 		{
-			name: 'tokenizedVaultDepositor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'vault';
-						docs: ['The vault deposited into'];
-						type: 'publicKey';
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'mint';
-						docs: [
-							'The token mint for tokenized shares owned by this VaultDepositor'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'vaultShares';
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastVaultShares';
-						docs: [
-							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
-							'CPI is done, used to track invariants'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastValidTs';
-						docs: ['creation ts of vault depositor'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['lifetime net deposits of vault depositor for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['lifetime total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['lifetime total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'cumulativeProfitShareAmount';
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'profitShareFeePaid';
-						type: 'u64';
-					},
-					{
-						name: 'vaultSharesBase';
-						docs: [
-							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
-							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
-							'is possible.'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'bump';
-						docs: ['The bump for the vault pda'];
-						type: 'u8';
-					},
-					{
-						name: 'padding1';
-						type: {
-							array: ['u8', 3];
-						};
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 10];
-						};
-					}
-				];
-			};
-		},
-		{
-			name: 'vaultDepositor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'vault';
-						docs: ['The vault deposited into'];
-						type: 'publicKey';
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault and authority"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'authority';
-						docs: [
-							'The authority is the address w permission to deposit/withdraw'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'vaultShares';
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastWithdrawRequest';
-						docs: ['last withdraw request'];
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'lastValidTs';
-						docs: ['creation ts of vault depositor'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['lifetime net deposits of vault depositor for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['lifetime total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['lifetime total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'cumulativeProfitShareAmount';
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'profitShareFeePaid';
-						type: 'u64';
-					},
-					{
-						name: 'vaultSharesBase';
-						docs: ['the exponent for vault_shares decimal places'];
-						type: 'u32';
-					},
-					{
-						name: 'padding1';
-						type: 'u32';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 8];
-						};
-					}
-				];
-			};
+			name: 'instantWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'minTokensOut';
+					type: {
+						option: 'u64';
+					};
+				}
+			];
 		},
 		{
-			name: 'vaultProtocol';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocol';
-						docs: [
-							'The protocol, company, or entity that services the product using this vault.',
-							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'protocolProfitAndFeeShares';
-						docs: [
-							'The shares from profit share and annual fee unclaimed by the protocol.'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'protocolFee';
-						docs: [
-							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
-							"Unlike the management fee this can't be negative."
-						];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalWithdraws';
-						docs: ['Total withdraws for the protocol'];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalFee';
-						docs: [
-							'Total fee charged by the protocol (annual management fee + profit share).',
-							"Unlike the management fee this can't be negative."
-						];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalProfitShare';
-						docs: ['Total profit share charged by the protocol'];
-						type: 'u64';
-					},
-					{
-						name: 'lastProtocolWithdrawRequest';
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'protocolProfitShare';
-						docs: [
-							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'bump';
-						type: 'u8';
-					},
-					{
-						name: 'version';
-						type: 'u8';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u8', 2];
-						};
-					}
-				];
-			};
+			name: 'initializeWithdrawRequestSlots';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'withdrawRequestSlots';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'vault';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						docs: [
-							'The name of the vault. Vault pubkey is derived from this name.'
-						];
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault's pubkey. It is a pda of name and also used as the authority for drift user"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'manager';
-						docs: [
-							'The manager of the vault who has ability to update vault params'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'tokenAccount';
-						docs: [
-							'The vaults token account. Used to receive tokens between deposits and withdrawals'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'userStats';
-						docs: ['The drift user stats account for the vault'];
-						type: 'publicKey';
-					},
-					{
-						name: 'user';
-						docs: ['The drift user account for the vault'];
-						type: 'publicKey';
-					},
-					{
-						name: 'delegate';
-						docs: [
-							'The vaults designated delegate for drift user account',
-							'can differ from actual user delegate if vault is in liquidation'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'liquidationDelegate';
-						docs: ['The delegate handling liquidation for depositor'];
-						type: 'publicKey';
-					},
-					{
-						name: 'userShares';
-						docs: [
-							'The sum of all shares held by the users (vault depositors)'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'totalShares';
-						docs: [
-							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
-							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastFeeUpdateTs';
-						docs: ['Last fee update unix timestamp'];
-						type: 'i64';
-					},
-					{
-						name: 'liquidationStartTs';
-						docs: ['When the liquidation starts'];
-						type: 'i64';
-					},
-					{
-						name: 'redeemPeriod';
-						docs: [
-							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
-							'Currently, the maximum is 90 days.'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'totalWithdrawRequested';
-						docs: ['The sum of all outstanding withdraw requests'];
-						type: 'u64';
-					},
-					{
-						name: 'maxTokens';
-						docs: [
-							'Max token capacity, once hit/passed vault will reject new deposits (updatable)'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						docs: [
-							'The annual fee charged on deposits by the manager.',
-							'Traditional funds typically charge 2% per year on assets under management.'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'initTs';
-						docs: ['Timestamp vault initialized'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['The net deposits for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'managerNetDeposits';
-						docs: ['The net deposits for the manager'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['Total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['Total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalDeposits';
-						docs: ['Total deposits for the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalWithdraws';
-						docs: ['Total withdraws for the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalFee';
-						docs: ['Total management fee accrued by the manager'];
-						type: 'i64';
-					},
-					{
-						name: 'managerTotalProfitShare';
-						docs: ['Total profit share accrued by the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'minDepositAmount';
-						docs: ['The minimum deposit amount'];
-						type: 'u64';
-					},
-					{
-						name: 'lastManagerWithdrawRequest';
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'sharesBase';
-						docs: [
-							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'profitShare';
-						docs: [
-							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						docs: [
-							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						docs: [
-							'The spot market index the vault deposits into/withdraws from'
-						];
-						type: 'u16';
-					},
-					{
-						name: 'bump';
-						docs: ['The bump for the vault pda'];
-						type: 'u8';
-					},
-					{
-						name: 'permissioned';
-						docs: ['Whether anybody can be a depositor'];
-						type: 'bool';
-					},
-					{
-						name: 'vaultProtocol';
-						docs: ['The optional [`VaultProtocol`] account.'];
-						type: 'bool';
-					},
-					{
-						name: 'padding1';
-						type: {
-							array: ['u8', 7];
-						};
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 7];
-						};
-					}
-				];
-			};
-		}
-	];
-	types: [
-		{
-			name: 'InitializeTokenizedVaultDepositorParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'tokenName';
-						type: 'string';
-					},
-					{
-						name: 'tokenSymbol';
-						type: 'string';
-					},
-					{
-						name: 'tokenUri';
-						type: 'string';
-					},
-					{
-						name: 'decimals';
-						type: 'u8';
-					}
-				];
-			};
-		},
-		{
-			name: 'VaultWithProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'redeemPeriod';
-						type: 'i64';
-					},
-					{
-						name: 'maxTokens';
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						type: 'i64';
-					},
-					{
-						name: 'minDepositAmount';
-						type: 'u64';
-					},
-					{
-						name: 'profitShare';
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'permissioned';
-						type: 'bool';
-					},
-					{
-						name: 'vaultProtocol';
-						type: {
-							defined: 'VaultProtocolParams';
-						};
-					}
-				];
-			};
+			name: 'requestWithdrawSlot';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'withdrawRequestSlots';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
 		},
 		{
-			name: 'VaultProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocol';
-						type: 'publicKey';
-					},
-					{
-						name: 'protocolFee';
-						type: 'u64';
-					},
-					{
-						name: 'protocolProfitShare';
-						type: 'u32';
-					}
-				];
-			};
+			name: 'cancelWithdrawRequestSlot';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'withdrawRequestSlots';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'slot';
+					type: 'u8';
+				}
+			];
 		},
 		{
-			name: 'VaultParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'redeemPeriod';
-						type: 'i64';
-					},
-					{
-						name: 'maxTokens';
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						type: 'i64';
-					},
-					{
-						name: 'minDepositAmount';
-						type: 'u64';
-					},
-					{
-						name: 'profitShare';
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'permissioned';
-						type: 'bool';
-					}
-				];
-			};
-		},
-		{
-			name: 'UpdateVaultProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocolFee';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'protocolProfitShare';
-						type: {
-							option: 'u32';
-						};
-					}
-				];
-			};
-		},
-		{
-			name: 'UpdateVaultParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'redeemPeriod';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'maxTokens';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'managementFee';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'minDepositAmount';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'profitShare';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'hurdleRate';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'permissioned';
-						type: {
-							option: 'bool';
-						};
-					}
-				];
-			};
-		},
-		{
-			name: 'WithdrawRequest';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'shares';
-						docs: ['request shares of vault withdraw'];
-						type: 'u128';
-					},
-					{
-						name: 'value';
-						docs: [
-							'requested value (in vault spot_market_index) of shares for withdraw'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'ts';
-						docs: ['request ts of vault withdraw'];
-						type: 'i64';
-					}
-				];
-			};
-		},
-		{
-			name: 'VaultDepositorAction';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Deposit';
-					},
-					{
-						name: 'WithdrawRequest';
-					},
-					{
-						name: 'CancelWithdrawRequest';
-					},
-					{
-						name: 'Withdraw';
-					},
-					{
-						name: 'FeePayment';
-					},
-					{
-						name: 'TokenizeShares';
-					},
-					{
-						name: 'RedeemTokens';
-					}
-				];
-			};
-		},
-		{
-			name: 'WithdrawUnit';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Shares';
-					},
-					{
-						name: 'Token';
-					},
-					{
-						name: 'SharesPercent';
-					}
-				];
-			};
-		}
-	];
-	events: [
-		{
-			name: 'VaultRecord';
-			fields: [
+			name: 'withdrawSlot';
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
-				}
-			];
-		},
-		{
-			name: 'VaultDepositorRecord';
-			fields: [
+					name: 'withdrawRequestSlots';
+					isMut: true;
+					isSigner: false;
+				},
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'action';
-					type: {
-						defined: 'VaultDepositorAction';
-					};
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'amount';
-					type: 'u64';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'vaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'slot';
+					type: 'u8';
 				},
 				{
-					name: 'totalVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'minTokensOut';
+					type: {
+						option: 'u64';
+					};
+				}
+			];
+		},
+		{
+			name: 'liquidate';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'totalVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'profitShare';
-					type: 'u64';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'managementFee';
-					type: 'i64';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'managementFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'liquidator';
+					type: {
+						option: 'publicKey';
+					};
 				}
 			];
 		},
 		{
-			name: 'VaultDepositorV1Record';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				},
+			name: 'resetDelegate';
+			accounts: [
 				{
 					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'action';
-					type: {
-						defined: 'VaultDepositorAction';
-					};
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'managerDeposit';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'amount';
-					type: 'u64';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'totalVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'totalVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'managerRequestWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'protocolSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'protocolSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'protocolProfitShare';
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
 					type: 'u64';
-					index: false;
 				},
 				{
-					name: 'protocolFee';
-					type: 'i64';
-					index: false;
-				},
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
+		},
+		{
+			name: 'mangerCancelWithdrawRequest';
+			accounts: [
 				{
-					name: 'protocolFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'managerProfitShare';
-					type: 'u64';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'managementFee';
-					type: 'i64';
-					index: false;
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'managementFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
 				}
 			];
+			args: [];
 		},
 		{
-			name: 'ShareTransferRecord';
-			fields: [
+			name: 'managerWithdraw';
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'fromVaultDepositor';
-					type: 'publicKey';
-					index: false;
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'toVaultDepositor';
-					type: 'publicKey';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'shares';
-					type: 'u128';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'value';
-					type: 'u64';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'fromDepositorSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'fromDepositorSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'toDepositorSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'toDepositorSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
-		}
-	];
-	errors: [
-		{
-			code: 6000;
-			name: 'Default';
-			msg: 'Default';
-		},
-		{
-			code: 6001;
-			name: 'InvalidVaultRebase';
-			msg: 'InvalidVaultRebase';
-		},
-		{
-			code: 6002;
-			name: 'InvalidVaultSharesDetected';
-			msg: 'InvalidVaultSharesDetected';
-		},
-		{
-			code: 6003;
-			name: 'CannotWithdrawBeforeRedeemPeriodEnd';
-			msg: 'CannotWithdrawBeforeRedeemPeriodEnd';
-		},
-		{
-			code: 6004;
-			name: 'InvalidVaultWithdraw';
-			msg: 'InvalidVaultWithdraw';
-		},
-		{
-			code: 6005;
-			name: 'InsufficientVaultShares';
-			msg: 'InsufficientVaultShares';
-		},
-		{
-			code: 6006;
-			name: 'InvalidVaultWithdrawSize';
-			msg: 'InvalidVaultWithdrawSize';
-		},
-		{
-			code: 6007;
-			name: 'InvalidVaultForNewDepositors';
-			msg: 'InvalidVaultForNewDepositors';
-		},
-		{
-			code: 6008;
-			name: 'VaultWithdrawRequestInProgress';
-			msg: 'VaultWithdrawRequestInProgress';
-		},
-		{
-			code: 6009;
-			name: 'VaultIsAtCapacity';
-			msg: 'VaultIsAtCapacity';
-		},
-		{
-			code: 6010;
-			name: 'InvalidVaultDepositorInitialization';
-			msg: 'InvalidVaultDepositorInitialization';
-		},
-		{
-			code: 6011;
-			name: 'DelegateNotAvailableForLiquidation';
-			msg: 'DelegateNotAvailableForLiquidation';
-		},
-		{
-			code: 6012;
-			name: 'InvalidEquityValue';
-			msg: 'InvalidEquityValue';
-		},
-		{
-			code: 6013;
-			name: 'VaultInLiquidation';
-			msg: 'VaultInLiquidation';
-		},
-		{
-			code: 6014;
-			name: 'DriftError';
-			msg: 'DriftError';
-		},
-		{
-			code: 6015;
-			name: 'InvalidVaultInitialization';
-			msg: 'InvalidVaultInitialization';
-		},
-		{
-			code: 6016;
-			name: 'InvalidVaultUpdate';
-			msg: 'InvalidVaultUpdate';
-		},
-		{
-			code: 6017;
-			name: 'PermissionedVault';
-			msg: 'PermissionedVault';
-		},
-		{
-			code: 6018;
-			name: 'WithdrawInProgress';
-			msg: 'WithdrawInProgress';
-		},
-		{
-			code: 6019;
-			name: 'SharesPercentTooLarge';
-			msg: 'SharesPercentTooLarge';
-		},
-		{
-			code: 6020;
-			name: 'InvalidVaultDeposit';
-			msg: 'InvalidVaultDeposit';
-		},
-		{
-			code: 6021;
-			name: 'OngoingLiquidation';
-			msg: 'OngoingLiquidation';
-		},
-		{
-			code: 6022;
-			name: 'VaultProtocolMissing';
-			msg: 'VaultProtocolMissing';
+			args: [];
 		},
 		{
-			code: 6023;
-			name: 'InvalidTokenization';
-			msg: 'InvalidTokenization';
-		}
-	];
-};
-
-export const IDL: DriftVaults = {
-	version: '0.2.0',
-	name: 'drift_vaults',
-	instructions: [
-		{
-			name: 'initializeVault',
+			name: 'applyProfitShare';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarket',
-					isMut: false,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyRebase';
+			accounts: [
 				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyRebaseTokenizedDepositor';
+			accounts: [
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'tokenizedVaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'VaultParams',
-					},
-				},
-			],
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'initializeVaultWithProtocol',
+			name: 'windDownVault';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'windDownWithdraw';
+			accounts: [
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: true,
-					isSigner: false,
+					name: 'withdrawEpoch';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
 				},
 				{
-					name: 'driftSpotMarket',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'VaultWithProtocolParams',
-					},
-				},
-			],
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'updateDelegate',
+			name: 'closeVaultProtocol';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'delegate',
-					type: 'publicKey',
-				},
-			],
+					name: 'protocol';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'updateMarginTradingEnabled',
+			name: 'closeVault';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'enabled',
-					type: 'bool',
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-		},
-		{
-			name: 'updateVaultProtocol',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'UpdateVaultProtocolParams',
-					},
-				},
-			],
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'updateVault',
+			name: 'pauseVault';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'UpdateVaultParams',
-					},
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
-			],
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'duration';
+					type: 'i64';
+				}
+			];
 		},
 		{
-			name: 'initializeVaultDepositor',
+			name: 'unpauseVault';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: false,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'resetDrawdownBreach';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [],
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'initializeTokenizedVaultDepositor',
+			name: 'forceWithdraw';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: false,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'mintAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'metadataAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenMetadataProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'InitializeTokenizedVaultDepositorParams',
-					},
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
-			],
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'tokenizeShares',
+			name: 'initializeInsuranceFundStake';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'mint',
-					isMut: true,
-					isSigner: false,
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSpotMarketMint';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'amount',
-					type: 'u64',
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'unit',
-					type: {
-						defined: 'WithdrawUnit',
-					},
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-		},
-		{
-			name: 'redeemTokens',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
 				{
-					name: 'mint',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'tokensToBurn',
-					type: 'u64',
-				},
-			],
+					name: 'marketIndex';
+					type: 'u16';
+				}
+			];
 		},
 		{
-			name: 'deposit',
+			name: 'addInsuranceFundStake';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultIfTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
-			],
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
 			args: [
 				{
-					name: 'amount',
-					type: 'u64',
+					name: 'marketIndex';
+					type: 'u16';
 				},
-			],
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'requestWithdraw',
+			name: 'requestRemoveInsuranceFundStake';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'withdrawAmount',
-					type: 'u64',
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
-					},
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
 				},
-			],
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'cancelRequestWithdraw',
+			name: 'removeInsuranceFundStake';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [],
-		},
-		{
-			name: 'withdraw',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultIfTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				}
+			];
+		},
+		{
+			name: 'cancelRequestRemoveInsuranceFundStake';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [],
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				}
+			];
 		},
 		{
-			name: 'liquidate',
+			name: 'updateInsuranceFundStakeInEquity';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
+					name: 'insuranceFundStake';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+					name: 'enabled';
+					type: 'bool';
+				}
+			];
 		},
 		{
-			name: 'resetDelegate',
+			name: 'vaultAddInsuranceFundStake';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [],
-		},
-		{
-			name: 'managerDeposit',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
 			args: [
 				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
+					name: 'amount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'managerRequestWithdraw',
+			name: 'vaultRemoveInsuranceFundStake';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'withdrawAmount',
-					type: 'u64',
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
-					},
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-		},
-		{
-			name: 'mangerCancelWithdrawRequest',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'managerWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'applyProfitShare',
+			name: 'protocolRequestWithdraw';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
 				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
+					name: 'withdrawAmount';
+					type: 'u64';
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
 		},
 		{
-			name: 'applyRebase',
+			name: 'protocolCancelWithdrawRequest';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [],
-		},
-		{
-			name: 'applyRebaseTokenizedDepositor',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'forceWithdraw',
+			name: 'protocolWithdraw';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		}
+	];
+	accounts: [
 		{
-			name: 'initializeInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
+			name: 'tokenizedVaultDepositor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault deposited into'];
+						type: 'publicKey';
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'mint';
+						docs: [
+							'The token mint for tokenized shares owned by this VaultDepositor'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastVaultShares';
+						docs: [
+							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
+							'CPI is done, used to track invariants'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastValidTs';
+						docs: ['creation ts of vault depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['lifetime net deposits of vault depositor for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['lifetime total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['lifetime total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: [
+							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
+							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
+							'is possible.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						docs: ['The bump for the vault pda'];
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 3];
+						};
+					},
+					{
+						name: 'lastProfitShareTs';
+						docs: [
+							'the last ts profit share was crystallized on the whole position, the hurdle accrues from this ts'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'lastCrystallizationTs';
+						docs: [
+							"the last ts the vault's profit share crystallization schedule was applied to the depositor"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 8];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'addInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'managerTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultIfTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
-		},
-		{
-			name: 'requestRemoveInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
+			name: 'vaultDepositor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault deposited into'];
+						type: 'publicKey';
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault and authority"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: [
+							'The authority is the address w permission to deposit/withdraw'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastWithdrawRequest';
+						docs: ['last withdraw request'];
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'lastValidTs';
+						docs: ['creation ts of vault depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['lifetime net deposits of vault depositor for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['lifetime total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['lifetime total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: ['the exponent for vault_shares decimal places'];
+						type: 'u32';
+					},
+					{
+						name: 'padding1';
+						type: 'u32';
+					},
+					{
+						name: 'lastProfitShareTs';
+						docs: [
+							'the last ts profit share was crystallized on the whole position, the hurdle accrues from this ts'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'withdrawRequestEpoch';
+						docs: [
+							'the withdraw epoch the pending withdraw request is queued in, if the vault has a withdraw queue'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'withdrawRequestSlotCount';
+						docs: [
+							"the number of pending requests in the depositor's [`WithdrawRequestSlots`]"
+						];
+						type: 'u8';
+					},
+					{
+						name: 'frozen';
+						docs: [
+							'set by the manager to block deposits, withdraw requests and tokenizing. The manager can still force',
+							'the depositor out.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 6];
+						};
+					},
+					{
+						name: 'withdrawRequestSlotsShares';
+						docs: [
+							"the shares reserved by pending requests in the depositor's [`WithdrawRequestSlots`]"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'withdrawDestination';
+						docs: [
+							'the token account withdrawals are paid to, if the authority registered one'
+						];
+						type: {
+							defined: 'WithdrawDestination';
+						};
+					},
+					{
+						name: 'maxEquityOverride';
+						docs: [
+							"overrides the vault's max_depositor_equity for this depositor if non-zero"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'minBalanceOverride';
+						docs: [
+							"overrides the vault's min_depositor_balance for this depositor if non-zero"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'allowlistMaxEquity';
+						docs: [
+							"the cap committed to in the depositor's allowlist leaf, used instead of the vault's",
+							'max_depositor_equity if non-zero and the manager set no max_equity_override'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'lastCrystallizationTs';
+						docs: [
+							"the last ts the vault's profit share crystallization schedule was applied to the depositor"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 1];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'removeInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'managerTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultIfTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
-		},
-		{
-			name: 'cancelRequestRemoveInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
-		},
-		{
-			name: 'protocolRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			name: 'vaultProtocol';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						docs: [
+							'The protocol, company, or entity that services the product using this vault.',
+							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.'
+						];
+						type: 'publicKey';
 					},
-				},
-			],
+					{
+						name: 'protocolProfitAndFeeShares';
+						docs: [
+							'The shares from profit share and annual fee unclaimed by the protocol.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'protocolFee';
+						docs: [
+							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
+							"Unlike the management fee this can't be negative."
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalWithdraws';
+						docs: ['Total withdraws for the protocol'];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalFee';
+						docs: [
+							'Total fee charged by the protocol (annual management fee + profit share).',
+							"Unlike the management fee this can't be negative."
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalProfitShare';
+						docs: ['Total profit share charged by the protocol'];
+						type: 'u64';
+					},
+					{
+						name: 'lastProtocolWithdrawRequest';
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						docs: [
+							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'version';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 2];
+						};
+					},
+					{
+						name: 'protocolEntryFee';
+						docs: [
+							'Percentage the protocol charges on deposits: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'protocolExitFee';
+						docs: [
+							'Percentage the protocol charges on withdrawals: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingProtocol';
+						docs: [
+							'The protocol proposed by the current protocol. Becomes the protocol once they accept the handover.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'pendingProtocolFee';
+						docs: [
+							'The protocol fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'pendingProtocolProfitShare';
+						docs: [
+							'The protocol profit share that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingProtocolEntryFee';
+						docs: [
+							'The protocol entry fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingProtocolExitFee';
+						docs: [
+							'The protocol exit fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 4];
+						};
+					},
+					{
+						name: 'pendingParamsEffectiveTs';
+						docs: [
+							'When the pending protocol fee increases take effect. 0 means there are no pending changes.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u64', 7];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'protocolCancelWithdrawRequest',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
+			name: 'vault';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						docs: [
+							'The name of the vault. Vault pubkey is derived from this name.'
+						];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault's pubkey. It is a pda of name and also used as the authority for drift user"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'manager';
+						docs: [
+							'The manager of the vault who has ability to update vault params'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'tokenAccount';
+						docs: [
+							'The vaults token account. Used to receive tokens between deposits and withdrawals'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'userStats';
+						docs: ['The drift user stats account for the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'user';
+						docs: ['The drift user account for the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'delegate';
+						docs: [
+							'The vaults designated delegate for drift user account',
+							'can differ from actual user delegate if vault is in liquidation'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'liquidationDelegate';
+						docs: ['The delegate handling liquidation for depositor'];
+						type: 'publicKey';
+					},
+					{
+						name: 'userShares';
+						docs: [
+							'The sum of all shares held by the users (vault depositors)'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'totalShares';
+						docs: [
+							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
+							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastFeeUpdateTs';
+						docs: ['Last fee update unix timestamp'];
+						type: 'i64';
+					},
+					{
+						name: 'liquidationStartTs';
+						docs: ['When the liquidation starts'];
+						type: 'i64';
+					},
+					{
+						name: 'redeemPeriod';
+						docs: [
+							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
+							'Currently, the maximum is 90 days.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'totalWithdrawRequested';
+						docs: ['The sum of all outstanding withdraw requests'];
+						type: 'u64';
+					},
+					{
+						name: 'maxTokens';
+						docs: [
+							'Max token capacity, once hit/passed vault will reject new deposits (updatable)'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						docs: [
+							'The annual fee charged on deposits by the manager.',
+							'Traditional funds typically charge 2% per year on assets under management.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'initTs';
+						docs: ['Timestamp vault initialized'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['The net deposits for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'managerNetDeposits';
+						docs: ['The net deposits for the manager'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['Total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['Total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalDeposits';
+						docs: ['Total deposits for the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalWithdraws';
+						docs: ['Total withdraws for the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalFee';
+						docs: ['Total management fee accrued by the manager'];
+						type: 'i64';
+					},
+					{
+						name: 'managerTotalProfitShare';
+						docs: ['Total profit share accrued by the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'minDepositAmount';
+						docs: ['The minimum deposit amount'];
+						type: 'u64';
+					},
+					{
+						name: 'lastManagerWithdrawRequest';
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'profitShare';
+						docs: [
+							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						docs: [
+							'Vault manager only collect incentive fees during periods when returns are higher than this annualized amount: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						docs: [
+							'The spot market index the vault deposits into/withdraws from'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						docs: ['The bump for the vault pda'];
+						type: 'u8';
+					},
+					{
+						name: 'permissioned';
+						docs: ['Whether anybody can be a depositor'];
+						type: 'bool';
+					},
+					{
+						name: 'vaultProtocol';
+						docs: ['The optional [`VaultProtocol`] account.'];
+						type: 'bool';
+					},
+					{
+						name: 'earlyWithdrawPenaltyToManager';
+						docs: [
+							'Whether the early withdraw penalty is paid to the manager instead of left in the vault for remaining depositors'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'subAccountCount';
+						docs: [
+							'The number of drift sub-accounts owned by the vault. Sub-account 0 (`user`) is the primary sub-account',
+							'that deposits and withdrawals go through. 0 for vaults created before sub-accounts were supported.'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'ifStakeInEquity';
+						docs: [
+							"Whether the vault's insurance fund stake in `spot_market_index` holds depositor capital and is counted",
+							'in vault equity, instead of being a manager-funded stake.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'withdrawQueue';
+						docs: [
+							'Whether withdraw requests are batched into epochs that the manager settles pro-rata, instead of each',
+							'depositor withdrawing directly from drift after the redeem period.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'windDown';
+						docs: [
+							'Whether the vault is winding down: no new deposits, no redeem period and no management or protocol',
+							'fees. Depositors are processed out with `wind_down_withdraw` until the vault can be closed.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 1];
+						};
+					},
+					{
+						name: 'profitShareCrystallizationPeriod';
+						docs: [
+							'The period (in seconds) between profit share crystallizations, aligned to init_ts.',
+							'Depositor profit share is only charged at period boundaries or on full exit. 0 means profit share is',
+							'charged on every depositor action.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'entryFee';
+						docs: [
+							'Percentage the manager charges on deposits: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'exitFee';
+						docs: [
+							'Percentage the manager charges on withdrawals: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'earlyWithdrawPenalty';
+						docs: [
+							'Percentage a depositor pays to withdraw immediately after requesting a withdrawal, decaying linearly to 0',
+							'over the redeem period: PERCENTAGE_PRECISION. 0 means instant withdraws are disabled.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingEarlyWithdrawPenalty';
+						docs: [
+							'The early withdraw penalty that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingManager';
+						docs: [
+							'The manager proposed by the current manager. Becomes the manager once they accept the handover.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'pendingManagementFee';
+						docs: [
+							'The management fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingRedeemPeriod';
+						docs: [
+							'The redeem period that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingParamsEffectiveTs';
+						docs: [
+							'When the pending parameter increases take effect. 0 means there are no pending changes.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingProfitShare';
+						docs: [
+							'The profit share that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingHurdleRate';
+						docs: [
+							'The hurdle rate that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingEntryFee';
+						docs: [
+							'The entry fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingExitFee';
+						docs: [
+							'The exit fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'withdrawEpoch';
+						docs: [
+							'The withdraw epoch currently accepting requests. Epochs before it have been settled.'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'withdrawQueueShares';
+						docs: [
+							'The shares queued for withdraw in the current epoch, including unfilled shares rolled over from',
+							'settled epochs.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'managerWithdrawDestination';
+						docs: [
+							'The token account manager withdrawals are paid to, if the manager registered one'
+						];
+						type: {
+							defined: 'WithdrawDestination';
+						};
+					},
+					{
+						name: 'maxDepositorEquity';
+						docs: [
+							'The max equity a single depositor can hold after a deposit. 0 means no limit.'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'minDepositorBalance';
+						docs: [
+							'The min equity a depositor must keep unless fully exiting. 0 means no minimum.'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'allowlistMerkleRoot';
+						docs: [
+							'Merkle root of the (authority, max equity) leaves allowed to self-initialize a vault depositor in a',
+							'permissioned vault. All zeros disables the allowlist.'
+						];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'accessMint';
+						docs: [
+							'Depositors must hold `access_min_balance` of this mint to join and deposit. Default disables the gate.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'accessMinBalance';
+						type: 'u64';
+					},
+					{
+						name: 'accessCollection';
+						docs: [
+							'Depositors must hold an NFT of this verified collection to join and deposit. Default disables the gate.',
+							'If both gates are set either one grants access.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'pausedUntilTs';
+						docs: [
+							'The ts the emergency pause expires. While paused, deposits and tokenizing are halted and the drift',
+							'delegate is set to the null key. 0 if never paused.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pausedBy';
+						docs: ['The manager or protocol that paused the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'maxDrawdown';
+						docs: [
+							"The max drawdown of the share price from its high water mark before the vault's drift user is set to",
+							'reduce-only: PERCENTAGE_PRECISION. 0 disables the circuit breaker.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pendingMaxDrawdown';
+						docs: [
+							'The looser max drawdown that takes effect at `pending_max_drawdown_ts`. 0 disables the circuit breaker.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'highWaterMarkSharePrice';
+						docs: [
+							'The highest share price seen since the circuit breaker was last reset: PRICE_PRECISION'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'drawdownBreachTs';
+						docs: ['The ts the max drawdown was breached. 0 if not breached.'];
+						type: 'i64';
+					},
+					{
+						name: 'liquidationWindow';
+						docs: [
+							'How long (in seconds) a liquidator has to unwind the vault before the manager can reset the delegate.',
+							'0 uses the default [`TIME_FOR_LIQUIDATION`].'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'liquidators';
+						docs: [
+							'The liquidators a depositor can hand the vault to in `liquidate`. If all default, only the',
+							'[`permissioned_liquidator`] is allowed.'
+						];
+						type: {
+							array: ['publicKey', 4];
+						};
+					},
+					{
+						name: 'permissionlessLiquidation';
+						docs: [
+							'Whether a depositor can hand the vault to any liquidator, including themselves'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'pendingPermissionlessLiquidation';
+						docs: [
+							'The `permissionless_liquidation` that takes effect at `pending_liquidation_settings_ts`'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'drawdownReduceOnlyPending';
+						docs: [
+							"Whether the max drawdown was breached in an instruction that can't set the drift users reduce-only,",
+							'the next instruction that can does so'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'padding6';
+						type: {
+							array: ['u8', 5];
+						};
+					},
+					{
+						name: 'withdrawQueueStartTs';
+						docs: [
+							'When the oldest request in the withdraw queue was made. 0 when the queue is empty.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingMaxDrawdownTs';
+						docs: [
+							'When `pending_max_drawdown` takes effect. 0 means there is no pending change.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pauseEndTs';
+						docs: [
+							'When the last emergency pause ended, by expiring or being lifted. 0 if never paused.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingLiquidationWindow';
+						docs: [
+							'The `liquidation_window` that takes effect at `pending_liquidation_settings_ts`'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingLiquidationSettingsTs';
+						docs: [
+							'When the pending liquidation settings take effect, or once the vault leaves liquidation if later.',
+							'0 means there are no pending changes.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'pendingLiquidators';
+						docs: [
+							'The `liquidators` that take effect at `pending_liquidation_settings_ts`'
+						];
+						type: {
+							array: ['publicKey', 4];
+						};
+					},
+					{
+						name: 'pendingProfitShareCrystallizationPeriod';
+						docs: [
+							'The profit share crystallization period that takes effect at `pending_params_effective_ts`. 0 means',
+							'unchanged.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'padding4';
+						type: {
+							array: ['u64', 4];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'withdrawEpoch';
+			docs: [
+				'A settled withdraw epoch. Every request queued in the epoch is filled by the same fraction,',
+				'`filled_shares / requested_shares`, at the share price the epoch was settled at, capped at the',
+				"request's value when claimed."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the epoch belongs to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The withdraw epoch account's pubkey. It is a pda of vault and epoch"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'requestedShares';
+						docs: ['The shares queued for withdraw when the epoch was settled'];
+						type: 'u128';
+					},
+					{
+						name: 'filledShares';
+						docs: [
+							'The queued shares burned to fill the epoch, the rest rolled over into the next epoch'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'filledAmount';
+						docs: [
+							'The token amount withdrawn from drift to pay for the filled shares, before exit fees'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'claimedAmount';
+						docs: [
+							'The token amount claimed by depositors so far, including exit fees and the value above each',
+							"request's cap deposited back into the vault"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'epoch';
+						type: 'u64';
+					},
+					{
+						name: 'settledTs';
+						type: 'i64';
+					},
+					{
+						name: 'sharesBase';
+						docs: ["The vault's shares_base when the epoch was settled"];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 3];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 9];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'withdrawRequestSlots';
+			docs: [
+				'Additional withdraw requests for a vault depositor, each with its own redeem period clock, so',
+				"withdrawals can be laddered alongside the depositor's `last_withdraw_request`."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the requests belong to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'vault';
+						docs: ['The vault the vault depositor deposited into'];
+						type: 'publicKey';
+					},
+					{
+						name: 'requests';
+						type: {
+							array: [
+								{
+									defined: 'WithdrawRequest';
+								},
+								8
+							];
+						};
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							'the exponent for the request shares decimal places, requests are rebased lazily when loaded'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 3];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 7];
+						};
+					}
+				];
+			};
+		}
+	];
+	types: [
+		{
+			name: 'InitializeTokenizedVaultDepositorParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'tokenName';
+						type: 'string';
+					},
+					{
+						name: 'tokenSymbol';
+						type: 'string';
+					},
+					{
+						name: 'tokenUri';
+						type: 'string';
+					},
+					{
+						name: 'decimals';
+						type: 'u8';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultWithProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'profitShareCrystallizationPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'entryFee';
+						type: 'u32';
+					},
+					{
+						name: 'exitFee';
+						type: 'u32';
+					},
+					{
+						name: 'earlyWithdrawPenalty';
+						type: 'u32';
+					},
+					{
+						name: 'earlyWithdrawPenaltyToManager';
+						type: 'bool';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					},
+					{
+						name: 'vaultProtocol';
+						type: {
+							defined: 'VaultProtocolParams';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						type: 'publicKey';
+					},
+					{
+						name: 'protocolFee';
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u32';
+					},
+					{
+						name: 'protocolEntryFee';
+						type: 'u32';
+					},
+					{
+						name: 'protocolExitFee';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'profitShareCrystallizationPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'entryFee';
+						type: 'u32';
+					},
+					{
+						name: 'exitFee';
+						type: 'u32';
+					},
+					{
+						name: 'earlyWithdrawPenalty';
+						type: 'u32';
+					},
+					{
+						name: 'earlyWithdrawPenaltyToManager';
+						type: 'bool';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProposeVaultParamsParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'managementFee';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'profitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'hurdleRate';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'entryFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'exitFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'earlyWithdrawPenalty';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'profitShareCrystallizationPeriod';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'liquidators';
+						type: {
+							option: {
+								vec: 'publicKey';
+							};
+						};
+					},
+					{
+						name: 'liquidationWindow';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'permissionlessLiquidation';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'effectiveTs';
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProposeVaultProtocolParamsParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocolFee';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'protocolEntryFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'protocolExitFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'effectiveTs';
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultDepositorLimitsParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'maxEquityOverride';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'minBalanceOverride';
+						type: {
+							option: 'u64';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocolFee';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'protocolEntryFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'protocolExitFee';
+						type: {
+							option: 'u32';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'redeemPeriod';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'maxTokens';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'managementFee';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'minDepositAmount';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'profitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'hurdleRate';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'permissioned';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'entryFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'exitFee';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'earlyWithdrawPenalty';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'earlyWithdrawPenaltyToManager';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'withdrawQueue';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'maxDepositorEquity';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'minDepositorBalance';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'allowlistMerkleRoot';
+						type: {
+							option: {
+								array: ['u8', 32];
+							};
+						};
+					},
+					{
+						name: 'accessMint';
+						type: {
+							option: 'publicKey';
+						};
+					},
+					{
+						name: 'accessMinBalance';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'accessCollection';
+						type: {
+							option: 'publicKey';
+						};
+					},
+					{
+						name: 'maxDrawdown';
+						type: {
+							option: 'u32';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawDestination';
+			docs: [
+				"The token account withdrawals are paid to instead of the owner's own token account. Changes only",
+				'take effect after [`WITHDRAW_DESTINATION_TIMELOCK`].'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'destination';
+						docs: [
+							'the token account withdrawals are paid to, default means any token account of the owner'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'pendingDestination';
+						docs: [
+							'the destination that replaces `destination` at `pending_effective_ts`'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'pendingEffectiveTs';
+						docs: [
+							'when `pending_destination` takes effect, 0 if there is no pending change'
+						];
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawRequest';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'shares';
+						docs: ['request shares of vault withdraw'];
+						type: 'u128';
+					},
+					{
+						name: 'value';
+						docs: [
+							'requested value (in vault spot_market_index) of shares for withdraw'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'ts';
+						docs: ['request ts of vault withdraw'];
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawMode';
+			docs: ['How [`process_withdraw`] pays out the pending withdraw request'];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'AfterRedeemPeriod';
+					},
+					{
+						name: 'Instant';
+					},
+					{
+						name: 'Partial';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultDepositorAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Deposit';
+					},
+					{
+						name: 'WithdrawRequest';
+					},
+					{
+						name: 'CancelWithdrawRequest';
+					},
+					{
+						name: 'Withdraw';
+					},
+					{
+						name: 'FeePayment';
+					},
+					{
+						name: 'TokenizeShares';
+					},
+					{
+						name: 'RedeemTokens';
+					},
+					{
+						name: 'InstantWithdraw';
+					},
+					{
+						name: 'EpochWithdraw';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultParamsUpdateAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Propose';
+					},
+					{
+						name: 'Apply';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawUnit';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Shares';
+					},
+					{
+						name: 'Token';
+					},
+					{
+						name: 'SharesPercent';
+					}
+				];
+			};
+		}
+	];
+	events: [
+		{
+			name: 'VaultRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'profitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'entryExitFee';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorV1Record';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'protocolFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managerProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolEntryExitFee';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managerEntryExitFee';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ShareTransferRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'fromVaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'toVaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'value';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'fromDepositorSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fromDepositorSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'toDepositorSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'toDepositorSharesAfter';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultManagerTransferRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldManager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'newManager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'delegate';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'managerShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'managerWithdrawRequestShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'managerWithdrawRequestValue';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultProtocolTransferRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldProtocol';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'newProtocol';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'protocolShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolWithdrawRequestShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolWithdrawRequestValue';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultParamsUpdateRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultParamsUpdateAction';
+					};
+					index: false;
+				},
+				{
+					name: 'effectiveTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'redeemPeriod';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'profitShare';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'hurdleRate';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'entryFee';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'exitFee';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'earlyWithdrawPenalty';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'profitShareCrystallizationPeriod';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultProtocolParamsUpdateRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultParamsUpdateAction';
+					};
+					index: false;
+				},
+				{
+					name: 'effectiveTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolFee';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'protocolProfitShare';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'protocolEntryFee';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'protocolExitFee';
+					type: 'u32';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WithdrawEpochRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'epoch';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'requestedShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'filledShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'filledAmount';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'DepositForRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'funder';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WithdrawDestinationUpdateRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldDestination';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'newDestination';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'effectiveTs';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorFreezeRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'manager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'frozen';
+					type: 'bool';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorCloseRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'closedBy';
+					type: 'publicKey';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultWindDownRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'manager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultEquity';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'totalShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolFee';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultCloseRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'manager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'subAccountsDeleted';
+					type: 'u16';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultPauseRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'pausedUntilTs';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDrawdownRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'maxDrawdown';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'highWaterMarkSharePrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'sharePrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'breached';
+					type: 'bool';
+					index: false;
+				}
+			];
+		}
+	];
+	errors: [
+		{
+			code: 6000;
+			name: 'Default';
+			msg: 'Default';
+		},
+		{
+			code: 6001;
+			name: 'InvalidVaultRebase';
+			msg: 'InvalidVaultRebase';
+		},
+		{
+			code: 6002;
+			name: 'InvalidVaultSharesDetected';
+			msg: 'InvalidVaultSharesDetected';
+		},
+		{
+			code: 6003;
+			name: 'CannotWithdrawBeforeRedeemPeriodEnd';
+			msg: 'CannotWithdrawBeforeRedeemPeriodEnd';
+		},
+		{
+			code: 6004;
+			name: 'InvalidVaultWithdraw';
+			msg: 'InvalidVaultWithdraw';
+		},
+		{
+			code: 6005;
+			name: 'InsufficientVaultShares';
+			msg: 'InsufficientVaultShares';
+		},
+		{
+			code: 6006;
+			name: 'InvalidVaultWithdrawSize';
+			msg: 'InvalidVaultWithdrawSize';
+		},
+		{
+			code: 6007;
+			name: 'InvalidVaultForNewDepositors';
+			msg: 'InvalidVaultForNewDepositors';
+		},
+		{
+			code: 6008;
+			name: 'VaultWithdrawRequestInProgress';
+			msg: 'VaultWithdrawRequestInProgress';
+		},
+		{
+			code: 6009;
+			name: 'VaultIsAtCapacity';
+			msg: 'VaultIsAtCapacity';
+		},
+		{
+			code: 6010;
+			name: 'InvalidVaultDepositorInitialization';
+			msg: 'InvalidVaultDepositorInitialization';
+		},
+		{
+			code: 6011;
+			name: 'DelegateNotAvailableForLiquidation';
+			msg: 'DelegateNotAvailableForLiquidation';
+		},
+		{
+			code: 6012;
+			name: 'InvalidEquityValue';
+			msg: 'InvalidEquityValue';
+		},
+		{
+			code: 6013;
+			name: 'VaultInLiquidation';
+			msg: 'VaultInLiquidation';
+		},
+		{
+			code: 6014;
+			name: 'DriftError';
+			msg: 'DriftError';
+		},
+		{
+			code: 6015;
+			name: 'InvalidVaultInitialization';
+			msg: 'InvalidVaultInitialization';
+		},
+		{
+			code: 6016;
+			name: 'InvalidVaultUpdate';
+			msg: 'InvalidVaultUpdate';
+		},
+		{
+			code: 6017;
+			name: 'PermissionedVault';
+			msg: 'PermissionedVault';
+		},
+		{
+			code: 6018;
+			name: 'WithdrawInProgress';
+			msg: 'WithdrawInProgress';
+		},
+		{
+			code: 6019;
+			name: 'SharesPercentTooLarge';
+			msg: 'SharesPercentTooLarge';
+		},
+		{
+			code: 6020;
+			name: 'InvalidVaultDeposit';
+			msg: 'InvalidVaultDeposit';
+		},
+		{
+			code: 6021;
+			name: 'OngoingLiquidation';
+			msg: 'OngoingLiquidation';
+		},
+		{
+			code: 6022;
+			name: 'VaultProtocolMissing';
+			msg: 'VaultProtocolMissing';
+		},
+		{
+			code: 6023;
+			name: 'InvalidTokenization';
+			msg: 'InvalidTokenization';
+		},
+		{
+			code: 6024;
+			name: 'VaultProtocolNotMigrated';
+			msg: 'VaultProtocolNotMigrated';
+		},
+		{
+			code: 6025;
+			name: 'SlippageExceeded';
+			msg: 'SlippageExceeded';
+		},
+		{
+			code: 6026;
+			name: 'VaultDepositorNotMigrated';
+			msg: 'VaultDepositorNotMigrated';
+		},
+		{
+			code: 6027;
+			name: 'VaultDepositorFrozen';
+			msg: 'VaultDepositorFrozen';
+		},
+		{
+			code: 6028;
+			name: 'InvalidVaultDepositorClose';
+			msg: 'InvalidVaultDepositorClose';
+		},
+		{
+			code: 6029;
+			name: 'VaultWindingDown';
+			msg: 'VaultWindingDown';
+		},
+		{
+			code: 6030;
+			name: 'InvalidVaultClose';
+			msg: 'InvalidVaultClose';
+		},
+		{
+			code: 6031;
+			name: 'VaultPaused';
+			msg: 'VaultPaused';
+		},
+		{
+			code: 6032;
+			name: 'InvalidVaultSubAccounts';
+			msg: 'InvalidVaultSubAccounts';
+		},
+		{
+			code: 6033;
+			name: 'VaultNotMigrated';
+			msg: 'VaultNotMigrated';
+		}
+	];
+};
+
+export const IDL: DriftVaults = {
+	version: '0.2.0',
+	name: 'drift_vaults',
+	instructions: [
+		{
+			name: 'initializeVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultWithProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultWithProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultSubAccount',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'transferSubAccountDeposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'fromUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'toUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'updateDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'delegate',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'updateManagerWithdrawDestination',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawDestination',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateMarginTradingEnabled',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'enabled',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'updateVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVaultDepositorLimits',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultDepositorLimitsParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVaultDepositorFrozen',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'frozen',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'closeVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawRequestSlots',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerCloseVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'depositorAuthority',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawRequestSlots',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'proposeVaultManager',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'newManager',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'acceptVaultManager',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'newManager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'proposeVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'newProtocol',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'acceptVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'newProtocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'renounceVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'removeVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: true,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'proposeVaultParams',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'ProposeVaultParamsParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'proposeVaultProtocolParams',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'ProposeVaultProtocolParamsParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'migrateVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'migrateVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'migrateVaultDepositor',
+			accounts: [
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorWithProof',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'maxEquity',
+					type: 'u64',
+				},
+				{
+					name: 'proof',
+					type: {
+						vec: {
+							array: ['u8', 32],
+						},
+					},
+				},
+			],
+		},
+		{
+			name: 'updateWithdrawDestination',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawDestination',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeTokenizedVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mintAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'metadataAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenMetadataProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'tokenizeShares',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'unit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'redeemTokens',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'tokensToBurn',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'deposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'minSharesOut',
+					type: {
+						option: 'u128',
+					},
+				},
+			],
+		},
+		{
+			name: 'depositFor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'funder',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'funderTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'minSharesOut',
+					type: {
+						option: 'u128',
+					},
+				},
+			],
+		},
+		{
+			name: 'requestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+				{
+					name: 'maxSharesBurned',
+					type: {
+						option: 'u128',
+					},
+				},
+			],
+		},
+		{
+			name: 'cancelRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'settleWithdrawEpoch',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawEpoch',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'claimWithdrawEpoch',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'withdrawEpoch',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'withdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'minTokensOut',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
+		},
+		{
+			name: 'partialWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'minTokensOut',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
+		},
+		{
+			name: 'instantWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'minTokensOut',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeWithdrawRequestSlots',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawRequestSlots',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'requestWithdrawSlot',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawRequestSlots',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'cancelWithdrawRequestSlot',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawRequestSlots',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'slot',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'withdrawSlot',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawRequestSlots',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'slot',
+					type: 'u8',
+				},
+				{
+					name: 'minTokensOut',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
+		},
+		{
+			name: 'liquidate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'liquidator',
+					type: {
+						option: 'publicKey',
+					},
+				},
+			],
+		},
+		{
+			name: 'resetDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerDeposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'managerRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'mangerCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyProfitShare',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyRebase',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyRebaseTokenizedDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'windDownVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'windDownWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawEpoch',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'closeVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'closeVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'pauseVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'duration',
+					type: 'i64',
+				},
+			],
+		},
+		{
+			name: 'unpauseVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetDrawdownBreach',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'forceWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'addInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultIfTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'removeInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultIfTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'cancelRequestRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'updateInsuranceFundStakeInEquity',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'enabled',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'vaultAddInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'vaultRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'protocolRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'protocolCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'protocolWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
 					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
 					isSigner: false,
 				},
 				{
 					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [],
 		},
+	],
+	accounts: [
+		{
+			name: 'tokenizedVaultDepositor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'mint',
+						docs: [
+							'The token mint for tokenized shares owned by this VaultDepositor',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastVaultShares',
+						docs: [
+							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
+							'CPI is done, used to track invariants',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastValidTs',
+						docs: ['creation ts of vault depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['lifetime net deposits of vault depositor for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['lifetime total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['lifetime total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: [
+							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
+							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
+							'is possible.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						docs: ['The bump for the vault pda'],
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+					{
+						name: 'lastProfitShareTs',
+						docs: [
+							'the last ts profit share was crystallized on the whole position, the hurdle accrues from this ts',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'lastCrystallizationTs',
+						docs: [
+							"the last ts the vault's profit share crystallization schedule was applied to the depositor",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 8],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultDepositor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault and authority",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: [
+							'The authority is the address w permission to deposit/withdraw',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastWithdrawRequest',
+						docs: ['last withdraw request'],
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'lastValidTs',
+						docs: ['creation ts of vault depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['lifetime net deposits of vault depositor for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['lifetime total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['lifetime total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: ['the exponent for vault_shares decimal places'],
+						type: 'u32',
+					},
+					{
+						name: 'padding1',
+						type: 'u32',
+					},
+					{
+						name: 'lastProfitShareTs',
+						docs: [
+							'the last ts profit share was crystallized on the whole position, the hurdle accrues from this ts',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'withdrawRequestEpoch',
+						docs: [
+							'the withdraw epoch the pending withdraw request is queued in, if the vault has a withdraw queue',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'withdrawRequestSlotCount',
+						docs: [
+							"the number of pending requests in the depositor's [`WithdrawRequestSlots`]",
+						],
+						type: 'u8',
+					},
+					{
+						name: 'frozen',
+						docs: [
+							'set by the manager to block deposits, withdraw requests and tokenizing. The manager can still force',
+							'the depositor out.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 6],
+						},
+					},
+					{
+						name: 'withdrawRequestSlotsShares',
+						docs: [
+							"the shares reserved by pending requests in the depositor's [`WithdrawRequestSlots`]",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'withdrawDestination',
+						docs: [
+							'the token account withdrawals are paid to, if the authority registered one',
+						],
+						type: {
+							defined: 'WithdrawDestination',
+						},
+					},
+					{
+						name: 'maxEquityOverride',
+						docs: [
+							"overrides the vault's max_depositor_equity for this depositor if non-zero",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'minBalanceOverride',
+						docs: [
+							"overrides the vault's min_depositor_balance for this depositor if non-zero",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'allowlistMaxEquity',
+						docs: [
+							"the cap committed to in the depositor's allowlist leaf, used instead of the vault's",
+							'max_depositor_equity if non-zero and the manager set no max_equity_override',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'lastCrystallizationTs',
+						docs: [
+							"the last ts the vault's profit share crystallization schedule was applied to the depositor",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 1],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultProtocol',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						docs: [
+							'The protocol, company, or entity that services the product using this vault.',
+							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'protocolProfitAndFeeShares',
+						docs: [
+							'The shares from profit share and annual fee unclaimed by the protocol.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'protocolFee',
+						docs: [
+							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
+							"Unlike the management fee this can't be negative.",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalWithdraws',
+						docs: ['Total withdraws for the protocol'],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalFee',
+						docs: [
+							'Total fee charged by the protocol (annual management fee + profit share).',
+							"Unlike the management fee this can't be negative.",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalProfitShare',
+						docs: ['Total profit share charged by the protocol'],
+						type: 'u64',
+					},
+					{
+						name: 'lastProtocolWithdrawRequest',
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'protocolProfitShare',
+						docs: [
+							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'version',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+					{
+						name: 'protocolEntryFee',
+						docs: [
+							'Percentage the protocol charges on deposits: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'protocolExitFee',
+						docs: [
+							'Percentage the protocol charges on withdrawals: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingProtocol',
+						docs: [
+							'The protocol proposed by the current protocol. Becomes the protocol once they accept the handover.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'pendingProtocolFee',
+						docs: [
+							'The protocol fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'pendingProtocolProfitShare',
+						docs: [
+							'The protocol profit share that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingProtocolEntryFee',
+						docs: [
+							'The protocol entry fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingProtocolExitFee',
+						docs: [
+							'The protocol exit fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 4],
+						},
+					},
+					{
+						name: 'pendingParamsEffectiveTs',
+						docs: [
+							'When the pending protocol fee increases take effect. 0 means there are no pending changes.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u64', 7],
+						},
+					},
+				],
+			},
+		},
 		{
-			name: 'protocolWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'vault',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						docs: [
+							'The name of the vault. Vault pubkey is derived from this name.',
+						],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault's pubkey. It is a pda of name and also used as the authority for drift user",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'manager',
+						docs: [
+							'The manager of the vault who has ability to update vault params',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'tokenAccount',
+						docs: [
+							'The vaults token account. Used to receive tokens between deposits and withdrawals',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'userStats',
+						docs: ['The drift user stats account for the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'user',
+						docs: ['The drift user account for the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'delegate',
+						docs: [
+							'The vaults designated delegate for drift user account',
+							'can differ from actual user delegate if vault is in liquidation',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'liquidationDelegate',
+						docs: ['The delegate handling liquidation for depositor'],
+						type: 'publicKey',
+					},
+					{
+						name: 'userShares',
+						docs: [
+							'The sum of all shares held by the users (vault depositors)',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'totalShares',
+						docs: [
+							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
+							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastFeeUpdateTs',
+						docs: ['Last fee update unix timestamp'],
+						type: 'i64',
+					},
+					{
+						name: 'liquidationStartTs',
+						docs: ['When the liquidation starts'],
+						type: 'i64',
+					},
+					{
+						name: 'redeemPeriod',
+						docs: [
+							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
+							'Currently, the maximum is 90 days.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'totalWithdrawRequested',
+						docs: ['The sum of all outstanding withdraw requests'],
+						type: 'u64',
+					},
+					{
+						name: 'maxTokens',
+						docs: [
+							'Max token capacity, once hit/passed vault will reject new deposits (updatable)',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						docs: [
+							'The annual fee charged on deposits by the manager.',
+							'Traditional funds typically charge 2% per year on assets under management.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'initTs',
+						docs: ['Timestamp vault initialized'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['The net deposits for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'managerNetDeposits',
+						docs: ['The net deposits for the manager'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['Total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['Total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalDeposits',
+						docs: ['Total deposits for the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalWithdraws',
+						docs: ['Total withdraws for the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalFee',
+						docs: ['Total management fee accrued by the manager'],
+						type: 'i64',
+					},
+					{
+						name: 'managerTotalProfitShare',
+						docs: ['Total profit share accrued by the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'minDepositAmount',
+						docs: ['The minimum deposit amount'],
+						type: 'u64',
+					},
+					{
+						name: 'lastManagerWithdrawRequest',
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'profitShare',
+						docs: [
+							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						docs: [
+							'Vault manager only collect incentive fees during periods when returns are higher than this annualized amount: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						docs: [
+							'The spot market index the vault deposits into/withdraws from',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						docs: ['The bump for the vault pda'],
+						type: 'u8',
+					},
+					{
+						name: 'permissioned',
+						docs: ['Whether anybody can be a depositor'],
+						type: 'bool',
+					},
+					{
+						name: 'vaultProtocol',
+						docs: ['The optional [`VaultProtocol`] account.'],
+						type: 'bool',
+					},
+					{
+						name: 'earlyWithdrawPenaltyToManager',
+						docs: [
+							'Whether the early withdraw penalty is paid to the manager instead of left in the vault for remaining depositors',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'subAccountCount',
+						docs: [
+							'The number of drift sub-accounts owned by the vault. Sub-account 0 (`user`) is the primary sub-account',
+							'that deposits and withdrawals go through. 0 for vaults created before sub-accounts were supported.',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'ifStakeInEquity',
+						docs: [
+							"Whether the vault's insurance fund stake in `spot_market_index` holds depositor capital and is counted",
+							'in vault equity, instead of being a manager-funded stake.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'withdrawQueue',
+						docs: [
+							'Whether withdraw requests are batched into epochs that the manager settles pro-rata, instead of each',
+							'depositor withdrawing directly from drift after the redeem period.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'windDown',
+						docs: [
+							'Whether the vault is winding down: no new deposits, no redeem period and no management or protocol',
+							'fees. Depositors are processed out with `wind_down_withdraw` until the vault can be closed.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 1],
+						},
+					},
+					{
+						name: 'profitShareCrystallizationPeriod',
+						docs: [
+							'The period (in seconds) between profit share crystallizations, aligned to init_ts.',
+							'Depositor profit share is only charged at period boundaries or on full exit. 0 means profit share is',
+							'charged on every depositor action.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'entryFee',
+						docs: [
+							'Percentage the manager charges on deposits: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'exitFee',
+						docs: [
+							'Percentage the manager charges on withdrawals: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'earlyWithdrawPenalty',
+						docs: [
+							'Percentage a depositor pays to withdraw immediately after requesting a withdrawal, decaying linearly to 0',
+							'over the redeem period: PERCENTAGE_PRECISION. 0 means instant withdraws are disabled.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingEarlyWithdrawPenalty',
+						docs: [
+							'The early withdraw penalty that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingManager',
+						docs: [
+							'The manager proposed by the current manager. Becomes the manager once they accept the handover.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'pendingManagementFee',
+						docs: [
+							'The management fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingRedeemPeriod',
+						docs: [
+							'The redeem period that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingParamsEffectiveTs',
+						docs: [
+							'When the pending parameter increases take effect. 0 means there are no pending changes.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingProfitShare',
+						docs: [
+							'The profit share that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingHurdleRate',
+						docs: [
+							'The hurdle rate that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingEntryFee',
+						docs: [
+							'The entry fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingExitFee',
+						docs: [
+							'The exit fee that takes effect at `pending_params_effective_ts`. 0 means unchanged.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'withdrawEpoch',
+						docs: [
+							'The withdraw epoch currently accepting requests. Epochs before it have been settled.',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'withdrawQueueShares',
+						docs: [
+							'The shares queued for withdraw in the current epoch, including unfilled shares rolled over from',
+							'settled epochs.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'managerWithdrawDestination',
+						docs: [
+							'The token account manager withdrawals are paid to, if the manager registered one',
+						],
+						type: {
+							defined: 'WithdrawDestination',
+						},
+					},
+					{
+						name: 'maxDepositorEquity',
+						docs: [
+							'The max equity a single depositor can hold after a deposit. 0 means no limit.',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'minDepositorBalance',
+						docs: [
+							'The min equity a depositor must keep unless fully exiting. 0 means no minimum.',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'allowlistMerkleRoot',
+						docs: [
+							'Merkle root of the (authority, max equity) leaves allowed to self-initialize a vault depositor in a',
+							'permissioned vault. All zeros disables the allowlist.',
+						],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'accessMint',
+						docs: [
+							'Depositors must hold `access_min_balance` of this mint to join and deposit. Default disables the gate.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'accessMinBalance',
+						type: 'u64',
+					},
+					{
+						name: 'accessCollection',
+						docs: [
+							'Depositors must hold an NFT of this verified collection to join and deposit. Default disables the gate.',
+							'If both gates are set either one grants access.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'pausedUntilTs',
+						docs: [
+							'The ts the emergency pause expires. While paused, deposits and tokenizing are halted and the drift',
+							'delegate is set to the null key. 0 if never paused.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pausedBy',
+						docs: ['The manager or protocol that paused the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'maxDrawdown',
+						docs: [
+							"The max drawdown of the share price from its high water mark before the vault's drift user is set to",
+							'reduce-only: PERCENTAGE_PRECISION. 0 disables the circuit breaker.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pendingMaxDrawdown',
+						docs: [
+							'The looser max drawdown that takes effect at `pending_max_drawdown_ts`. 0 disables the circuit breaker.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'highWaterMarkSharePrice',
+						docs: [
+							'The highest share price seen since the circuit breaker was last reset: PRICE_PRECISION',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'drawdownBreachTs',
+						docs: ['The ts the max drawdown was breached. 0 if not breached.'],
+						type: 'i64',
+					},
+					{
+						name: 'liquidationWindow',
+						docs: [
+							'How long (in seconds) a liquidator has to unwind the vault before the manager can reset the delegate.',
+							'0 uses the default [`TIME_FOR_LIQUIDATION`].',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'liquidators',
+						docs: [
+							'The liquidators a depositor can hand the vault to in `liquidate`. If all default, only the',
+							'[`permissioned_liquidator`] is allowed.',
+						],
+						type: {
+							array: ['publicKey', 4],
+						},
+					},
+					{
+						name: 'permissionlessLiquidation',
+						docs: [
+							'Whether a depositor can hand the vault to any liquidator, including themselves',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'pendingPermissionlessLiquidation',
+						docs: [
+							'The `permissionless_liquidation` that takes effect at `pending_liquidation_settings_ts`',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'drawdownReduceOnlyPending',
+						docs: [
+							"Whether the max drawdown was breached in an instruction that can't set the drift users reduce-only,",
+							'the next instruction that can does so',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'padding6',
+						type: {
+							array: ['u8', 5],
+						},
+					},
+					{
+						name: 'withdrawQueueStartTs',
+						docs: [
+							'When the oldest request in the withdraw queue was made. 0 when the queue is empty.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingMaxDrawdownTs',
+						docs: [
+							'When `pending_max_drawdown` takes effect. 0 means there is no pending change.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pauseEndTs',
+						docs: [
+							'When the last emergency pause ended, by expiring or being lifted. 0 if never paused.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingLiquidationWindow',
+						docs: [
+							'The `liquidation_window` that takes effect at `pending_liquidation_settings_ts`',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingLiquidationSettingsTs',
+						docs: [
+							'When the pending liquidation settings take effect, or once the vault leaves liquidation if later.',
+							'0 means there are no pending changes.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'pendingLiquidators',
+						docs: [
+							'The `liquidators` that take effect at `pending_liquidation_settings_ts`',
+						],
+						type: {
+							array: ['publicKey', 4],
+						},
+					},
+					{
+						name: 'pendingProfitShareCrystallizationPeriod',
+						docs: [
+							'The profit share crystallization period that takes effect at `pending_params_effective_ts`. 0 means',
+							'unchanged.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'padding4',
+						type: {
+							array: ['u64', 4],
+						},
+					},
+				],
+			},
 		},
-	],
-	accounts: [
 		{
-			name: 'tokenizedVaultDepositor',
+			name: 'withdrawEpoch',
+			docs: [
+				'A settled withdraw epoch. Every request queued in the epoch is filled by the same fraction,',
+				'`filled_shares / requested_shares`, at the share price the epoch was settled at, capped at the',
+				"request's value when claimed.",
+			],
 			type: {
 				kind: 'struct',
 				fields: [
 					{
 						name: 'vault',
-						docs: ['The vault deposited into'],
+						docs: ['The vault the epoch belongs to'],
 						type: 'publicKey',
 					},
 					{
 						name: 'pubkey',
 						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault",
+							"The withdraw epoch account's pubkey. It is a pda of vault and epoch",
 						],
 						type: 'publicKey',
 					},
 					{
-						name: 'mint',
+						name: 'requestedShares',
+						docs: ['The shares queued for withdraw when the epoch was settled'],
+						type: 'u128',
+					},
+					{
+						name: 'filledShares',
 						docs: [
-							'The token mint for tokenized shares owned by this VaultDepositor',
+							'The queued shares burned to fill the epoch, the rest rolled over into the next epoch',
 						],
-						type: 'publicKey',
+						type: 'u128',
 					},
 					{
-						name: 'vaultShares',
+						name: 'filledAmount',
 						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+							'The token amount withdrawn from drift to pay for the filled shares, before exit fees',
 						],
-						type: 'u128',
+						type: 'u64',
 					},
 					{
-						name: 'lastVaultShares',
+						name: 'claimedAmount',
 						docs: [
-							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
-							'CPI is done, used to track invariants',
+							'The token amount claimed by depositors so far, including exit fees and the value above each',
+							"request's cap deposited back into the vault",
 						],
-						type: 'u128',
+						type: 'u64',
 					},
 					{
-						name: 'lastValidTs',
-						docs: ['creation ts of vault depositor'],
-						type: 'i64',
+						name: 'epoch',
+						type: 'u64',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['lifetime net deposits of vault depositor for the vault'],
+						name: 'settledTs',
 						type: 'i64',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['lifetime total deposits'],
-						type: 'u64',
+						name: 'sharesBase',
+						docs: ["The vault's shares_base when the epoch was settled"],
+						type: 'u32',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['lifetime total withdraws'],
-						type: 'u64',
+						name: 'bump',
+						type: 'u8',
 					},
 					{
-						name: 'cumulativeProfitShareAmount',
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on',
-						],
-						type: 'i64',
+						name: 'padding1',
+						type: {
+							array: ['u8', 3],
+						},
 					},
 					{
-						name: 'profitShareFeePaid',
-						type: 'u64',
+						name: 'padding',
+						type: {
+							array: ['u64', 9],
+						},
 					},
+				],
+			},
+		},
+		{
+			name: 'withdrawRequestSlots',
+			docs: [
+				'Additional withdraw requests for a vault depositor, each with its own redeem period clock, so',
+				"withdrawals can be laddered alongside the depositor's `last_withdraw_request`.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'vaultSharesBase',
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the requests belong to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'vault',
+						docs: ['The vault the vault depositor deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'requests',
+						type: {
+							array: [
+								{
+									defined: 'WithdrawRequest',
+								},
+								8,
+							],
+						},
+					},
+					{
+						name: 'sharesBase',
 						docs: [
-							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
-							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
-							'is possible.',
+							'the exponent for the request shares decimal places, requests are rebased lazily when loaded',
 						],
 						type: 'u32',
 					},
 					{
 						name: 'bump',
-						docs: ['The bump for the vault pda'],
 						type: 'u8',
 					},
 					{
@@ -4364,689 +10280,1083 @@ export const IDL: DriftVaults = {
 					{
 						name: 'padding',
 						type: {
-							array: ['u64', 10],
+							array: ['u64', 7],
 						},
 					},
 				],
 			},
 		},
+	],
+	types: [
 		{
-			name: 'vaultDepositor',
+			name: 'InitializeTokenizedVaultDepositorParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'vault',
-						docs: ['The vault deposited into'],
-						type: 'publicKey',
+						name: 'tokenName',
+						type: 'string',
 					},
 					{
-						name: 'pubkey',
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault and authority",
-						],
-						type: 'publicKey',
+						name: 'tokenSymbol',
+						type: 'string',
 					},
 					{
-						name: 'authority',
-						docs: [
-							'The authority is the address w permission to deposit/withdraw',
-						],
+						name: 'tokenUri',
+						type: 'string',
+					},
+					{
+						name: 'decimals',
+						type: 'u8',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultWithProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'redeemPeriod',
+						type: 'i64',
+					},
+					{
+						name: 'maxTokens',
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						type: 'i64',
+					},
+					{
+						name: 'minDepositAmount',
+						type: 'u64',
+					},
+					{
+						name: 'profitShare',
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'profitShareCrystallizationPeriod',
+						type: 'i64',
+					},
+					{
+						name: 'entryFee',
+						type: 'u32',
+					},
+					{
+						name: 'exitFee',
+						type: 'u32',
+					},
+					{
+						name: 'earlyWithdrawPenalty',
+						type: 'u32',
+					},
+					{
+						name: 'earlyWithdrawPenaltyToManager',
+						type: 'bool',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+					{
+						name: 'vaultProtocol',
+						type: {
+							defined: 'VaultProtocolParams',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
 						type: 'publicKey',
 					},
 					{
-						name: 'vaultShares',
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
-						],
-						type: 'u128',
+						name: 'protocolFee',
+						type: 'u64',
 					},
 					{
-						name: 'lastWithdrawRequest',
-						docs: ['last withdraw request'],
+						name: 'protocolProfitShare',
+						type: 'u32',
+					},
+					{
+						name: 'protocolEntryFee',
+						type: 'u32',
+					},
+					{
+						name: 'protocolExitFee',
+						type: 'u32',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
 						type: {
-							defined: 'WithdrawRequest',
+							array: ['u8', 32],
 						},
 					},
 					{
-						name: 'lastValidTs',
-						docs: ['creation ts of vault depositor'],
+						name: 'redeemPeriod',
 						type: 'i64',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['lifetime net deposits of vault depositor for the vault'],
+						name: 'maxTokens',
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
 						type: 'i64',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['lifetime total deposits'],
+						name: 'minDepositAmount',
 						type: 'u64',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['lifetime total withdraws'],
-						type: 'u64',
+						name: 'profitShare',
+						type: 'u32',
 					},
 					{
-						name: 'cumulativeProfitShareAmount',
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on',
-						],
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'profitShareCrystallizationPeriod',
 						type: 'i64',
 					},
 					{
-						name: 'profitShareFeePaid',
-						type: 'u64',
+						name: 'entryFee',
+						type: 'u32',
 					},
 					{
-						name: 'vaultSharesBase',
-						docs: ['the exponent for vault_shares decimal places'],
+						name: 'exitFee',
 						type: 'u32',
 					},
 					{
-						name: 'padding1',
+						name: 'earlyWithdrawPenalty',
 						type: 'u32',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u64', 8],
-						},
+						name: 'earlyWithdrawPenaltyToManager',
+						type: 'bool',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
 					},
 				],
 			},
 		},
 		{
-			name: 'vaultProtocol',
+			name: 'ProposeVaultParamsParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'protocol',
-						docs: [
-							'The protocol, company, or entity that services the product using this vault.',
-							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.',
-						],
-						type: 'publicKey',
+						name: 'managementFee',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
-						name: 'protocolProfitAndFeeShares',
-						docs: [
-							'The shares from profit share and annual fee unclaimed by the protocol.',
-						],
-						type: 'u128',
+						name: 'redeemPeriod',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
-						name: 'protocolFee',
-						docs: [
-							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
-							"Unlike the management fee this can't be negative.",
-						],
-						type: 'u64',
+						name: 'profitShare',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'protocolTotalWithdraws',
-						docs: ['Total withdraws for the protocol'],
-						type: 'u64',
+						name: 'hurdleRate',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'protocolTotalFee',
-						docs: [
-							'Total fee charged by the protocol (annual management fee + profit share).',
-							"Unlike the management fee this can't be negative.",
-						],
-						type: 'u64',
+						name: 'entryFee',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'protocolTotalProfitShare',
-						docs: ['Total profit share charged by the protocol'],
-						type: 'u64',
+						name: 'exitFee',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'lastProtocolWithdrawRequest',
+						name: 'earlyWithdrawPenalty',
 						type: {
-							defined: 'WithdrawRequest',
+							option: 'u32',
 						},
 					},
 					{
-						name: 'protocolProfitShare',
-						docs: [
-							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'profitShareCrystallizationPeriod',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
-						name: 'bump',
-						type: 'u8',
+						name: 'liquidators',
+						type: {
+							option: {
+								vec: 'publicKey',
+							},
+						},
 					},
 					{
-						name: 'version',
-						type: 'u8',
+						name: 'liquidationWindow',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
-						name: 'padding',
+						name: 'permissionlessLiquidation',
 						type: {
-							array: ['u8', 2],
+							option: 'bool',
 						},
 					},
+					{
+						name: 'effectiveTs',
+						type: 'i64',
+					},
 				],
 			},
 		},
 		{
-			name: 'vault',
+			name: 'ProposeVaultProtocolParamsParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'name',
-						docs: [
-							'The name of the vault. Vault pubkey is derived from this name.',
-						],
+						name: 'protocolFee',
 						type: {
-							array: ['u8', 32],
+							option: 'u64',
 						},
 					},
 					{
-						name: 'pubkey',
-						docs: [
-							"The vault's pubkey. It is a pda of name and also used as the authority for drift user",
-						],
-						type: 'publicKey',
-					},
-					{
-						name: 'manager',
-						docs: [
-							'The manager of the vault who has ability to update vault params',
-						],
-						type: 'publicKey',
-					},
-					{
-						name: 'tokenAccount',
-						docs: [
-							'The vaults token account. Used to receive tokens between deposits and withdrawals',
-						],
-						type: 'publicKey',
-					},
-					{
-						name: 'userStats',
-						docs: ['The drift user stats account for the vault'],
-						type: 'publicKey',
-					},
-					{
-						name: 'user',
-						docs: ['The drift user account for the vault'],
-						type: 'publicKey',
-					},
-					{
-						name: 'delegate',
-						docs: [
-							'The vaults designated delegate for drift user account',
-							'can differ from actual user delegate if vault is in liquidation',
-						],
-						type: 'publicKey',
-					},
-					{
-						name: 'liquidationDelegate',
-						docs: ['The delegate handling liquidation for depositor'],
-						type: 'publicKey',
-					},
-					{
-						name: 'userShares',
-						docs: [
-							'The sum of all shares held by the users (vault depositors)',
-						],
-						type: 'u128',
+						name: 'protocolProfitShare',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'totalShares',
-						docs: [
-							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
-							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.',
-						],
-						type: 'u128',
+						name: 'protocolEntryFee',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'lastFeeUpdateTs',
-						docs: ['Last fee update unix timestamp'],
-						type: 'i64',
+						name: 'protocolExitFee',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'liquidationStartTs',
-						docs: ['When the liquidation starts'],
+						name: 'effectiveTs',
 						type: 'i64',
 					},
+				],
+			},
+		},
+		{
+			name: 'UpdateVaultDepositorLimitsParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'redeemPeriod',
-						docs: [
-							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
-							'Currently, the maximum is 90 days.',
-						],
-						type: 'i64',
+						name: 'maxEquityOverride',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'totalWithdrawRequested',
-						docs: ['The sum of all outstanding withdraw requests'],
-						type: 'u64',
+						name: 'minBalanceOverride',
+						type: {
+							option: 'u64',
+						},
 					},
+				],
+			},
+		},
+		{
+			name: 'UpdateVaultProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'maxTokens',
-						docs: [
-							'Max token capacity, once hit/passed vault will reject new deposits (updatable)',
-						],
-						type: 'u64',
+						name: 'protocolFee',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'managementFee',
-						docs: [
-							'The annual fee charged on deposits by the manager.',
-							'Traditional funds typically charge 2% per year on assets under management.',
-						],
-						type: 'i64',
+						name: 'protocolProfitShare',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'initTs',
-						docs: ['Timestamp vault initialized'],
-						type: 'i64',
+						name: 'protocolEntryFee',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'netDeposits',
-						docs: ['The net deposits for the vault'],
-						type: 'i64',
+						name: 'protocolExitFee',
+						type: {
+							option: 'u32',
+						},
 					},
+				],
+			},
+		},
+		{
+			name: 'UpdateVaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'managerNetDeposits',
-						docs: ['The net deposits for the manager'],
-						type: 'i64',
+						name: 'redeemPeriod',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['Total deposits'],
-						type: 'u64',
+						name: 'maxTokens',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['Total withdraws'],
-						type: 'u64',
+						name: 'managementFee',
+						type: {
+							option: 'i64',
+						},
 					},
 					{
-						name: 'managerTotalDeposits',
-						docs: ['Total deposits for the manager'],
-						type: 'u64',
+						name: 'minDepositAmount',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'managerTotalWithdraws',
-						docs: ['Total withdraws for the manager'],
-						type: 'u64',
+						name: 'profitShare',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'managerTotalFee',
-						docs: ['Total management fee accrued by the manager'],
-						type: 'i64',
+						name: 'hurdleRate',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'managerTotalProfitShare',
-						docs: ['Total profit share accrued by the manager'],
-						type: 'u64',
+						name: 'permissioned',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'minDepositAmount',
-						docs: ['The minimum deposit amount'],
-						type: 'u64',
+						name: 'entryFee',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'lastManagerWithdrawRequest',
+						name: 'exitFee',
 						type: {
-							defined: 'WithdrawRequest',
+							option: 'u32',
 						},
 					},
 					{
-						name: 'sharesBase',
-						docs: [
-							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)',
-						],
-						type: 'u32',
+						name: 'earlyWithdrawPenalty',
+						type: {
+							option: 'u32',
+						},
 					},
 					{
-						name: 'profitShare',
-						docs: [
-							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'earlyWithdrawPenaltyToManager',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'hurdleRate',
-						docs: [
-							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'withdrawQueue',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'spotMarketIndex',
-						docs: [
-							'The spot market index the vault deposits into/withdraws from',
-						],
-						type: 'u16',
+						name: 'maxDepositorEquity',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'bump',
-						docs: ['The bump for the vault pda'],
-						type: 'u8',
+						name: 'minDepositorBalance',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'permissioned',
-						docs: ['Whether anybody can be a depositor'],
-						type: 'bool',
+						name: 'allowlistMerkleRoot',
+						type: {
+							option: {
+								array: ['u8', 32],
+							},
+						},
 					},
 					{
-						name: 'vaultProtocol',
-						docs: ['The optional [`VaultProtocol`] account.'],
-						type: 'bool',
+						name: 'accessMint',
+						type: {
+							option: 'publicKey',
+						},
 					},
 					{
-						name: 'padding1',
+						name: 'accessMinBalance',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'accessCollection',
 						type: {
-							array: ['u8', 7],
+							option: 'publicKey',
 						},
 					},
 					{
-						name: 'padding',
+						name: 'maxDrawdown',
 						type: {
-							array: ['u64', 7],
+							option: 'u32',
 						},
 					},
 				],
 			},
 		},
-	],
-	types: [
 		{
-			name: 'InitializeTokenizedVaultDepositorParams',
+			name: 'WithdrawDestination',
+			docs: [
+				"The token account withdrawals are paid to instead of the owner's own token account. Changes only",
+				'take effect after [`WITHDRAW_DESTINATION_TIMELOCK`].',
+			],
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'tokenName',
-						type: 'string',
-					},
-					{
-						name: 'tokenSymbol',
-						type: 'string',
+						name: 'destination',
+						docs: [
+							'the token account withdrawals are paid to, default means any token account of the owner',
+						],
+						type: 'publicKey',
 					},
 					{
-						name: 'tokenUri',
-						type: 'string',
+						name: 'pendingDestination',
+						docs: [
+							'the destination that replaces `destination` at `pending_effective_ts`',
+						],
+						type: 'publicKey',
 					},
 					{
-						name: 'decimals',
-						type: 'u8',
+						name: 'pendingEffectiveTs',
+						docs: [
+							'when `pending_destination` takes effect, 0 if there is no pending change',
+						],
+						type: 'i64',
 					},
 				],
 			},
 		},
 		{
-			name: 'VaultWithProtocolParams',
+			name: 'WithdrawRequest',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
-					},
-					{
-						name: 'redeemPeriod',
-						type: 'i64',
+						name: 'shares',
+						docs: ['request shares of vault withdraw'],
+						type: 'u128',
 					},
 					{
-						name: 'maxTokens',
+						name: 'value',
+						docs: [
+							'requested value (in vault spot_market_index) of shares for withdraw',
+						],
 						type: 'u64',
 					},
 					{
-						name: 'managementFee',
+						name: 'ts',
+						docs: ['request ts of vault withdraw'],
 						type: 'i64',
 					},
-					{
-						name: 'minDepositAmount',
-						type: 'u64',
-					},
-					{
-						name: 'profitShare',
-						type: 'u32',
-					},
-					{
-						name: 'hurdleRate',
-						type: 'u32',
-					},
-					{
-						name: 'spotMarketIndex',
-						type: 'u16',
-					},
-					{
-						name: 'permissioned',
-						type: 'bool',
-					},
-					{
-						name: 'vaultProtocol',
-						type: {
-							defined: 'VaultProtocolParams',
-						},
-					},
 				],
 			},
 		},
 		{
-			name: 'VaultProtocolParams',
+			name: 'WithdrawMode',
+			docs: ['How [`process_withdraw`] pays out the pending withdraw request'],
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'protocol',
-						type: 'publicKey',
+						name: 'AfterRedeemPeriod',
 					},
 					{
-						name: 'protocolFee',
-						type: 'u64',
+						name: 'Instant',
 					},
 					{
-						name: 'protocolProfitShare',
-						type: 'u32',
+						name: 'Partial',
 					},
 				],
 			},
 		},
 		{
-			name: 'VaultParams',
+			name: 'VaultDepositorAction',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
+						name: 'Deposit',
 					},
 					{
-						name: 'redeemPeriod',
-						type: 'i64',
+						name: 'WithdrawRequest',
 					},
 					{
-						name: 'maxTokens',
-						type: 'u64',
+						name: 'CancelWithdrawRequest',
 					},
 					{
-						name: 'managementFee',
-						type: 'i64',
+						name: 'Withdraw',
 					},
 					{
-						name: 'minDepositAmount',
-						type: 'u64',
+						name: 'FeePayment',
 					},
 					{
-						name: 'profitShare',
-						type: 'u32',
+						name: 'TokenizeShares',
 					},
 					{
-						name: 'hurdleRate',
-						type: 'u32',
+						name: 'RedeemTokens',
+					},
+					{
+						name: 'InstantWithdraw',
+					},
+					{
+						name: 'EpochWithdraw',
 					},
+				],
+			},
+		},
+		{
+			name: 'VaultParamsUpdateAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'spotMarketIndex',
-						type: 'u16',
+						name: 'Propose',
 					},
 					{
-						name: 'permissioned',
-						type: 'bool',
+						name: 'Apply',
 					},
 				],
 			},
 		},
 		{
-			name: 'UpdateVaultProtocolParams',
+			name: 'WithdrawUnit',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'protocolFee',
-						type: {
-							option: 'u64',
-						},
+						name: 'Shares',
 					},
 					{
-						name: 'protocolProfitShare',
-						type: {
-							option: 'u32',
-						},
+						name: 'Token',
+					},
+					{
+						name: 'SharesPercent',
 					},
 				],
 			},
 		},
+	],
+	events: [
+		{
+			name: 'VaultRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultDepositorRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
+					},
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'vaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'profitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managementFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'entryExitFee',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultDepositorV1Record',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
+					},
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'vaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'protocolFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'protocolFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managerProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managementFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'protocolEntryExitFee',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managerEntryExitFee',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
 		{
-			name: 'UpdateVaultParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'redeemPeriod',
-						type: {
-							option: 'i64',
-						},
-					},
-					{
-						name: 'maxTokens',
-						type: {
-							option: 'u64',
-						},
-					},
-					{
-						name: 'managementFee',
-						type: {
-							option: 'i64',
-						},
-					},
-					{
-						name: 'minDepositAmount',
-						type: {
-							option: 'u64',
-						},
-					},
-					{
-						name: 'profitShare',
-						type: {
-							option: 'u32',
-						},
-					},
-					{
-						name: 'hurdleRate',
-						type: {
-							option: 'u32',
-						},
-					},
-					{
-						name: 'permissioned',
-						type: {
-							option: 'bool',
-						},
-					},
-				],
-			},
+			name: 'ShareTransferRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'fromVaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'toVaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'shares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'value',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'fromDepositorSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'fromDepositorSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'toDepositorSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'toDepositorSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'WithdrawRequest',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'shares',
-						docs: ['request shares of vault withdraw'],
-						type: 'u128',
-					},
-					{
-						name: 'value',
-						docs: [
-							'requested value (in vault spot_market_index) of shares for withdraw',
-						],
-						type: 'u64',
-					},
-					{
-						name: 'ts',
-						docs: ['request ts of vault withdraw'],
-						type: 'i64',
-					},
-				],
-			},
+			name: 'VaultManagerTransferRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'oldManager',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'newManager',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'delegate',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'managerShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'managerWithdrawRequestShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'managerWithdrawRequestValue',
+					type: 'u64',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'VaultDepositorAction',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'Deposit',
-					},
-					{
-						name: 'WithdrawRequest',
-					},
-					{
-						name: 'CancelWithdrawRequest',
-					},
-					{
-						name: 'Withdraw',
-					},
-					{
-						name: 'FeePayment',
-					},
-					{
-						name: 'TokenizeShares',
-					},
-					{
-						name: 'RedeemTokens',
-					},
-				],
-			},
+			name: 'VaultProtocolTransferRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'oldProtocol',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'newProtocol',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'protocolShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolWithdrawRequestShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolWithdrawRequestValue',
+					type: 'u64',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'WithdrawUnit',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'Shares',
-					},
-					{
-						name: 'Token',
-					},
-					{
-						name: 'SharesPercent',
+			name: 'VaultParamsUpdateRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultParamsUpdateAction',
 					},
-				],
-			},
+					index: false,
+				},
+				{
+					name: 'effectiveTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'redeemPeriod',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'profitShare',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'hurdleRate',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'entryFee',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'exitFee',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'earlyWithdrawPenalty',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'profitShareCrystallizationPeriod',
+					type: 'i64',
+					index: false,
+				},
+			],
 		},
-	],
-	events: [
 		{
-			name: 'VaultRecord',
+			name: 'VaultProtocolParamsUpdateRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5054,19 +11364,46 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'spotMarketIndex',
-					type: 'u16',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'vaultEquityBefore',
+					name: 'action',
+					type: {
+						defined: 'VaultParamsUpdateAction',
+					},
+					index: false,
+				},
+				{
+					name: 'effectiveTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'protocolFee',
 					type: 'u64',
 					index: false,
 				},
+				{
+					name: 'protocolProfitShare',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'protocolEntryFee',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'protocolExitFee',
+					type: 'u32',
+					index: false,
+				},
 			],
 		},
 		{
-			name: 'VaultDepositorRecord',
+			name: 'WithdrawEpochRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5079,81 +11416,109 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'depositorAuthority',
-					type: 'publicKey',
+					name: 'epoch',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'action',
-					type: {
-						defined: 'VaultDepositorAction',
-					},
+					name: 'vaultEquityBefore',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'amount',
+					name: 'requestedShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'filledShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'filledAmount',
 					type: 'u64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'DepositForRecord',
+			fields: [
 				{
-					name: 'spotMarketIndex',
-					type: 'u16',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'vaultSharesBefore',
-					type: 'u128',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'vaultSharesAfter',
-					type: 'u128',
+					name: 'vaultDepositor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'vaultEquityBefore',
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'funder',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'amount',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesBefore',
+					name: 'shares',
 					type: 'u128',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'WithdrawDestinationUpdateRecord',
+			fields: [
 				{
-					name: 'totalVaultSharesBefore',
-					type: 'u128',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesAfter',
-					type: 'u128',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'totalVaultSharesAfter',
-					type: 'u128',
+					name: 'authority',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'profitShare',
-					type: 'u64',
+					name: 'oldDestination',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'managementFee',
-					type: 'i64',
+					name: 'newDestination',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'managementFeeShares',
+					name: 'effectiveTs',
 					type: 'i64',
 					index: false,
 				},
 			],
 		},
 		{
-			name: 'VaultDepositorV1Record',
+			name: 'VaultDepositorFreezeRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5165,76 +11530,89 @@ export const IDL: DriftVaults = {
 					type: 'publicKey',
 					index: false,
 				},
+				{
+					name: 'vaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
 				{
 					name: 'depositorAuthority',
 					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'action',
-					type: {
-						defined: 'VaultDepositorAction',
-					},
+					name: 'manager',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'amount',
-					type: 'u64',
+					name: 'frozen',
+					type: 'bool',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'VaultDepositorCloseRecord',
+			fields: [
 				{
-					name: 'spotMarketIndex',
-					type: 'u16',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'vaultSharesBefore',
-					type: 'u128',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'vaultSharesAfter',
-					type: 'u128',
+					name: 'vaultDepositor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'vaultEquityBefore',
-					type: 'u64',
+					name: 'depositorAuthority',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesBefore',
-					type: 'u128',
+					name: 'closedBy',
+					type: 'publicKey',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'VaultWindDownRecord',
+			fields: [
 				{
-					name: 'totalVaultSharesBefore',
-					type: 'u128',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesAfter',
-					type: 'u128',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'totalVaultSharesAfter',
-					type: 'u128',
+					name: 'manager',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'protocolSharesBefore',
-					type: 'u128',
+					name: 'vaultEquity',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'protocolSharesAfter',
+					name: 'totalShares',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'protocolProfitShare',
-					type: 'u64',
+					name: 'managementFee',
+					type: 'i64',
 					index: false,
 				},
 				{
@@ -5242,30 +11620,35 @@ export const IDL: DriftVaults = {
 					type: 'i64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'VaultCloseRecord',
+			fields: [
 				{
-					name: 'protocolFeeShares',
+					name: 'ts',
 					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'managerProfitShare',
-					type: 'u64',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'managementFee',
-					type: 'i64',
+					name: 'manager',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'managementFeeShares',
-					type: 'i64',
+					name: 'subAccountsDeleted',
+					type: 'u16',
 					index: false,
 				},
 			],
 		},
 		{
-			name: 'ShareTransferRecord',
+			name: 'VaultPauseRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5278,43 +11661,48 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'fromVaultDepositor',
+					name: 'authority',
 					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'toVaultDepositor',
-					type: 'publicKey',
+					name: 'pausedUntilTs',
+					type: 'i64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'VaultDrawdownRecord',
+			fields: [
 				{
-					name: 'shares',
-					type: 'u128',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'value',
-					type: 'u64',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'fromDepositorSharesBefore',
-					type: 'u128',
+					name: 'maxDrawdown',
+					type: 'u32',
 					index: false,
 				},
 				{
-					name: 'fromDepositorSharesAfter',
-					type: 'u128',
+					name: 'highWaterMarkSharePrice',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'toDepositorSharesBefore',
-					type: 'u128',
+					name: 'sharePrice',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'toDepositorSharesAfter',
-					type: 'u128',
+					name: 'breached',
+					type: 'bool',
 					index: false,
 				},
 			],
@@ -5441,5 +11829,55 @@ export const IDL: DriftVaults = {
 			name: 'InvalidTokenization',
 			msg: 'InvalidTokenization',
 		},
+		{
+			code: 6024,
+			name: 'VaultProtocolNotMigrated',
+			msg: 'VaultProtocolNotMigrated',
+		},
+		{
+			code: 6025,
+			name: 'SlippageExceeded',
+			msg: 'SlippageExceeded',
+		},
+		{
+			code: 6026,
+			name: 'VaultDepositorNotMigrated',
+			msg: 'VaultDepositorNotMigrated',
+		},
+		{
+			code: 6027,
+			name: 'VaultDepositorFrozen',
+			msg: 'VaultDepositorFrozen',
+		},
+		{
+			code: 6028,
+			name: 'InvalidVaultDepositorClose',
+			msg: 'InvalidVaultDepositorClose',
+		},
+		{
+			code: 6029,
+			name: 'VaultWindingDown',
+			msg: 'VaultWindingDown',
+		},
+		{
+			code: 6030,
+			name: 'InvalidVaultClose',
+			msg: 'InvalidVaultClose',
+		},
+		{
+			code: 6031,
+			name: 'VaultPaused',
+			msg: 'VaultPaused',
+		},
+		{
+			code: 6032,
+			name: 'InvalidVaultSubAccounts',
+			msg: 'InvalidVaultSubAccounts',
+		},
+		{
+			code: 6033,
+			name: 'VaultNotMigrated',
+			msg: 'VaultNotMigrated',
+		},
 	],
 };
@@ -29,18 +29,14 @@ export type VaultParams = {
 	profitShare: number;
 	hurdleRate: number;
 	permissioned: boolean;
+	profitShareCrystallizationPeriod: BN;
+	entryFee: number;
+	exitFee: number;
+	earlyWithdrawPenalty: number;
+	earlyWithdrawPenaltyToManager: boolean;
 };
 
-export type VaultWithProtocolParams = {
-	name: number[];
-	spotMarketIndex: number;
-	redeemPeriod: BN;
-	maxTokens: BN;
-	minDepositAmount: BN;
-	managementFee: BN;
-	profitShare: number;
-	hurdleRate: number;
-	permissioned: boolean;
+export type VaultWithProtocolParams = VaultParams & {
 	vaultProtocol: VaultProtocolParams;
 };
 
@@ -48,6 +44,8 @@ export type VaultProtocolParams = {
 	protocol: PublicKey;
 	protocolFee: BN;
 	protocolProfitShare: number;
+	protocolEntryFee: number;
+	protocolExitFee: number;
 };
 
 export type UpdateVaultParams = {
@@ -58,11 +56,40 @@ export type UpdateVaultParams = {
 	profitShare: number | null;
 	hurdleRate: number | null;
 	permissioned: boolean | null;
+	entryFee: number | null;
+	exitFee: number | null;
+	earlyWithdrawPenalty: number | null;
+	earlyWithdrawPenaltyToManager: boolean | null;
+	withdrawQueue: boolean | null;
+	maxDepositorEquity: BN | null;
+	minDepositorBalance: BN | null;
+	allowlistMerkleRoot: number[] | null;
+	accessMint: PublicKey | null;
+	accessMinBalance: BN | null;
+	accessCollection: PublicKey | null;
+	maxDrawdown: number | null;
+};
+
+export type ProposeVaultParams = {
+	managementFee: BN | null;
+	redeemPeriod: BN | null;
+	profitShare: number | null;
+	hurdleRate: number | null;
+	entryFee: number | null;
+	exitFee: number | null;
+	earlyWithdrawPenalty: number | null;
+	profitShareCrystallizationPeriod: BN | null;
+	liquidators: PublicKey[] | null;
+	liquidationWindow: BN | null;
+	permissionlessLiquidation: boolean | null;
+	effectiveTs: BN;
 };
 
 export type UpdateVaultProtocolParams = {
 	protocolFee: BN | null;
 	protocolProfitShare: number | null;
+	protocolEntryFee: number | null;
+	protocolExitFee: number | null;
 };
 
 // Vault program accounts
@@ -45,6 +45,8 @@ import {
 	TOKEN_PROGRAM_ID,
 } from '@solana/spl-token';
 import {
+	ProposeVaultParams,
+	UpdateVaultParams,
 	Vault,
 	VaultDepositor,
 	VaultParams,
@@ -468,11 +470,25 @@ export class VaultClient {
 			profitShare: number;
 			hurdleRate: number;
 			permissioned: boolean;
+			profitShareCrystallizationPeriod?: BN;
+			entryFee?: number;
+			exitFee?: number;
+			earlyWithdrawPenalty?: number;
+			earlyWithdrawPenaltyToManager?: boolean;
 			vaultProtocol?: VaultProtocolParams;
 		},
 		uiTxParams?: TxParams
 	): Promise<TransactionSignature> {
-		const { vaultProtocol: vaultProtocolParams, ...vaultParams } = params;
+		const { vaultProtocol: vaultProtocolParams, ...optionalVaultParams } =
+			params;
+		const vaultParams: VaultParams = {
+			profitShareCrystallizationPeriod: ZERO,
+			entryFee: 0,
+			exitFee: 0,
+			earlyWithdrawPenalty: 0,
+			earlyWithdrawPenaltyToManager: false,
+			...optionalVaultParams,
+		};
 		const vault = getVaultAddressSync(this.program.programId, params.name);
 		const tokenAccount = getTokenVaultAddressSync(
 			this.program.programId,
@@ -551,7 +567,7 @@ export class VaultClient {
 				return await this.createAndSendTxn(ixs, uiTxParams);
 			}
 		} else {
-			const _params: VaultParams = vaultParams;
+			const _params = vaultParams;
 
 			if (this.cliMode) {
 				return await this.program.methods
@@ -967,20 +983,40 @@ export class VaultClient {
 		return this.createAndSendTxn([ix], uiTxParams);
 	}
 
+	/**
+	 * Updates the vault params. Params left out are unchanged.
+	 * @param vault vault address to update
+	 * @param params params to update
+	 * @returns
+	 */
 	public async managerUpdateVault(
 		vault: PublicKey,
-		params: {
-			redeemPeriod: BN | null;
-			maxTokens: BN | null;
-			managementFee: BN | null;
-			minDepositAmount: BN | null;
-			profitShare: number | null;
-			hurdleRate: number | null;
-			permissioned: boolean | null;
-		},
+		params: Partial<UpdateVaultParams>,
 		uiTxParams?: TxParams
 	): Promise<TransactionSignature> {
-		const ix = this.program.instruction.updateVault(params, {
+		const updateParams: UpdateVaultParams = {
+			redeemPeriod: null,
+			maxTokens: null,
+			managementFee: null,
+			minDepositAmount: null,
+			profitShare: null,
+			hurdleRate: null,
+			permissioned: null,
+			entryFee: null,
+			exitFee: null,
+			earlyWithdrawPenalty: null,
+			earlyWithdrawPenaltyToManager: null,
+			withdrawQueue: null,
+			maxDepositorEquity: null,
+			minDepositorBalance: null,
+			allowlistMerkleRoot: null,
+			accessMint: null,
+			accessMinBalance: null,
+			accessCollection: null,
+			maxDrawdown: null,
+			...params,
+		};
+		const ix = this.program.instruction.updateVault(updateParams, {
 			accounts: {
 				vault,
 				manager: this.driftClient.wallet.publicKey,
@@ -996,6 +1032,116 @@ export class VaultClient {
 		}
 	}
 
+	/**
+	 * Proposes fee increases and liquidation settings that take effect at `effectiveTs`.
+	 * Params left out are unchanged, and proposing none cancels the pending ones.
+	 * @param vault vault address to update
+	 * @param params params to propose
+	 * @returns
+	 */
+	public async managerProposeVaultParams(
+		vault: PublicKey,
+		params: Partial<ProposeVaultParams> & { effectiveTs: BN },
+		uiTxParams?: TxParams
+	): Promise<TransactionSignature> {
+		const vaultAccount = await this.program.account.vault.fetch(vault);
+		const proposeParams: ProposeVaultParams = {
+			managementFee: null,
+			redeemPeriod: null,
+			profitShare: null,
+			hurdleRate: null,
+			entryFee: null,
+			exitFee: null,
+			earlyWithdrawPenalty: null,
+			profitShareCrystallizationPeriod: null,
+			liquidators: null,
+			liquidationWindow: null,
+			permissionlessLiquidation: null,
+			...params,
+		};
+
+		const remainingAccounts: AccountMeta[] = [];
+		if (vaultAccount.vaultProtocol) {
+			remainingAccounts.push({
+				pubkey: this.getVaultProtocolAddress(vault),
+				isSigner: false,
+				isWritable: false,
+			});
+		}
+
+		const ix = await this.program.methods
+			.proposeVaultParams(proposeParams)
+			.accounts({
+				vault,
+				manager: this.driftClient.wallet.publicKey,
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+		return this.createAndSendTxn([ix], uiTxParams);
+	}
+
+	/**
+	 * Grows a vault created before the account grew. Must run before
+	 * {@link migrateVaultProtocol} and any other instruction on the vault.
+	 * @param vault vault address to migrate
+	 * @returns
+	 */
+	public async migrateVault(
+		vault: PublicKey,
+		uiTxParams?: TxParams
+	): Promise<TransactionSignature> {
+		const ix = await this.program.methods
+			.migrateVault()
+			.accounts({
+				vault,
+				payer: this.driftClient.wallet.publicKey,
+				systemProgram: SystemProgram.programId,
+			})
+			.instruction();
+		return await this.createAndSendTxn([ix], uiTxParams);
+	}
+
+	/**
+	 * Grows the vault protocol of a vault migrated with {@link migrateVault}.
+	 * @param vault vault address whose vault protocol to migrate
+	 * @returns
+	 */
+	public async migrateVaultProtocol(
+		vault: PublicKey,
+		uiTxParams?: TxParams
+	): Promise<TransactionSignature> {
+		const ix = await this.program.methods
+			.migrateVaultProtocol()
+			.accounts({
+				vault,
+				vaultProtocol: this.getVaultProtocolAddress(vault),
+				payer: this.driftClient.wallet.publicKey,
+				systemProgram: SystemProgram.programId,
+			})
+			.instruction();
+		return await this.createAndSendTxn([ix], uiTxParams);
+	}
+
+	/**
+	 * Grows a vault depositor created before the account grew.
+	 * @param vaultDepositor vault depositor address to migrate
+	 * @returns
+	 */
+	public async migrateVaultDepositor(
+		vaultDepositor: PublicKey,
+		uiTxParams?: TxParams
+	): Promise<TransactionSignature> {
+		const ix = await this.program.methods
+			.migrateVaultDepositor()
+			.accounts({
+				vaultDepositor,
+				payer: this.driftClient.wallet.publicKey,
+				systemProgram: SystemProgram.programId,
+			})
+			.instruction();
+		return await this.createAndSendTxn([ix], uiTxParams);
+	}
+
 	public async getApplyProfitShareIx(
 		vault: PublicKey,
 		vaultDepositor: PublicKey
@@ -1593,6 +1739,8 @@ export class VaultClient {
 	 * @param vaultDepositor
 	 * @param amount
 	 * @param initVaultDepositor If true, will initialize the vault depositor account
+	 * @param txParams
+	 * @param minSharesOut The deposit fails if it mints fewer shares
 	 * @returns transaction
 	 */
 	public async createDepositTx(
@@ -1602,7 +1750,8 @@ export class VaultClient {
 			authority: PublicKey;
 			vault: PublicKey;
 		},
-		txParams?: TxParams
+		txParams?: TxParams,
+		minSharesOut?: BN
 	): Promise<VersionedTransaction> {
 		const { vaultAccount, accounts, remainingAccounts, preIxs, postIxs } =
 			await this.prepDepositTx(vaultDepositor, amount, initVaultDepositor);
@@ -1619,7 +1768,7 @@ export class VaultClient {
 		}
 
 		const depositIx = await this.program.methods
-			.deposit(amount)
+			.deposit(amount, minSharesOut ?? null)
 			.accounts({
 				authority: this.driftClient.wallet.publicKey,
 				...accounts,
@@ -1639,6 +1788,8 @@ export class VaultClient {
 	 * @param amount
 	 * @param initVaultDepositor If true, will initialize the vault depositor account
 	 * @param txParams
+	 * @param userTokenAccount
+	 * @param minSharesOut The deposit fails if it mints fewer shares
 	 * @returns
 	 */
 	public async deposit(
@@ -1649,7 +1800,8 @@ export class VaultClient {
 			vault: PublicKey;
 		},
 		txParams?: TxParams,
-		userTokenAccount?: PublicKey
+		userTokenAccount?: PublicKey,
+		minSharesOut?: BN
 	): Promise<TransactionSignature> {
 		if (this.cliMode) {
 			const { vaultAccount, accounts, remainingAccounts, preIxs, postIxs } =
@@ -1667,7 +1819,7 @@ export class VaultClient {
 				);
 			}
 			return this.program.methods
-				.deposit(amount)
+				.deposit(amount, minSharesOut ?? null)
 				.accounts(accounts)
 				.remainingAccounts(remainingAccounts)
 				.preInstructions(preIxs)
@@ -1678,18 +1830,29 @@ export class VaultClient {
 				vaultDepositor,
 				amount,
 				initVaultDepositor,
-				txParams
+				txParams,
+				minSharesOut
 			);
 
 			return this.sendTxn(depositTxn, txParams?.simulateTransaction);
 		}
 	}
 
+	/**
+	 * Requests a withdraw from the vault.
+	 * @param vaultDepositor
+	 * @param amount
+	 * @param withdrawUnit
+	 * @param txParams
+	 * @param maxSharesBurned The request fails if it would burn more shares
+	 * @returns
+	 */
 	public async requestWithdraw(
 		vaultDepositor: PublicKey,
 		amount: BN,
 		withdrawUnit: WithdrawUnit,
-		txParams?: TxParams
+		txParams?: TxParams,
+		maxSharesBurned?: BN
 	): Promise<TransactionSignature> {
 		const vaultDepositorAccount =
 			await this.program.account.vaultDepositor.fetch(vaultDepositor);
@@ -1727,7 +1890,7 @@ export class VaultClient {
 		if (this.cliMode) {
 			return await this.program.methods
 				// @ts-ignore
-				.requestWithdraw(amount, withdrawUnit)
+				.requestWithdraw(amount, withdrawUnit, maxSharesBurned ?? null)
 				.accounts(accounts)
 				.remainingAccounts(remainingAccounts)
 				.rpc();
@@ -1740,6 +1903,7 @@ export class VaultClient {
 				// @ts-ignore
 				amount,
 				withdrawUnit,
+				maxSharesBurned ?? null,
 				{
 					accounts: {
 						authority: this.driftClient.wallet.publicKey,
@@ -1756,9 +1920,17 @@ export class VaultClient {
 		}
 	}
 
+	/**
+	 * Withdraws the pending withdraw request once the redeem period has passed.
+	 * @param vaultDepositor
+	 * @param txParams
+	 * @param minTokensOut The withdraw fails if it pays out fewer tokens
+	 * @returns
+	 */
 	public async withdraw(
 		vaultDepositor: PublicKey,
-		txParams?: TxParams
+		txParams?: TxParams,
+		minTokensOut?: BN
 	): Promise<TransactionSignature> {
 		const vaultDepositorAccount =
 			await this.program.account.vaultDepositor.fetch(vaultDepositor);
@@ -1857,7 +2029,7 @@ export class VaultClient {
 
 		if (this.cliMode) {
 			return await this.program.methods
-				.withdraw()
+				.withdraw(minTokensOut ?? null)
 				.accounts(accounts)
 				.remainingAccounts(remainingAccounts)
 				.preInstructions(preIxs)
@@ -1872,7 +2044,7 @@ export class VaultClient {
 				...oracleFeedsToCrankIxs,
 				...preIxs,
 				await this.program.methods
-					.withdraw()
+					.withdraw(minTokensOut ?? null)
 					.accounts({
 						authority: this.driftClient.wallet.publicKey,
 						...accounts,
@@ -2051,13 +2223,15 @@ export class VaultClient {
 
 	/**
 	 * Liquidates (become delegate for) a vault.
-	 * @param
-	 * @param
+	 * @param vaultDepositor
+	 * @param txParams
+	 * @param liquidator Defaults to the vault's default liquidator
 	 * @returns
 	 */
 	public async liquidate(
 		vaultDepositor: PublicKey,
-		txParams?: TxParams
+		txParams?: TxParams,
+		liquidator?: PublicKey
 	): Promise<TransactionSignature> {
 		const vaultDepositorAccount =
 			await this.program.account.vaultDepositor.fetch(vaultDepositor);
@@ -2090,18 +2264,21 @@ export class VaultClient {
 
 		if (this.cliMode) {
 			return await this.program.methods
-				.liquidate()
+				.liquidate(liquidator ?? null)
 				.accounts(accounts)
 				.remainingAccounts(remainingAccounts)
 				.rpc();
 		} else {
-			const liquidateIx = this.program.instruction.liquidate({
-				accounts: {
-					authority: this.driftClient.wallet.publicKey,
-					...accounts,
-				},
-				remainingAccounts,
-			});
+			const liquidateIx = this.program.instruction.liquidate(
+				liquidator ?? null,
+				{
+					accounts: {
+						authority: this.driftClient.wallet.publicKey,
+						...accounts,
+					},
+					remainingAccounts,
+				}
+			);
 
 			return await this.createAndSendTxn([liquidateIx], txParams);
 		}
@@ -2327,7 +2504,7 @@ export class VaultClient {
 			.requestRemoveInsuranceFundStake(spotMarketIndex, amount)
 			.accounts({
 				vault,
-				manager: this.driftClient.wallet.publicKey,
+				authority: this.driftClient.wallet.publicKey,
 				driftSpotMarket: spotMarket.pubkey,
 				insuranceFundStake: ifStakeAccountPublicKey,
 				insuranceFundVault: ifVaultPublicKey,